@@ -0,0 +1,97 @@
+//! A headless benchmark comparing a virtual list against spawning a node for
+//! every row of a large list.
+//!
+//! Run with `cargo bench --bench virtual_list`.
+//!
+//! No UI layout is performed in a headless app, so the virtual list has no
+//! visible area and only spawns its buffer rows around the scroll position.
+//! The buffer is sized to roughly match a full screen of rows.
+
+use std::time::{Duration, Instant};
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy_streamline_ui::prelude::*;
+
+const ITEM_COUNT: usize = 10_000;
+const ROW_HEIGHT: f32 = 24.0;
+const BUFFER: usize = 40;
+const SCROLL_FRAMES: u32 = 1_000;
+
+fn main() {
+    let eager = bench_eager();
+    println!("eager:   {ITEM_COUNT} rows spawned in {eager:?}");
+
+    let (spawn, scroll) = bench_virtual();
+    println!("virtual: spawned in {spawn:?}");
+    println!(
+        "virtual: {SCROLL_FRAMES} scrolled frames in {scroll:?} ({:?} per frame)",
+        scroll / SCROLL_FRAMES
+    );
+}
+
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        InputPlugin,
+        StreamlineUIPlugin,
+    ))
+    .init_asset::<Font>()
+    .init_resource::<UiScale>();
+    app
+}
+
+fn row(index: usize) -> UiNode {
+    UiNodeBuilder::text(
+        TextBuilder::default().section(TextSectionBuilder::new(format!("Server #{index}"))),
+    )
+    .position(PositionBuilder::relative().size(Val::Percent(100.0), Val::Px(ROW_HEIGHT)))
+    .into()
+}
+
+fn bench_eager() -> Duration {
+    let mut app = headless_app();
+    app.add_systems(
+        Startup,
+        |asset_server: Res<AssetServer>, mut cmd: Commands| {
+            let mut view = UiNodeBuilder::scroll_view();
+            for index in 0 .. ITEM_COUNT {
+                view = view.child(row(index));
+            }
+            UiNode::from(view).build(&mut cmd, &asset_server);
+        },
+    );
+
+    let start = Instant::now();
+    app.update();
+    start.elapsed()
+}
+
+fn bench_virtual() -> (Duration, Duration) {
+    let mut app = headless_app();
+    app.add_systems(
+        Startup,
+        |asset_server: Res<AssetServer>, mut cmd: Commands| {
+            UiNode::from(UiNodeBuilder::virtual_list(ITEM_COUNT, ROW_HEIGHT, row).buffer(BUFFER))
+                .build(&mut cmd, &asset_server);
+        },
+    );
+
+    let start = Instant::now();
+    app.update();
+    app.update();
+    let spawn = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0 .. SCROLL_FRAMES {
+        let mut query = app.world.query::<&mut ScrollPosition>();
+        for mut position in query.iter_mut(&mut app.world) {
+            position.offset.y += ROW_HEIGHT * 5.0;
+        }
+        app.update();
+    }
+
+    (spawn, start.elapsed())
+}
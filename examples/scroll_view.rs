@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+use bevy_streamline_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WorldInspectorPlugin::new())
+        .add_plugins(StreamlineUIPlugin)
+        .add_systems(Startup, init)
+        .run();
+}
+
+fn init(asset_server: Res<AssetServer>, mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    scroll_list().build(&mut commands, &asset_server);
+}
+
+fn scroll_list() -> UiNode {
+    let mut view = UiNodeBuilder::scroll_view()
+        .background(BackgroundBuilder::color(Color::rgba(0.0, 0.0, 0.0, 0.5)))
+        .position(
+            PositionBuilder::anchored(AnchorPoint::Center).size(Val::Px(300.0), Val::Px(400.0)),
+        )
+        .scroll(ScrollBuilder::default().momentum(8.0));
+
+    for i in 1 ..= 50 {
+        view = view.child(
+            UiNodeBuilder::text(
+                TextBuilder::default()
+                    .anchor_point(AnchorPoint::CenterLeft)
+                    .section(
+                        TextSectionBuilder::new(format!("Item #{i}"))
+                            .font("fonts/FiraMono-Medium.ttf")
+                            .color(Color::WHITE)
+                            .text_size(24.0),
+                    ),
+            )
+            .position(PositionBuilder::relative().size(Val::Percent(100.0), Val::Px(32.0))),
+        );
+    }
+
+    UiNodeBuilder::canvas().child(view).into()
+}
@@ -0,0 +1,48 @@
+//! Contains blocks related to collapsible sections.
+
+use bevy::prelude::*;
+
+use super::{NodeBackground, NodeText};
+
+/// A data block for defining the header and behavior of a collapsible
+/// section.
+#[derive(Debug, Clone)]
+pub struct NodeCollapsible {
+    /// The text displayed in the header, next to the icon.
+    pub header: NodeText,
+
+    /// The background of the header.
+    pub header_background: NodeBackground,
+
+    /// The height of the header.
+    pub header_height: Val,
+
+    /// Whether the section is initially expanded.
+    pub expanded: bool,
+
+    /// The time, in seconds, the body takes to expand or collapse. If zero,
+    /// the body is shown and hidden immediately.
+    pub animation_time: f32,
+
+    /// The text displayed on the icon of a collapsed section.
+    pub expand_icon: String,
+
+    /// The text displayed on the icon of an expanded section.
+    pub collapse_icon: String,
+}
+
+impl Default for NodeCollapsible {
+    fn default() -> Self {
+        Self {
+            header: Default::default(),
+            header_background: NodeBackground::Color {
+                color: Color::rgb(0.2, 0.2, 0.2),
+            },
+            header_height: Val::Px(28.0),
+            expanded: false,
+            animation_time: 0.15,
+            expand_icon: String::from(">"),
+            collapse_icon: String::from("v"),
+        }
+    }
+}
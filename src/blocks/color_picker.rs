@@ -0,0 +1,52 @@
+//! Contains blocks related to color pickers.
+
+use bevy::prelude::*;
+
+use super::NodeText;
+
+/// A data block for defining the initial color and layout of a color picker.
+#[derive(Debug, Clone)]
+pub struct NodeColorPicker {
+    /// The initial color of the color picker.
+    pub color: Color,
+
+    /// Whether the color picker displays a slider for the alpha channel.
+    pub show_alpha: bool,
+
+    /// The preset colors displayed below the color picker, which set the color
+    /// when clicked.
+    pub swatches: Vec<Color>,
+
+    /// The size of the saturation/value square, in logical pixels.
+    pub size: f32,
+
+    /// The thickness of the hue and alpha bars, and the size of each swatch,
+    /// in logical pixels.
+    pub bar_thickness: f32,
+
+    /// The space between each part of the color picker, in logical pixels.
+    pub spacing: f32,
+
+    /// The style of the text of the hex entry field.
+    ///
+    /// Only the style of the first section is used.
+    pub text: NodeText,
+
+    /// The background color of the hex entry field.
+    pub field_color: Color,
+}
+
+impl Default for NodeColorPicker {
+    fn default() -> Self {
+        Self {
+            color: Color::WHITE,
+            show_alpha: true,
+            swatches: Vec::new(),
+            size: 160.0,
+            bar_thickness: 20.0,
+            spacing: 8.0,
+            text: NodeText::styled(16.0, Color::WHITE),
+            field_color: Color::rgb(0.1, 0.1, 0.1),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Contains blocks related to dialogue boxes.
+
+use bevy::prelude::*;
+
+use super::{NodeBackground, NodeText};
+
+/// A data block for defining the initial line and style of a dialogue box.
+///
+/// The text of each line may contain inline cues, which are not displayed:
+///
+/// - `{pause=0.5}` pauses the reveal for the given number of seconds.
+/// - `{speed=2}` multiplies the reveal rate by the given factor until the end
+///   of the line, or until the next speed cue.
+///
+/// A literal `{` is written as `{{`.
+#[derive(Debug, Clone)]
+pub struct NodeDialogue {
+    /// The first line displayed by the dialogue box.
+    pub text: NodeText,
+
+    /// The name displayed on the name plate. The name plate is hidden if not
+    /// set.
+    pub speaker: Option<String>,
+
+    /// The style of the speaker name.
+    ///
+    /// Only the style of the first section is used.
+    pub speaker_text: NodeText,
+
+    /// The background of the name plate.
+    pub speaker_background: NodeBackground,
+
+    /// The path of the portrait image displayed beside the text. The portrait
+    /// is hidden if not set.
+    pub portrait: Option<String>,
+
+    /// The width and height of the portrait, in logical pixels.
+    pub portrait_size: f32,
+
+    /// The number of characters revealed each second.
+    pub chars_per_second: f32,
+
+    /// The text of the indicator displayed once the line has been fully
+    /// revealed.
+    pub continue_indicator: NodeText,
+
+    /// The time, in seconds, the continue indicator takes to blink on and off.
+    /// A time of zero disables blinking.
+    pub blink_time: f32,
+
+    /// The keys that skip to the end of the line, or continue to the next line
+    /// once the line has been fully revealed.
+    pub advance_keys: Vec<KeyCode>,
+
+    /// The space between the portrait, name plate, and text, in logical
+    /// pixels.
+    pub spacing: f32,
+}
+
+impl Default for NodeDialogue {
+    fn default() -> Self {
+        let mut continue_indicator = NodeText::styled(18.0, Color::WHITE);
+        continue_indicator.sections[0].text = String::from("v");
+
+        Self {
+            text: NodeText::styled(20.0, Color::WHITE),
+            speaker: None,
+            speaker_text: NodeText::styled(18.0, Color::WHITE),
+            speaker_background: NodeBackground::Color {
+                color: Color::rgb(0.15, 0.15, 0.2),
+            },
+            portrait: None,
+            portrait_size: 96.0,
+            chars_per_second: 30.0,
+            continue_indicator,
+            blink_time: 0.5,
+            advance_keys: vec![KeyCode::Space, KeyCode::Enter],
+            spacing: 8.0,
+        }
+    }
+}
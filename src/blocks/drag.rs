@@ -0,0 +1,113 @@
+//! Contains blocks related to dragging nodes and dropping them onto other
+//! nodes.
+
+use std::any::{Any, TypeId};
+use std::fmt;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::prelude::UiNode;
+
+/// The data carried by a dragged node, which may be of any type.
+///
+/// Drop targets only accept payloads of the types they are defined with.
+#[derive(Clone)]
+pub struct DragPayload {
+    /// The value of the payload.
+    value: Arc<dyn Any + Send + Sync>,
+
+    /// The name of the type of the value, used for debug output.
+    type_name: &'static str,
+}
+
+impl DragPayload {
+    /// Creates a new payload with the given value.
+    pub fn new<T: Any + Send + Sync>(value: T) -> Self {
+        Self {
+            value: Arc::new(value),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Gets the value of the payload, if it is of the given type.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+
+    /// Returns true if the value of the payload is of the given type.
+    pub fn is<T: Any>(&self) -> bool {
+        self.value.is::<T>()
+    }
+
+    /// Gets the type id of the value of the payload.
+    pub fn value_type_id(&self) -> TypeId {
+        self.value.as_ref().type_id()
+    }
+}
+
+impl fmt::Debug for DragPayload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DragPayload").field(&self.type_name).finish()
+    }
+}
+
+/// A data block for defining a node that may be dragged.
+#[derive(Debug, Clone)]
+pub struct NodeDraggable {
+    /// The data carried by the node while it is dragged.
+    pub payload: DragPayload,
+
+    /// The opacity of the node that follows the cursor while it is dragged.
+    pub ghost_opacity: f32,
+
+    /// The node that is built to follow the cursor while dragged. If not set,
+    /// a plain node with the size and background color of the dragged node is
+    /// used.
+    pub ghost: Option<Box<UiNode>>,
+}
+
+impl NodeDraggable {
+    /// Creates a new draggable block with the given payload.
+    pub fn new<T: Any + Send + Sync>(payload: T) -> Self {
+        Self {
+            payload: DragPayload::new(payload),
+            ghost_opacity: 0.6,
+            ghost: None,
+        }
+    }
+}
+
+/// A data block for defining a node that dragged nodes may be dropped onto.
+#[derive(Debug, Clone)]
+pub struct NodeDropTarget {
+    /// The types of payloads that may be dropped onto the node.
+    pub accepts: Vec<TypeId>,
+
+    /// The outline color of the node while an accepted payload is dragged.
+    pub highlight_color: Color,
+
+    /// The outline color of the node while an accepted payload is dragged
+    /// over it.
+    pub hover_color: Color,
+}
+
+impl NodeDropTarget {
+    /// Adds the given payload type to the types accepted by the node.
+    pub fn accept<T: Any>(&mut self) {
+        let type_id = TypeId::of::<T>();
+        if !self.accepts.contains(&type_id) {
+            self.accepts.push(type_id);
+        }
+    }
+}
+
+impl Default for NodeDropTarget {
+    fn default() -> Self {
+        Self {
+            accepts: Vec::new(),
+            highlight_color: Color::rgba(1.0, 1.0, 1.0, 0.4),
+            hover_color: Color::rgb(1.0, 0.85, 0.3),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Contains blocks related to key binding lists.
+
+use std::any::TypeId;
+
+use bevy::prelude::*;
+
+use super::{NodeBackground, NodeText};
+
+/// Defines a single action that may be bound to an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeBindingAction {
+    /// The identifier of the action, passed to the bindings resource.
+    pub id: String,
+
+    /// The label displayed beside the bindings of the action.
+    pub label: String,
+}
+
+/// A data block for defining the actions and style of a key binding list.
+#[derive(Debug, Clone)]
+pub struct NodeKeyBindings {
+    /// The type of the [`BindingSource`](crate::prelude::BindingSource)
+    /// resource that stores the bindings.
+    pub source: Option<TypeId>,
+
+    /// The actions displayed by the list.
+    pub actions: Vec<NodeBindingAction>,
+
+    /// The number of bindings each action may have, such as a primary and
+    /// secondary binding.
+    pub slots: usize,
+
+    /// The style of the action labels and binding buttons.
+    ///
+    /// Only the style of the first section is used.
+    pub text: NodeText,
+
+    /// The width of the action labels.
+    pub label_width: Val,
+
+    /// The width of each binding button, in logical pixels.
+    pub button_width: f32,
+
+    /// The height of each row, in logical pixels.
+    pub row_height: f32,
+
+    /// The space between each row and binding button, in logical pixels.
+    pub spacing: f32,
+
+    /// The background of each binding button.
+    pub button_background: NodeBackground,
+
+    /// The text displayed on a binding button while waiting for an input.
+    pub capture_text: String,
+
+    /// The text displayed on a binding button with no binding.
+    pub unbound_text: String,
+
+    /// The text color of bindings that are used by more than one action.
+    pub conflict_color: Color,
+
+    /// The key that cancels capturing an input.
+    pub cancel_key: KeyCode,
+}
+
+impl Default for NodeKeyBindings {
+    fn default() -> Self {
+        Self {
+            source: None,
+            actions: Vec::new(),
+            slots: 1,
+            text: NodeText::styled(18.0, Color::WHITE),
+            label_width: Val::Px(200.0),
+            button_width: 140.0,
+            row_height: 32.0,
+            spacing: 4.0,
+            button_background: NodeBackground::Color {
+                color: Color::rgb(0.2, 0.2, 0.25),
+            },
+            capture_text: "Press a key...".to_string(),
+            unbound_text: "-".to_string(),
+            conflict_color: Color::rgb(1.0, 0.35, 0.3),
+            cancel_key: KeyCode::Escape,
+        }
+    }
+}
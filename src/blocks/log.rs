@@ -0,0 +1,53 @@
+//! Contains blocks related to log views.
+
+use bevy::prelude::*;
+
+use super::{NodeScroll, NodeText};
+
+/// A data block for defining the behavior and style of a log view, such as a
+/// chat window or debug log.
+#[derive(Debug, Clone)]
+pub struct NodeLogView {
+    /// The largest number of lines kept in the history. Once reached, the
+    /// oldest line is replaced by each new line.
+    pub max_lines: usize,
+
+    /// The style of plain lines appended to the log.
+    ///
+    /// Only the style of the first section is used.
+    pub line_text: NodeText,
+
+    /// The space below each line, in logical pixels.
+    pub line_spacing: f32,
+
+    /// Whether each line is prefixed with the time it was appended.
+    pub show_timestamps: bool,
+
+    /// The color of the timestamps.
+    pub timestamp_color: Color,
+
+    /// If set, lines fade out once they are older than the given number of
+    /// seconds, unless the log view is hovered.
+    pub fade_after: Option<f32>,
+
+    /// The time, in seconds, old lines take to fade out.
+    pub fade_time: f32,
+
+    /// How the lines of the log view are scrolled.
+    pub scroll: NodeScroll,
+}
+
+impl Default for NodeLogView {
+    fn default() -> Self {
+        Self {
+            max_lines: 200,
+            line_text: NodeText::styled(16.0, Color::WHITE),
+            line_spacing: 2.0,
+            show_timestamps: false,
+            timestamp_color: Color::rgb(0.6, 0.6, 0.6),
+            fade_after: None,
+            fade_time: 1.0,
+            scroll: NodeScroll::default(),
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Contains blocks related to context menus and menu bars.
+
+use bevy::prelude::*;
+
+use super::{NodeBackground, NodeText};
+
+/// A single entry within a menu.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MenuEntry {
+    /// An item that sends a menu action event when clicked.
+    Action {
+        /// The user-defined id that is sent with the menu action event.
+        id: String,
+
+        /// The text displayed on the item.
+        label: String,
+
+        /// The keyboard accelerator displayed on the right side of the item,
+        /// if any.
+        ///
+        /// This is only displayed, and does not bind the key combination.
+        accelerator: Option<String>,
+
+        /// Whether the item may be clicked.
+        enabled: bool,
+
+        /// If set, the item is checkable, and this is whether it is currently
+        /// checked. Clicking the item toggles this value.
+        checked: Option<bool>,
+    },
+
+    /// An item that opens a nested menu while hovered.
+    Submenu {
+        /// The text displayed on the item.
+        label: String,
+
+        /// The entries of the nested menu.
+        entries: Vec<MenuEntry>,
+
+        /// Whether the nested menu may be opened.
+        enabled: bool,
+    },
+
+    /// A horizontal line that separates groups of items.
+    Separator,
+}
+
+impl MenuEntry {
+    /// Gets the entry at the given path of nested submenu indices, starting
+    /// from the given list of entries.
+    pub fn find<'a>(entries: &'a [MenuEntry], path: &[usize]) -> Option<&'a MenuEntry> {
+        let (first, rest) = path.split_first()?;
+        let entry = entries.get(*first)?;
+        match (rest.is_empty(), entry) {
+            (true, entry) => Some(entry),
+            (false, MenuEntry::Submenu { entries, .. }) => MenuEntry::find(entries, rest),
+            (false, _) => None,
+        }
+    }
+
+    /// Gets a mutable reference to the entry at the given path of nested
+    /// submenu indices, starting from the given list of entries.
+    pub fn find_mut<'a>(entries: &'a mut [MenuEntry], path: &[usize]) -> Option<&'a mut MenuEntry> {
+        let (first, rest) = path.split_first()?;
+        let entry = entries.get_mut(*first)?;
+        match (rest.is_empty(), entry) {
+            (true, entry) => Some(entry),
+            (false, MenuEntry::Submenu { entries, .. }) => MenuEntry::find_mut(entries, rest),
+            (false, _) => None,
+        }
+    }
+
+    /// Returns true if the entry may be interacted with.
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            MenuEntry::Action { enabled, .. } => *enabled,
+            MenuEntry::Submenu { enabled, .. } => *enabled,
+            MenuEntry::Separator => false,
+        }
+    }
+}
+
+/// Defines how the popups of a menu are displayed.
+#[derive(Debug, Clone)]
+pub struct MenuStyle {
+    /// The background of each popup.
+    pub background: NodeBackground,
+
+    /// The style of the text displayed on each item.
+    ///
+    /// Only the style of the first section is used.
+    pub text: NodeText,
+
+    /// The height of each item, in logical pixels.
+    pub item_height: f32,
+
+    /// The smallest width of each popup, in logical pixels.
+    pub min_width: f32,
+
+    /// The background color of the hovered item.
+    pub hover_color: Color,
+
+    /// The color of the text of disabled items.
+    pub disabled_color: Color,
+
+    /// The color of separators.
+    pub separator_color: Color,
+
+    /// The text displayed next to checked items.
+    pub check_icon: String,
+
+    /// The text displayed on the right side of items that open a submenu.
+    pub submenu_icon: String,
+}
+
+impl Default for MenuStyle {
+    fn default() -> Self {
+        Self {
+            background: NodeBackground::Color {
+                color: Color::rgb(0.15, 0.15, 0.15),
+            },
+            text: NodeText::styled(16.0, Color::WHITE),
+            item_height: 24.0,
+            min_width: 160.0,
+            hover_color: Color::rgba(0.3, 0.5, 0.8, 0.5),
+            disabled_color: Color::GRAY,
+            separator_color: Color::rgba(1.0, 1.0, 1.0, 0.2),
+            check_icon: String::from("x"),
+            submenu_icon: String::from(">"),
+        }
+    }
+}
+
+/// A data block for defining the entries and appearance of a menu.
+#[derive(Debug, Default, Clone)]
+pub struct NodeMenu {
+    /// The entries of the menu.
+    pub entries: Vec<MenuEntry>,
+
+    /// How the popups of the menu are displayed.
+    pub style: MenuStyle,
+}
+
+/// A single menu within a menu bar.
+#[derive(Debug, Default, Clone)]
+pub struct NodeMenuBarItem {
+    /// The text displayed on the button that opens the menu.
+    pub label: String,
+
+    /// The menu that is opened by the button.
+    pub menu: NodeMenu,
+}
+
+/// A data block for defining the menus of a menu bar.
+#[derive(Debug, Clone)]
+pub struct NodeMenuBar {
+    /// The menus of the menu bar, from left to right.
+    pub items: Vec<NodeMenuBarItem>,
+
+    /// The style of the text displayed on each button.
+    ///
+    /// Only the style of the first section is used.
+    pub text: NodeText,
+
+    /// The horizontal padding of each button.
+    pub button_padding: Val,
+
+    /// The background color of the button whose menu is open or hovered.
+    pub hover_color: Color,
+}
+
+impl Default for NodeMenuBar {
+    fn default() -> Self {
+        Self {
+            items: Default::default(),
+            text: MenuStyle::default().text,
+            button_padding: Val::Px(10.0),
+            hover_color: Color::rgba(0.3, 0.5, 0.8, 0.5),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates an enabled action entry with the given id.
+    fn action(id: &str) -> MenuEntry {
+        MenuEntry::Action {
+            id: id.to_string(),
+            label: id.to_string(),
+            accelerator: None,
+            enabled: true,
+            checked: None,
+        }
+    }
+
+    /// Creates a menu with an action, a separator, and a submenu that holds an
+    /// action and a nested submenu.
+    fn entries() -> Vec<MenuEntry> {
+        vec![
+            action("new"),
+            MenuEntry::Separator,
+            MenuEntry::Submenu {
+                label: "Recent".to_string(),
+                entries: vec![
+                    action("first"),
+                    MenuEntry::Submenu {
+                        label: "More".to_string(),
+                        entries: vec![action("second")],
+                        enabled: true,
+                    },
+                ],
+                enabled: true,
+            },
+        ]
+    }
+
+    #[test]
+    fn find_follows_submenu_path() {
+        let entries = entries();
+
+        assert_eq!(MenuEntry::find(&entries, &[0]), Some(&action("new")));
+        assert_eq!(MenuEntry::find(&entries, &[1]), Some(&MenuEntry::Separator));
+        assert_eq!(MenuEntry::find(&entries, &[2, 0]), Some(&action("first")));
+        assert_eq!(
+            MenuEntry::find(&entries, &[2, 1, 0]),
+            Some(&action("second"))
+        );
+        assert!(matches!(
+            MenuEntry::find(&entries, &[2, 1]),
+            Some(MenuEntry::Submenu { label, .. }) if label == "More"
+        ));
+    }
+
+    #[test]
+    fn find_rejects_invalid_paths() {
+        let entries = entries();
+
+        assert_eq!(MenuEntry::find(&entries, &[]), None);
+        assert_eq!(MenuEntry::find(&entries, &[3]), None);
+        assert_eq!(MenuEntry::find(&entries, &[2, 2]), None);
+        assert_eq!(MenuEntry::find(&entries, &[0, 0]), None);
+        assert_eq!(MenuEntry::find(&entries, &[1, 0]), None);
+    }
+
+    #[test]
+    fn find_mut_edits_nested_entry() {
+        let mut entries = entries();
+
+        if let Some(MenuEntry::Action { checked, .. }) =
+            MenuEntry::find_mut(&mut entries, &[2, 1, 0])
+        {
+            *checked = Some(true);
+        }
+
+        assert!(matches!(
+            MenuEntry::find(&entries, &[2, 1, 0]),
+            Some(MenuEntry::Action {
+                checked: Some(true),
+                ..
+            })
+        ));
+    }
+}
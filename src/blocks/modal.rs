@@ -0,0 +1,61 @@
+//! Contains blocks related to modal dialogs.
+
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use super::DataBlock;
+use crate::prelude::{Modal, NodeBundleBuilder, UiLayer};
+
+/// A data block for defining how a modal dialog blocks the UI below it.
+#[derive(Debug, Clone)]
+pub struct NodeModal {
+    /// An identifier for the modal, which is included in the event sent when
+    /// the modal is closed.
+    pub id: String,
+
+    /// The color of the full-screen scrim displayed behind the dialog.
+    pub scrim: Color,
+
+    /// If true, the modal may be cancelled by clicking on the scrim or by
+    /// pressing escape.
+    pub dismissible: bool,
+}
+
+impl Default for NodeModal {
+    fn default() -> Self {
+        Self {
+            id: Default::default(),
+            scrim: Color::rgba(0.0, 0.0, 0.0, 0.5),
+            dismissible: true,
+        }
+    }
+}
+
+impl DataBlock for NodeModal {
+    fn apply_to_node(self, node: &mut NodeBundleBuilder, _: &AssetServer) {
+        node.insert(FocusPolicy::Block);
+
+        let style = node.get_style_mut();
+        style.flex_direction = FlexDirection::Column;
+    }
+
+    fn apply_to_parent(&self, node: &mut NodeBundleBuilder, _: &AssetServer) {
+        node.insert((
+            Modal {
+                id: self.id.clone(),
+                dismissible: self.dismissible,
+            },
+            UiLayer::Modal,
+            BackgroundColor(self.scrim),
+            Interaction::default(),
+            FocusPolicy::Block,
+        ));
+
+        let style = node.get_style_mut();
+        style.position_type = PositionType::Absolute;
+        style.left = Val::Px(0.0);
+        style.top = Val::Px(0.0);
+        style.width = Val::Percent(100.0);
+        style.height = Val::Percent(100.0);
+    }
+}
@@ -38,6 +38,27 @@ pub enum AnchorPoint {
     BottomRight,
 }
 
+impl AnchorPoint {
+    /// Gets the direction of this anchor point from the center of the parent
+    /// element.
+    ///
+    /// The x component is -1 for left, 0 for center, and 1 for right. The y
+    /// component is -1 for top, 0 for center, and 1 for bottom.
+    pub fn direction(self) -> IVec2 {
+        match self {
+            AnchorPoint::TopLeft => IVec2::new(-1, -1),
+            AnchorPoint::TopCenter => IVec2::new(0, -1),
+            AnchorPoint::TopRight => IVec2::new(1, -1),
+            AnchorPoint::CenterLeft => IVec2::new(-1, 0),
+            AnchorPoint::Center => IVec2::new(0, 0),
+            AnchorPoint::CenterRight => IVec2::new(1, 0),
+            AnchorPoint::BottomLeft => IVec2::new(-1, 1),
+            AnchorPoint::BottomCenter => IVec2::new(0, 1),
+            AnchorPoint::BottomRight => IVec2::new(1, 1),
+        }
+    }
+}
+
 /// A data block for defining how a node is anchored to it's parent.
 #[derive(Debug, Clone)]
 pub enum NodePosition {
@@ -0,0 +1,234 @@
+//! Contains blocks related to displaying the progress of a value.
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use super::{DataBlock, NodeBackground, NodeText};
+use crate::prelude::{
+    NodeBundleBuilder,
+    NodeBundleType,
+    ProgressBar,
+    ProgressBinding,
+    ProgressIndicator,
+    RadialProgressMaterial,
+};
+
+/// The direction in which the fill of a progress bar grows.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum ProgressDirection {
+    /// The fill grows from the left edge towards the right edge.
+    ///
+    /// Radial progress bars fill clockwise.
+    #[default]
+    LeftToRight,
+
+    /// The fill grows from the right edge towards the left edge.
+    ///
+    /// Radial progress bars fill counter-clockwise.
+    RightToLeft,
+
+    /// The fill grows from the top edge towards the bottom edge.
+    ///
+    /// Radial progress bars fill clockwise.
+    TopToBottom,
+
+    /// The fill grows from the bottom edge towards the top edge.
+    ///
+    /// Radial progress bars fill counter-clockwise.
+    BottomToTop,
+}
+
+impl ProgressDirection {
+    /// Returns true if the fill grows along the horizontal axis.
+    pub fn horizontal(self) -> bool {
+        matches!(
+            self,
+            ProgressDirection::LeftToRight | ProgressDirection::RightToLeft
+        )
+    }
+
+    /// Returns true if a radial fill in this direction grows clockwise.
+    pub fn clockwise(self) -> bool {
+        matches!(
+            self,
+            ProgressDirection::LeftToRight | ProgressDirection::TopToBottom
+        )
+    }
+}
+
+/// Defines how the fill of a progress bar is displayed.
+#[derive(Debug, Clone)]
+pub enum ProgressStyle {
+    /// The fill is a node that is resized along the fill direction.
+    ///
+    /// Sliced image backgrounds keep their borders intact as the fill grows.
+    Linear {
+        /// The background of the fill.
+        fill: NodeBackground,
+    },
+
+    /// The fill is a ring that is swept around the center of the node,
+    /// starting from the top.
+    Radial {
+        /// The color of the filled part of the ring.
+        fill: Color,
+
+        /// The color of the unfilled part of the ring.
+        track: Color,
+
+        /// The thickness of the ring, as a fraction of its radius.
+        thickness: f32,
+    },
+}
+
+impl Default for ProgressStyle {
+    fn default() -> Self {
+        ProgressStyle::Linear {
+            fill: NodeBackground::Color {
+                color: Color::WHITE,
+            },
+        }
+    }
+}
+
+/// A shared function that reads a value from the world, if it exists.
+type ValueReader = Arc<dyn Fn(&World) -> Option<f32> + Send + Sync>;
+
+/// A binding that reads the value of a progress bar from the world each frame.
+#[derive(Clone)]
+pub struct ValueBinding(ValueReader);
+
+impl ValueBinding {
+    /// Creates a new binding that reads a value from the world using the given
+    /// function.
+    pub fn new<F>(func: F) -> Self
+    where
+        F: Fn(&World) -> Option<f32> + Send + Sync + 'static,
+    {
+        Self(Arc::new(func))
+    }
+
+    /// Creates a new binding that reads a value from the given resource.
+    ///
+    /// The bound value is left unchanged while the resource does not exist.
+    pub fn resource<R, F>(func: F) -> Self
+    where
+        R: Resource,
+        F: Fn(&R) -> f32 + Send + Sync + 'static,
+    {
+        Self::new(move |world| world.get_resource::<R>().map(&func))
+    }
+
+    /// Creates a new binding that reads a value from a component of the given
+    /// entity.
+    ///
+    /// The bound value is left unchanged while the component does not exist.
+    pub fn component<C, F>(entity: Entity, func: F) -> Self
+    where
+        C: Component,
+        F: Fn(&C) -> f32 + Send + Sync + 'static,
+    {
+        Self::new(move |world| world.get::<C>(entity).map(&func))
+    }
+
+    /// Reads the current value of this binding.
+    pub fn get(&self, world: &World) -> Option<f32> {
+        (self.0)(world)
+    }
+}
+
+impl Debug for ValueBinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ValueBinding")
+    }
+}
+
+/// A data block for defining how a progress bar displays its value.
+#[derive(Debug, Default, Clone)]
+pub struct NodeProgress {
+    /// The initial value of the progress bar, between `0.0` and `1.0`.
+    pub value: f32,
+
+    /// The direction in which the fill grows.
+    pub direction: ProgressDirection,
+
+    /// How the fill is displayed.
+    pub style: ProgressStyle,
+
+    /// The label displayed over the progress bar, if any.
+    pub label: Option<NodeText>,
+
+    /// Whether the text of the label is replaced with the value of the
+    /// progress bar as a percentage.
+    pub percent_label: bool,
+
+    /// The binding the value of the progress bar is read from, if any.
+    pub binding: Option<ValueBinding>,
+}
+
+impl DataBlock for NodeProgress {
+    fn apply_to_node(self, node: &mut NodeBundleBuilder, asset_server: &AssetServer) {
+        let value = self.value.clamp(0.0, 1.0);
+
+        let style = node.get_style_mut();
+        style.position_type = PositionType::Absolute;
+        style.width = Val::Percent(100.0);
+        style.height = Val::Percent(100.0);
+
+        match self.style {
+            ProgressStyle::Linear { fill } => {
+                fill.apply_to_node(node, asset_server);
+
+                let style = node.get_style_mut();
+                match self.direction {
+                    ProgressDirection::LeftToRight => style.left = Val::Px(0.0),
+                    ProgressDirection::RightToLeft => style.right = Val::Px(0.0),
+                    ProgressDirection::TopToBottom => style.top = Val::Px(0.0),
+                    ProgressDirection::BottomToTop => style.bottom = Val::Px(0.0),
+                }
+
+                if self.direction.horizontal() {
+                    style.width = Val::Percent(value * 100.0);
+                } else {
+                    style.height = Val::Percent(value * 100.0);
+                }
+            }
+
+            ProgressStyle::Radial {
+                fill,
+                track,
+                thickness,
+            } => {
+                let material = RadialProgressMaterial {
+                    fill,
+                    track,
+                    params: Vec4::new(
+                        value,
+                        thickness.clamp(0.0, 1.0),
+                        if self.direction.clockwise() { 1.0 } else { 0.0 },
+                        0.0,
+                    ),
+                };
+
+                node.bundle_type(NodeBundleType::Material);
+                node.insert(asset_server.add(material));
+            }
+        }
+
+        node.insert(ProgressIndicator);
+    }
+
+    fn apply_to_parent(&self, node: &mut NodeBundleBuilder, _: &AssetServer) {
+        node.insert(ProgressBar {
+            value: self.value.clamp(0.0, 1.0),
+            direction: self.direction,
+            percent_label: self.percent_label,
+        });
+
+        if let Some(binding) = &self.binding {
+            node.insert(ProgressBinding(binding.clone()));
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Contains blocks related to groups of mutually exclusive options.
+
+use bevy::prelude::*;
+
+use super::{DataBlock, NodeBackground, NodeText};
+use crate::prelude::{Focusable, NodeBundleBuilder, RadioGroup, SelectedIndex};
+
+/// A data block for defining a group of mutually exclusive options.
+#[derive(Debug, Clone)]
+pub struct NodeRadioGroup {
+    /// The labels of the options within the group.
+    pub options: Vec<NodeText>,
+
+    /// The index of the option that is initially selected, if any.
+    pub selected: Option<usize>,
+
+    /// Whether the options are laid out in a row instead of a column.
+    pub horizontal: bool,
+
+    /// The space between each option, and between an indicator and its label.
+    pub gap: Val,
+
+    /// The size of the indicator displayed next to each option.
+    pub indicator_size: Val,
+
+    /// The background of the indicator of each unselected option.
+    pub indicator: NodeBackground,
+
+    /// The background of the indicator of the selected option.
+    pub selected_indicator: NodeBackground,
+}
+
+impl Default for NodeRadioGroup {
+    fn default() -> Self {
+        Self {
+            options: Default::default(),
+            selected: None,
+            horizontal: false,
+            gap: Val::Px(8.0),
+            indicator_size: Val::Px(16.0),
+            indicator: NodeBackground::Color {
+                color: Color::rgb(0.25, 0.25, 0.25),
+            },
+            selected_indicator: NodeBackground::Color {
+                color: Color::WHITE,
+            },
+        }
+    }
+}
+
+impl DataBlock for NodeRadioGroup {
+    fn apply_to_node(self, node: &mut NodeBundleBuilder, _: &AssetServer) {
+        let selected = self.selected.filter(|&index| index < self.options.len());
+
+        node.insert((
+            RadioGroup {
+                count: self.options.len(),
+                shown: selected,
+            },
+            SelectedIndex(selected),
+            Interaction::default(),
+            Focusable,
+        ));
+
+        let style = node.get_style_mut();
+        style.flex_direction = if self.horizontal {
+            FlexDirection::Row
+        } else {
+            FlexDirection::Column
+        };
+        style.row_gap = self.gap;
+        style.column_gap = self.gap;
+    }
+}
@@ -0,0 +1,127 @@
+//! Contains blocks related to scrolling the contents of a node.
+
+use bevy::prelude::*;
+
+use super::{DataBlock, NodeBackground};
+use crate::prelude::{Focusable, NodeBundleBuilder, ScrollContent, ScrollPosition, ScrollView};
+
+/// The axes along which a scroll view may be scrolled.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum ScrollDirection {
+    /// The contents may only be scrolled vertically.
+    #[default]
+    Vertical,
+
+    /// The contents may only be scrolled horizontally.
+    Horizontal,
+
+    /// The contents may be scrolled both vertically and horizontally.
+    Both,
+}
+
+impl ScrollDirection {
+    /// Returns true if this direction allows for horizontal scrolling.
+    pub fn horizontal(self) -> bool {
+        matches!(self, ScrollDirection::Horizontal | ScrollDirection::Both)
+    }
+
+    /// Returns true if this direction allows for vertical scrolling.
+    pub fn vertical(self) -> bool {
+        matches!(self, ScrollDirection::Vertical | ScrollDirection::Both)
+    }
+}
+
+/// Defines how the scrollbars of a scroll view are displayed.
+#[derive(Debug, Clone)]
+pub struct NodeScrollbar {
+    /// The thickness of the scrollbar, in logical pixels.
+    pub thickness: f32,
+
+    /// The background of the scrollbar track.
+    pub track: NodeBackground,
+
+    /// The background of the draggable scrollbar thumb.
+    pub thumb: NodeBackground,
+}
+
+impl Default for NodeScrollbar {
+    fn default() -> Self {
+        Self {
+            thickness: 8.0,
+            track: NodeBackground::Color {
+                color: Color::rgba(0.0, 0.0, 0.0, 0.25),
+            },
+            thumb: NodeBackground::Color {
+                color: Color::rgba(1.0, 1.0, 1.0, 0.5),
+            },
+        }
+    }
+}
+
+/// A data block for defining how the contents of a node are scrolled.
+#[derive(Debug, Clone)]
+pub struct NodeScroll {
+    /// The axes along which the contents may be scrolled.
+    pub direction: ScrollDirection,
+
+    /// The number of logical pixels to scroll per mouse wheel line or key
+    /// press.
+    pub scroll_speed: f32,
+
+    /// If set, scrolling carries momentum that decays at the given rate per
+    /// second. Higher values cause the contents to stop sooner.
+    pub momentum: Option<f32>,
+
+    /// The scrollbars to display, if any.
+    pub scrollbar: Option<NodeScrollbar>,
+}
+
+impl Default for NodeScroll {
+    fn default() -> Self {
+        Self {
+            direction: Default::default(),
+            scroll_speed: 32.0,
+            momentum: None,
+            scrollbar: Some(Default::default()),
+        }
+    }
+}
+
+impl DataBlock for NodeScroll {
+    fn apply_to_node(self, node: &mut NodeBundleBuilder, _: &AssetServer) {
+        let style = node.get_style_mut();
+        style.position_type = PositionType::Absolute;
+        style.flex_direction = FlexDirection::Column;
+        style.left = Val::Px(0.0);
+        style.top = Val::Px(0.0);
+        style.min_width = Val::Percent(100.0);
+        style.min_height = Val::Percent(100.0);
+
+        if !self.direction.horizontal() {
+            style.width = Val::Percent(100.0);
+        }
+
+        if !self.direction.vertical() {
+            style.height = Val::Percent(100.0);
+        }
+
+        node.insert(ScrollContent);
+    }
+
+    fn apply_to_parent(&self, node: &mut NodeBundleBuilder, _: &AssetServer) {
+        let style = node.get_style_mut();
+        style.overflow = Overflow::clip();
+
+        node.insert((
+            ScrollView {
+                direction: self.direction,
+                scroll_speed: self.scroll_speed,
+                momentum: self.momentum,
+                ..default()
+            },
+            ScrollPosition::default(),
+            Interaction::default(),
+            Focusable,
+        ));
+    }
+}
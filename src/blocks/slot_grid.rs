@@ -0,0 +1,82 @@
+//! Contains blocks related to inventory slot grids.
+
+use bevy::prelude::*;
+
+use super::{AnchorPoint, NodeBackground, NodeText};
+
+/// A data block for defining the layout and style of a slot grid, such as an
+/// inventory or hotbar.
+#[derive(Debug, Clone)]
+pub struct NodeSlotGrid {
+    /// The entity with the [`SlotSource`](crate::prelude::SlotSource)
+    /// component that provides the contents of the slots.
+    pub source: Option<Entity>,
+
+    /// The number of slots in each row.
+    pub columns: u16,
+
+    /// The number of rows of slots.
+    pub rows: u16,
+
+    /// The width and height of each slot, in logical pixels.
+    pub slot_size: f32,
+
+    /// The space between each slot, in logical pixels.
+    pub spacing: f32,
+
+    /// The background of each slot, which is usually a sliced frame image.
+    pub slot_background: NodeBackground,
+
+    /// The space between the edge of a slot and its item icon, in logical
+    /// pixels.
+    pub icon_padding: f32,
+
+    /// The style of the stack count text of each slot.
+    ///
+    /// Only the style of the first section is used.
+    pub count_text: NodeText,
+
+    /// The corner of each slot the stack count is displayed in.
+    pub count_anchor: AnchorPoint,
+
+    /// Whether the first ten slots display a number label, and may be selected
+    /// by pressing the matching number key.
+    pub hotkeys: bool,
+
+    /// The style of the hotkey number labels.
+    ///
+    /// Only the style of the first section is used.
+    pub hotkey_text: NodeText,
+
+    /// The index of the initially selected slot, if any.
+    pub selected: Option<usize>,
+
+    /// The border color of the selected slot.
+    pub selection_color: Color,
+
+    /// The border width of the selected slot, in logical pixels.
+    pub selection_width: f32,
+}
+
+impl Default for NodeSlotGrid {
+    fn default() -> Self {
+        Self {
+            source: None,
+            columns: 9,
+            rows: 1,
+            slot_size: 48.0,
+            spacing: 4.0,
+            slot_background: NodeBackground::Color {
+                color: Color::rgba(0.0, 0.0, 0.0, 0.5),
+            },
+            icon_padding: 6.0,
+            count_text: NodeText::styled(14.0, Color::WHITE),
+            count_anchor: AnchorPoint::BottomRight,
+            hotkeys: false,
+            hotkey_text: NodeText::styled(11.0, Color::rgb(0.8, 0.8, 0.8)),
+            selected: None,
+            selection_color: Color::rgb(1.0, 0.85, 0.3),
+            selection_width: 2.0,
+        }
+    }
+}
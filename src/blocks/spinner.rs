@@ -0,0 +1,125 @@
+//! Contains blocks related to numeric entry nodes.
+
+use bevy::prelude::*;
+
+use super::{DataBlock, NodeBackground, NodeText};
+use crate::prelude::{
+    Focusable,
+    KeyboardLayout,
+    NodeBundleBuilder,
+    Spinner,
+    VirtualKeyboardTarget,
+};
+
+/// Defines how the value of a spinner is formatted and rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum NumberFormat {
+    /// The value is rounded to the nearest whole number.
+    Integer,
+
+    /// The value is displayed with a fixed number of decimal places.
+    Float {
+        /// The number of digits to display after the decimal point.
+        decimals: usize,
+    },
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat::Float { decimals: 2 }
+    }
+}
+
+impl NumberFormat {
+    /// Formats the given value as text.
+    pub fn format(self, value: f32) -> String {
+        match self {
+            NumberFormat::Integer => format!("{}", value.round() as i64),
+            NumberFormat::Float { decimals } => format!("{value:.decimals$}"),
+        }
+    }
+}
+
+/// A data block for defining a numeric entry node with buttons that step the
+/// value up and down.
+#[derive(Debug, Clone)]
+pub struct NodeSpinner {
+    /// The initial value of the spinner.
+    pub value: f32,
+
+    /// The smallest value the spinner may hold.
+    pub min: f32,
+
+    /// The largest value the spinner may hold.
+    pub max: f32,
+
+    /// The amount the value changes per button press, arrow key, or scrub
+    /// increment.
+    pub step: f32,
+
+    /// How the value is formatted and rounded.
+    pub format: NumberFormat,
+
+    /// The distance, in logical pixels, the label must be dragged to change
+    /// the value by one step.
+    pub scrub_distance: f32,
+
+    /// The style of the text used to display the value and the button labels.
+    ///
+    /// Only the style of the first section is used.
+    pub text: NodeText,
+
+    /// The background of the increment and decrement buttons.
+    pub button_background: NodeBackground,
+
+    /// The width of the increment and decrement buttons.
+    pub button_width: Val,
+}
+
+impl Default for NodeSpinner {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            min: f32::NEG_INFINITY,
+            max: f32::INFINITY,
+            step: 1.0,
+            format: Default::default(),
+            scrub_distance: 4.0,
+            text: NodeText::styled(16.0, Color::WHITE),
+            button_background: NodeBackground::Color {
+                color: Color::rgb(0.3, 0.3, 0.3),
+            },
+            button_width: Val::Px(24.0),
+        }
+    }
+}
+
+impl DataBlock for NodeSpinner {
+    fn apply_to_node(self, node: &mut NodeBundleBuilder, _: &AssetServer) {
+        let mut spinner = Spinner {
+            value: self.value,
+            min: self.min,
+            max: self.max,
+            step: self.step,
+            format: self.format,
+            scrub_distance: self.scrub_distance,
+            shown: 0.0,
+            entry: None,
+        };
+        spinner.value = spinner.constrain(spinner.value);
+        spinner.shown = spinner.value;
+
+        node.insert((
+            spinner,
+            Interaction::default(),
+            Focusable,
+            VirtualKeyboardTarget {
+                layout: KeyboardLayout::Numeric,
+            },
+        ));
+
+        let style = node.get_style_mut();
+        style.flex_direction = FlexDirection::Row;
+        style.align_items = AlignItems::Stretch;
+    }
+}
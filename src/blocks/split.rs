@@ -0,0 +1,68 @@
+//! Contains blocks related to dividing a node between two panes.
+
+use bevy::prelude::*;
+
+/// The direction in which a split pane divides its area.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum SplitDirection {
+    /// The panes are placed side by side, with a vertical divider between
+    /// them.
+    #[default]
+    Horizontal,
+
+    /// The panes are stacked on top of each other, with a horizontal divider
+    /// between them.
+    Vertical,
+}
+
+impl SplitDirection {
+    /// Gets the component of the given vector along the axis of this
+    /// direction.
+    pub fn axis(self, vec: Vec2) -> f32 {
+        match self {
+            SplitDirection::Horizontal => vec.x,
+            SplitDirection::Vertical => vec.y,
+        }
+    }
+}
+
+/// A data block for defining how a split pane divides its area.
+#[derive(Debug, Clone)]
+pub struct NodeSplit {
+    /// The direction in which the area is divided.
+    pub direction: SplitDirection,
+
+    /// The initial fraction of the area given to the first pane, between
+    /// `0.0` and `1.0`.
+    pub ratio: f32,
+
+    /// The smallest sizes of the first and second panes while dragging the
+    /// divider, in logical pixels.
+    pub min_sizes: [f32; 2],
+
+    /// The thickness of the divider, in logical pixels.
+    pub divider_size: f32,
+
+    /// The color of the divider.
+    pub divider_color: Color,
+
+    /// The color of the divider while it is hovered or dragged.
+    pub divider_hover_color: Color,
+
+    /// Whether double-clicking the divider collapses the smaller pane.
+    pub collapsible: bool,
+}
+
+impl Default for NodeSplit {
+    fn default() -> Self {
+        Self {
+            direction: SplitDirection::Horizontal,
+            ratio: 0.5,
+            min_sizes: [32.0, 32.0],
+            divider_size: 6.0,
+            divider_color: Color::rgb(0.25, 0.25, 0.25),
+            divider_hover_color: Color::rgb(0.35, 0.45, 0.6),
+            collapsible: true,
+        }
+    }
+}
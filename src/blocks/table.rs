@@ -0,0 +1,200 @@
+//! Contains blocks related to tables of rows and columns.
+
+use std::cmp::Ordering;
+
+use bevy::prelude::*;
+
+use super::{AnchorPoint, NodeBackground, NodeText};
+use crate::prelude::UiNode;
+
+/// The order in which the rows of a table are sorted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum SortOrder {
+    /// The rows are sorted from the smallest value to the largest value.
+    #[default]
+    Ascending,
+
+    /// The rows are sorted from the largest value to the smallest value.
+    Descending,
+}
+
+impl SortOrder {
+    /// Gets the opposite sort order.
+    pub fn reversed(self) -> Self {
+        match self {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+}
+
+/// Defines a single column of a table.
+#[derive(Debug, Clone)]
+pub struct NodeTableColumn {
+    /// The text displayed in the header of the column.
+    pub header: NodeText,
+
+    /// The initial width of the column, in logical pixels.
+    pub width: f32,
+
+    /// The smallest width the column may be resized to, in logical pixels.
+    pub min_width: f32,
+
+    /// How the contents of each cell are aligned within the column.
+    pub anchor: AnchorPoint,
+
+    /// Whether the rows may be sorted by clicking the header of the column.
+    pub sortable: bool,
+}
+
+impl Default for NodeTableColumn {
+    fn default() -> Self {
+        Self {
+            header: Default::default(),
+            width: 120.0,
+            min_width: 24.0,
+            anchor: AnchorPoint::CenterLeft,
+            sortable: true,
+        }
+    }
+}
+
+/// Defines the contents of a single cell of a table.
+#[derive(Debug, Clone)]
+pub enum TableCell {
+    /// The cell displays a string using the cell text style of the table.
+    Text(String),
+
+    /// The cell displays a node.
+    Node {
+        /// The node displayed within the cell.
+        node: Box<UiNode>,
+
+        /// The value used to sort the cell.
+        sort_key: String,
+    },
+}
+
+impl TableCell {
+    /// Gets the value used to sort the cell.
+    pub fn sort_key(&self) -> &str {
+        match self {
+            TableCell::Text(text) => text,
+            TableCell::Node { sort_key, .. } => sort_key,
+        }
+    }
+
+    /// Compares the sort keys of two cells.
+    ///
+    /// If both keys are numbers they are compared numerically, otherwise they
+    /// are compared as strings.
+    pub fn compare(&self, other: &TableCell) -> Ordering {
+        let (a, b) = (self.sort_key(), other.sort_key());
+        match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+            (Ok(a), Ok(b)) => a.total_cmp(&b),
+            _ => a.cmp(b),
+        }
+    }
+}
+
+impl From<String> for TableCell {
+    fn from(text: String) -> Self {
+        TableCell::Text(text)
+    }
+}
+
+impl From<&str> for TableCell {
+    fn from(text: &str) -> Self {
+        TableCell::Text(text.to_string())
+    }
+}
+
+impl From<UiNode> for TableCell {
+    fn from(node: UiNode) -> Self {
+        TableCell::Node {
+            node: Box::new(node),
+            sort_key: String::new(),
+        }
+    }
+}
+
+/// Defines a single row of a table.
+#[derive(Debug, Default, Clone)]
+pub struct NodeTableRow {
+    /// The cells of the row, in column order.
+    pub cells: Vec<TableCell>,
+}
+
+impl<T: Into<TableCell>> From<Vec<T>> for NodeTableRow {
+    fn from(cells: Vec<T>) -> Self {
+        Self {
+            cells: cells.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A data block for defining the columns, rows, and appearance of a table.
+#[derive(Debug, Clone)]
+pub struct NodeTable {
+    /// The columns of the table.
+    pub columns: Vec<NodeTableColumn>,
+
+    /// The rows of the table.
+    pub rows: Vec<NodeTableRow>,
+
+    /// The column and order the rows are initially sorted by, if any.
+    pub sort: Option<(usize, SortOrder)>,
+
+    /// Whether the columns may be resized by dragging the edges of their
+    /// headers.
+    pub resizable: bool,
+
+    /// If set, only the visible rows are spawned, along with the given number
+    /// of additional rows above and below the visible area.
+    pub virtualized: Option<usize>,
+
+    /// The height of the header, in logical pixels.
+    pub header_height: f32,
+
+    /// The background of the header.
+    pub header_background: NodeBackground,
+
+    /// The height of each row, in logical pixels.
+    pub row_height: f32,
+
+    /// The background colors of even and odd rows.
+    pub row_colors: [Color; 2],
+
+    /// The background color of the selected row.
+    pub selected_color: Color,
+
+    /// The style of the text displayed in text cells.
+    ///
+    /// Only the style of the first section is used.
+    pub cell_text: NodeText,
+
+    /// The text displayed next to the header of a column sorted in ascending
+    /// and descending order.
+    pub sort_icons: [String; 2],
+}
+
+impl Default for NodeTable {
+    fn default() -> Self {
+        Self {
+            columns: Default::default(),
+            rows: Default::default(),
+            sort: None,
+            resizable: true,
+            virtualized: None,
+            header_height: 28.0,
+            header_background: NodeBackground::Color {
+                color: Color::rgb(0.2, 0.2, 0.2),
+            },
+            row_height: 24.0,
+            row_colors: [Color::NONE, Color::rgba(1.0, 1.0, 1.0, 0.04)],
+            selected_color: Color::rgba(0.3, 0.5, 0.8, 0.5),
+            cell_text: NodeText::styled(16.0, Color::WHITE),
+            sort_icons: [String::from(" ^"), String::from(" v")],
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Contains blocks related to tab containers.
+
+use bevy::prelude::*;
+
+use super::{DataBlock, NodeBackground, NodeText};
+use crate::prelude::{NodeBundleBuilder, Tabs, UiNode};
+
+/// Defines a single tab within a tab container.
+#[derive(Debug, Clone)]
+pub struct NodeTab {
+    /// The text displayed on the tab button.
+    pub title: NodeText,
+
+    /// The contents displayed while this tab is active.
+    pub content: UiNode,
+}
+
+/// A data block for defining the tabs of a tab container.
+#[derive(Debug, Clone)]
+pub struct NodeTabs {
+    /// The tabs within the container.
+    pub tabs: Vec<NodeTab>,
+
+    /// The index of the tab that is initially active.
+    pub active: usize,
+
+    /// If true, the contents of each tab are only built when the tab is shown
+    /// for the first time.
+    pub lazy: bool,
+
+    /// The background of the header strip containing the tab buttons.
+    pub header_background: NodeBackground,
+
+    /// The height of the header strip.
+    pub header_height: Val,
+
+    /// The background of each tab button.
+    pub tab_background: NodeBackground,
+
+    /// The width of each tab button.
+    pub tab_width: Val,
+
+    /// The tint color of inactive tab buttons.
+    pub tab_color: Color,
+
+    /// The tint color of the active tab button.
+    pub active_tab_color: Color,
+}
+
+impl Default for NodeTabs {
+    fn default() -> Self {
+        Self {
+            tabs: Default::default(),
+            active: 0,
+            lazy: false,
+            header_background: Default::default(),
+            header_height: Val::Px(32.0),
+            tab_background: NodeBackground::Color {
+                color: Color::WHITE,
+            },
+            tab_width: Val::Px(120.0),
+            tab_color: Color::rgb(0.25, 0.25, 0.25),
+            active_tab_color: Color::rgb(0.45, 0.45, 0.45),
+        }
+    }
+}
+
+impl DataBlock for NodeTabs {
+    fn apply_to_node(self, node: &mut NodeBundleBuilder, asset_server: &AssetServer) {
+        self.header_background.apply_to_node(node, asset_server);
+
+        let style = node.get_style_mut();
+        style.flex_direction = FlexDirection::Row;
+        style.flex_shrink = 0.0;
+        style.width = Val::Percent(100.0);
+        style.height = self.header_height;
+    }
+
+    fn apply_to_parent(&self, node: &mut NodeBundleBuilder, _: &AssetServer) {
+        node.get_style_mut().flex_direction = FlexDirection::Column;
+        node.insert(Tabs {
+            active: self.active,
+            count: self.tabs.len(),
+            shown: None,
+        });
+    }
+}
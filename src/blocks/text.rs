@@ -4,7 +4,7 @@ use bevy::prelude::*;
 use bevy::text::BreakLineOn;
 
 use super::{AnchorPoint, DataBlock};
-use crate::prelude::{NodeBundleBuilder, NodeBundleType};
+use crate::prelude::{NodeBundleBuilder, NodeBundleType, TextLink, TextLinks};
 
 /// Defines a section of text.
 #[derive(Debug, Default, Clone)]
@@ -13,6 +13,8 @@ pub struct NodeTextSection {
     pub text: String,
 
     /// The font to use for the text.
+    ///
+    /// If empty, Bevy's built-in default font is used.
     pub font: String,
 
     /// The size of the text.
@@ -20,6 +22,43 @@ pub struct NodeTextSection {
 
     /// The color of the text.
     pub color: Color,
+
+    /// If set, this section is displayed as an inline image with the given
+    /// path instead of text, scaled to the line height of the text size.
+    ///
+    /// The text and color of image sections are ignored. Images are only
+    /// displayed by text nodes, and are skipped by other nodes that display
+    /// text.
+    pub image: Option<String>,
+
+    /// If set, this section is a link that may be clicked.
+    pub link: Option<NodeTextLink>,
+}
+
+/// Defines a section of text that may be clicked, sending a
+/// [`LinkClicked`](crate::prelude::LinkClicked) event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeTextLink {
+    /// The identifier of the link, sent with the click event.
+    pub id: String,
+
+    /// The color of the link while hovered.
+    pub hover_color: Color,
+
+    /// Whether the link is underlined while hovered.
+    pub underline: bool,
+}
+
+impl NodeTextLink {
+    /// Creates a new link with the given identifier and the default hover
+    /// style.
+    pub fn new<S: Into<String>>(id: S) -> Self {
+        Self {
+            id: id.into(),
+            hover_color: Color::rgb(0.4, 0.7, 1.0),
+            underline: true,
+        }
+    }
 }
 
 /// Defines the text for a node.
@@ -45,19 +84,71 @@ impl Default for NodeText {
     }
 }
 
-impl DataBlock for NodeText {
-    fn apply_to_node(self, node: &mut NodeBundleBuilder, asset_server: &AssetServer) {
-        node.bundle_type(NodeBundleType::Text);
+impl NodeText {
+    /// Creates a new text block with a single empty section of the given size
+    /// and color.
+    ///
+    /// This is used as the text style of widgets that fill in the text
+    /// themselves.
+    pub fn styled(text_size: f32, color: Color) -> Self {
+        Self {
+            sections: vec![NodeTextSection {
+                text_size,
+                color,
+                ..default()
+            }],
+            ..default()
+        }
+    }
 
+    /// Returns true if any section of this text is an inline image.
+    pub fn has_images(&self) -> bool {
+        self.sections.iter().any(|section| section.image.is_some())
+    }
+
+    /// Creates the link component for the link sections of this text, if it
+    /// has any. Inline image sections are skipped, as they are not part of the
+    /// [`Text`] component.
+    ///
+    /// Text nodes with links also need an [`Interaction`] to be hovered.
+    pub(crate) fn text_links(&self) -> Option<TextLinks> {
+        let links = self
+            .sections
+            .iter()
+            .filter(|section| section.image.is_none())
+            .enumerate()
+            .filter_map(|(index, section)| {
+                section.link.clone().map(|link| TextLink {
+                    section: index,
+                    id: link.id,
+                    color: section.color,
+                    hover_color: link.hover_color,
+                    underline: link.underline,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        (!links.is_empty()).then(|| TextLinks::new(links))
+    }
+
+    /// Converts this block into a Bevy [`Text`] component, loading the fonts
+    /// of each section. Inline image sections are skipped.
+    pub(crate) fn into_text(self, asset_server: &AssetServer) -> Text {
         let mut text = Text::default();
         text.linebreak_behavior = self.line_break;
         text.sections = self
             .sections
             .into_iter()
+            .filter(|section| section.image.is_none())
             .map(|section| TextSection {
                 value: section.text,
                 style: TextStyle {
-                    font: asset_server.load(&section.font),
+                    font: if section.font.is_empty() {
+                        // Use Bevy's built-in default font when no font is set.
+                        Handle::default()
+                    } else {
+                        asset_server.load(&section.font)
+                    },
                     font_size: section.text_size,
                     color: section.color,
                 },
@@ -76,7 +167,20 @@ impl DataBlock for NodeText {
             AnchorPoint::BottomRight => JustifyText::Right,
         };
 
-        node.insert(text);
+        text
+    }
+}
+
+impl DataBlock for NodeText {
+    fn apply_to_node(self, node: &mut NodeBundleBuilder, asset_server: &AssetServer) {
+        let links = self.text_links();
+
+        node.bundle_type(NodeBundleType::Text);
+        node.insert(self.into_text(asset_server));
+
+        if let Some(links) = links {
+            node.insert((links, Interaction::default()));
+        }
     }
 
     fn apply_to_parent(&self, node: &mut NodeBundleBuilder, _: &AssetServer) {
@@ -0,0 +1,89 @@
+//! Contains blocks related to the tooltips of a node.
+
+use bevy::prelude::*;
+
+use super::{AnchorPoint, NodeBackground, NodePosition, NodeText, NodeTextSection};
+use crate::prelude::UiNode;
+
+/// Defines what a tooltip is positioned next to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum TooltipTarget {
+    /// The tooltip is positioned next to the cursor, and follows it while the
+    /// node is hovered.
+    #[default]
+    Cursor,
+
+    /// The tooltip is positioned next to the hovered node.
+    Node,
+}
+
+/// Defines the tooltip that is displayed while a node is hovered.
+#[derive(Debug, Clone)]
+pub struct NodeTooltip {
+    /// The contents of the tooltip.
+    pub content: Box<UiNode>,
+
+    /// The time, in seconds, the node must be hovered before the tooltip is
+    /// shown. If not set, the delay from the tooltip settings is used.
+    pub delay: Option<f32>,
+
+    /// What the tooltip is positioned next to.
+    pub target: TooltipTarget,
+
+    /// The preferred side of the target to display the tooltip on.
+    ///
+    /// If the tooltip would leave the window on this side, it is flipped to
+    /// the opposite side.
+    pub anchor: AnchorPoint,
+}
+
+impl Default for NodeTooltip {
+    fn default() -> Self {
+        Self {
+            content: Box::new(UiNode::Panel {
+                background: default(),
+                position: default(),
+                children: default(),
+            }),
+            delay: None,
+            target: default(),
+            anchor: AnchorPoint::BottomRight,
+        }
+    }
+}
+
+impl From<UiNode> for NodeTooltip {
+    fn from(content: UiNode) -> Self {
+        Self {
+            content: Box::new(content),
+            ..default()
+        }
+    }
+}
+
+impl From<String> for NodeTooltip {
+    fn from(text: String) -> Self {
+        UiNode::Text {
+            background: NodeBackground::Color {
+                color: Color::rgba(0.1, 0.1, 0.1, 0.9),
+            },
+            position: NodePosition::default(),
+            text: NodeText {
+                sections: vec![NodeTextSection {
+                    text,
+                    text_size: 16.0,
+                    color: Color::WHITE,
+                    ..default()
+                }],
+                ..default()
+            },
+        }
+        .into()
+    }
+}
+
+impl From<&str> for NodeTooltip {
+    fn from(text: &str) -> Self {
+        text.to_string().into()
+    }
+}
@@ -0,0 +1,116 @@
+//! Contains blocks related to hierarchical tree views.
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use super::{DataBlock, NodeText};
+use crate::prelude::{Focusable, NodeBundleBuilder, Tree, TreeSelection, UiNode};
+
+/// A shared function that creates the children of a tree item when it is
+/// expanded for the first time.
+#[derive(Clone)]
+pub struct ChildrenBuilder(Arc<dyn Fn() -> Vec<UiNode> + Send + Sync>);
+
+impl ChildrenBuilder {
+    /// Creates a new children builder from the given function.
+    pub fn new<F>(builder: F) -> Self
+    where
+        F: Fn() -> Vec<UiNode> + Send + Sync + 'static,
+    {
+        Self(Arc::new(builder))
+    }
+
+    /// Creates the children of the tree item.
+    pub fn build(&self) -> Vec<UiNode> {
+        (self.0)()
+    }
+}
+
+impl Debug for ChildrenBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ChildrenBuilder")
+    }
+}
+
+impl<F> From<F> for ChildrenBuilder
+where
+    F: Fn() -> Vec<UiNode> + Send + Sync + 'static,
+{
+    fn from(builder: F) -> Self {
+        Self::new(builder)
+    }
+}
+
+/// A data block for defining how the items of a tree view are displayed and
+/// selected.
+#[derive(Debug, Clone)]
+pub struct NodeTree {
+    /// Whether more than one item may be selected at a time, using control
+    /// and shift clicks.
+    pub multi_select: bool,
+
+    /// The indentation of each level of the tree.
+    pub indent: Val,
+
+    /// The height of the row of each item.
+    pub row_height: Val,
+
+    /// The text displayed on the arrow of a collapsed item.
+    pub expand_icon: String,
+
+    /// The text displayed on the arrow of an expanded item.
+    pub collapse_icon: String,
+
+    /// The background color of the row of each selected item.
+    pub selected_color: Color,
+}
+
+impl Default for NodeTree {
+    fn default() -> Self {
+        Self {
+            multi_select: false,
+            indent: Val::Px(16.0),
+            row_height: Val::Px(24.0),
+            expand_icon: String::from("+"),
+            collapse_icon: String::from("-"),
+            selected_color: Color::rgba(0.3, 0.5, 0.8, 0.5),
+        }
+    }
+}
+
+impl DataBlock for NodeTree {
+    fn apply_to_node(self, node: &mut NodeBundleBuilder, _: &AssetServer) {
+        node.insert((
+            Tree {
+                multi_select: self.multi_select,
+                indent: self.indent,
+                row_height: self.row_height,
+                expand_icon: self.expand_icon,
+                collapse_icon: self.collapse_icon,
+                selected_color: self.selected_color,
+            },
+            TreeSelection::default(),
+            Interaction::default(),
+            Focusable,
+        ));
+
+        let style = node.get_style_mut();
+        style.flex_direction = FlexDirection::Column;
+    }
+}
+
+/// A data block for defining a single item within a tree view.
+#[derive(Debug, Clone, Default)]
+pub struct NodeTreeItem {
+    /// The label displayed on the row of the item.
+    pub label: NodeText,
+
+    /// Whether the item is initially expanded.
+    pub expanded: bool,
+
+    /// The function used to create the children of the item when it is first
+    /// expanded, if any.
+    pub lazy_children: Option<ChildrenBuilder>,
+}
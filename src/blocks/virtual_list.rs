@@ -0,0 +1,98 @@
+//! Contains blocks related to lists that only spawn their visible rows.
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use super::DataBlock;
+use crate::prelude::{NodeBundleBuilder, UiNode, VirtualList};
+
+/// A shared function that creates the node for the row at the given index of
+/// a virtual list.
+#[derive(Clone)]
+pub struct RowBuilder(Arc<dyn Fn(usize) -> UiNode + Send + Sync>);
+
+impl RowBuilder {
+    /// Creates a new row builder from the given function.
+    pub fn new<F>(builder: F) -> Self
+    where
+        F: Fn(usize) -> UiNode + Send + Sync + 'static,
+    {
+        Self(Arc::new(builder))
+    }
+
+    /// Creates the node for the row at the given index.
+    pub fn build(&self, index: usize) -> UiNode {
+        (self.0)(index)
+    }
+}
+
+impl Default for RowBuilder {
+    fn default() -> Self {
+        Self::new(|_| UiNode::Panel {
+            background: default(),
+            position: default(),
+            children: default(),
+        })
+    }
+}
+
+impl Debug for RowBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RowBuilder")
+    }
+}
+
+impl<F> From<F> for RowBuilder
+where
+    F: Fn(usize) -> UiNode + Send + Sync + 'static,
+{
+    fn from(builder: F) -> Self {
+        Self::new(builder)
+    }
+}
+
+/// A data block for defining a list that only spawns the rows that are
+/// currently visible.
+#[derive(Debug, Clone)]
+pub struct NodeVirtualList {
+    /// The number of items in the list.
+    pub item_count: usize,
+
+    /// The height of each row, in logical pixels.
+    ///
+    /// All rows in a virtual list share the same height, which allows the list
+    /// to determine which rows are visible without spawning them.
+    pub row_height: f32,
+
+    /// The number of additional rows to keep spawned above and below the
+    /// visible area.
+    pub buffer: usize,
+
+    /// The function used to create the node for each row.
+    pub row_builder: RowBuilder,
+}
+
+impl Default for NodeVirtualList {
+    fn default() -> Self {
+        Self {
+            item_count: 0,
+            row_height: 24.0,
+            buffer: 4,
+            row_builder: Default::default(),
+        }
+    }
+}
+
+impl DataBlock for NodeVirtualList {
+    fn apply_to_node(self, node: &mut NodeBundleBuilder, _: &AssetServer) {
+        node.insert(VirtualList {
+            item_count: self.item_count,
+            row_height: self.row_height,
+            buffer: self.buffer,
+            row_builder: self.row_builder,
+            dirty: true,
+        });
+    }
+}
@@ -0,0 +1,65 @@
+//! Contains blocks related to floating windows.
+
+use bevy::prelude::*;
+
+use super::{NodeBackground, NodeText};
+
+/// A data block for defining the title bar and behavior of a floating window.
+#[derive(Debug, Clone)]
+pub struct NodeWindow {
+    /// The title displayed in the title bar.
+    pub title: NodeText,
+
+    /// Whether the title bar displays a button that closes the window.
+    pub closable: bool,
+
+    /// Whether the title bar displays a button that minimizes the window.
+    pub minimizable: bool,
+
+    /// Whether the window may be resized by dragging its edges and corners.
+    pub resizable: bool,
+
+    /// Whether the window is initially minimized.
+    pub minimized: bool,
+
+    /// The smallest size the window may be resized to, in logical pixels.
+    pub min_size: Vec2,
+
+    /// The largest size the window may be resized to, in logical pixels.
+    pub max_size: Vec2,
+
+    /// The height of the title bar, in logical pixels.
+    pub title_bar_height: f32,
+
+    /// The background of the title bar.
+    pub title_bar_background: NodeBackground,
+
+    /// The thickness of the resize handles along each edge, in logical
+    /// pixels.
+    pub handle_size: f32,
+
+    /// If set, the position and size of the window are stored in the window
+    /// layouts resource under this key, and restored when a window with the
+    /// same key is created.
+    pub persist_key: Option<String>,
+}
+
+impl Default for NodeWindow {
+    fn default() -> Self {
+        Self {
+            title: NodeText::styled(16.0, Color::WHITE),
+            closable: true,
+            minimizable: true,
+            resizable: true,
+            minimized: false,
+            min_size: Vec2::new(120.0, 60.0),
+            max_size: Vec2::splat(f32::INFINITY),
+            title_bar_height: 24.0,
+            title_bar_background: NodeBackground::Color {
+                color: Color::rgb(0.2, 0.2, 0.25),
+            },
+            handle_size: 6.0,
+            persist_key: None,
+        }
+    }
+}
@@ -24,6 +24,12 @@ pub enum NodeBundleType {
 
     /// A node bundle that contains text.
     Text,
+
+    /// A node bundle without a background color, for nodes that are rendered
+    /// using a UI material.
+    ///
+    /// The material handle must be inserted as a separate component.
+    Material,
 }
 
 impl NodeBundleType {
@@ -35,6 +41,32 @@ impl NodeBundleType {
             NodeBundleType::Image => cmd.spawn(ImageBundle::default()),
             NodeBundleType::Button => cmd.spawn(ButtonBundle::default()),
             NodeBundleType::Text => cmd.spawn(TextBundle::default()),
+            NodeBundleType::Material => {
+                let NodeBundle {
+                    node,
+                    style,
+                    focus_policy,
+                    transform,
+                    global_transform,
+                    visibility,
+                    inherited_visibility,
+                    view_visibility,
+                    z_index,
+                    ..
+                } = NodeBundle::default();
+
+                cmd.spawn((
+                    node,
+                    style,
+                    focus_policy,
+                    transform,
+                    global_transform,
+                    visibility,
+                    inherited_visibility,
+                    view_visibility,
+                    z_index,
+                ))
+            }
         }
     }
 }
@@ -0,0 +1,143 @@
+//! Builders for defining collapsible sections and accordions.
+
+use bevy::prelude::*;
+
+use crate::prelude::{NodeBackground, NodeCollapsible, NodePosition, NodeText, UiNode};
+
+/// A builder for defining a collapsible section node.
+#[derive(Debug, Default, Clone)]
+pub struct CollapsibleNodeBuilder {
+    /// The background of the section.
+    background: NodeBackground,
+
+    /// The position of the section.
+    position: NodePosition,
+
+    /// The header and behavior of the section.
+    section: NodeCollapsible,
+
+    /// The contents of the body of the section.
+    children: Vec<UiNode>,
+}
+
+impl CollapsibleNodeBuilder {
+    /// Creates a new collapsible section builder with the given header text.
+    pub fn new<T: Into<NodeText>>(header: T) -> Self {
+        Self {
+            section: NodeCollapsible {
+                header: header.into(),
+                ..default()
+            },
+            ..default()
+        }
+    }
+
+    /// Sets the background of the section.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the section.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Adds a child node to the body of the section.
+    pub fn child<T: Into<UiNode>>(mut self, child: T) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Sets the section to be initially expanded.
+    pub fn expanded(mut self) -> Self {
+        self.section.expanded = true;
+        self
+    }
+
+    /// Sets the time, in seconds, the body takes to expand or collapse. A
+    /// time of zero disables the animation.
+    pub fn animation_time(mut self, seconds: f32) -> Self {
+        self.section.animation_time = seconds;
+        self
+    }
+
+    /// Sets the background of the header.
+    pub fn header_background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.section.header_background = background.into();
+        self
+    }
+
+    /// Sets the height of the header.
+    pub fn header_height(mut self, height: Val) -> Self {
+        self.section.header_height = height;
+        self
+    }
+
+    /// Sets the text displayed on the icon of collapsed and expanded
+    /// sections.
+    pub fn icons<E, C>(mut self, expand: E, collapse: C) -> Self
+    where
+        E: Into<String>,
+        C: Into<String>,
+    {
+        self.section.expand_icon = expand.into();
+        self.section.collapse_icon = collapse.into();
+        self
+    }
+}
+
+impl From<CollapsibleNodeBuilder> for UiNode {
+    fn from(builder: CollapsibleNodeBuilder) -> Self {
+        UiNode::Collapsible {
+            background: builder.background,
+            position: builder.position,
+            section: builder.section,
+            children: builder.children.into(),
+        }
+    }
+}
+
+/// A builder for defining an accordion node.
+#[derive(Debug, Default, Clone)]
+pub struct AccordionNodeBuilder {
+    /// The background of the accordion.
+    background: NodeBackground,
+
+    /// The position of the accordion.
+    position: NodePosition,
+
+    /// The sections of the accordion.
+    children: Vec<UiNode>,
+}
+
+impl AccordionNodeBuilder {
+    /// Sets the background of the accordion.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the accordion.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Adds a collapsible section to the end of the accordion.
+    pub fn section<T: Into<UiNode>>(mut self, section: T) -> Self {
+        self.children.push(section.into());
+        self
+    }
+}
+
+impl From<AccordionNodeBuilder> for UiNode {
+    fn from(builder: AccordionNodeBuilder) -> Self {
+        UiNode::Accordion {
+            background: builder.background,
+            position: builder.position,
+            children: builder.children.into(),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! A builder for defining color picker nodes.
+
+use bevy::prelude::*;
+
+use crate::prelude::{NodeBackground, NodeColorPicker, NodePosition, NodeText, UiNode};
+
+/// A builder for defining a color picker node.
+#[derive(Debug, Default, Clone)]
+pub struct ColorPickerNodeBuilder {
+    /// The background of the color picker.
+    background: NodeBackground,
+
+    /// The position of the color picker.
+    position: NodePosition,
+
+    /// The initial color and layout of the color picker.
+    picker: NodeColorPicker,
+}
+
+impl ColorPickerNodeBuilder {
+    /// Sets the background of the color picker.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the color picker.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets the initial color of the color picker.
+    pub fn color(mut self, color: Color) -> Self {
+        self.picker.color = color;
+        self
+    }
+
+    /// Sets whether the color picker displays a slider for the alpha channel.
+    pub fn show_alpha(mut self, show_alpha: bool) -> Self {
+        self.picker.show_alpha = show_alpha;
+        self
+    }
+
+    /// Adds a preset color swatch to the color picker.
+    pub fn swatch(mut self, color: Color) -> Self {
+        self.picker.swatches.push(color);
+        self
+    }
+
+    /// Adds several preset color swatches to the color picker.
+    pub fn swatches<I: IntoIterator<Item = Color>>(mut self, colors: I) -> Self {
+        self.picker.swatches.extend(colors);
+        self
+    }
+
+    /// Sets the size of the saturation/value square, in logical pixels.
+    pub fn size(mut self, size: f32) -> Self {
+        self.picker.size = size;
+        self
+    }
+
+    /// Sets the thickness of the hue and alpha bars, and the size of each
+    /// swatch, in logical pixels.
+    pub fn bar_thickness(mut self, thickness: f32) -> Self {
+        self.picker.bar_thickness = thickness;
+        self
+    }
+
+    /// Sets the space between each part of the color picker, in logical
+    /// pixels.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.picker.spacing = spacing;
+        self
+    }
+
+    /// Sets the style of the text of the hex entry field.
+    pub fn text<T: Into<NodeText>>(mut self, text: T) -> Self {
+        self.picker.text = text.into();
+        self
+    }
+
+    /// Sets the background color of the hex entry field.
+    pub fn field_color(mut self, color: Color) -> Self {
+        self.picker.field_color = color;
+        self
+    }
+}
+
+impl From<ColorPickerNodeBuilder> for UiNode {
+    fn from(builder: ColorPickerNodeBuilder) -> Self {
+        UiNode::ColorPicker {
+            background: builder.background,
+            position: builder.position,
+            picker: builder.picker,
+        }
+    }
+}
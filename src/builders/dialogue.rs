@@ -0,0 +1,114 @@
+//! A builder for defining dialogue box nodes.
+
+use bevy::prelude::*;
+
+use crate::prelude::{NodeBackground, NodeDialogue, NodePosition, NodeText, UiNode};
+
+/// A builder for defining a dialogue box node.
+#[derive(Debug, Default, Clone)]
+pub struct DialogueNodeBuilder {
+    /// The background of the dialogue box.
+    background: NodeBackground,
+
+    /// The position of the dialogue box.
+    position: NodePosition,
+
+    /// The initial line and style of the dialogue box.
+    dialogue: NodeDialogue,
+}
+
+impl DialogueNodeBuilder {
+    /// Sets the background of the dialogue box.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the dialogue box.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets the first line displayed by the dialogue box.
+    ///
+    /// See [`NodeDialogue`] for the cues that may be written within the text.
+    pub fn text<T: Into<NodeText>>(mut self, text: T) -> Self {
+        self.dialogue.text = text.into();
+        self
+    }
+
+    /// Sets the name displayed on the name plate.
+    pub fn speaker<S: Into<String>>(mut self, speaker: S) -> Self {
+        self.dialogue.speaker = Some(speaker.into());
+        self
+    }
+
+    /// Sets the style of the speaker name, and the background of the name
+    /// plate.
+    pub fn speaker_style<T, B>(mut self, text: T, background: B) -> Self
+    where
+        T: Into<NodeText>,
+        B: Into<NodeBackground>,
+    {
+        self.dialogue.speaker_text = text.into();
+        self.dialogue.speaker_background = background.into();
+        self
+    }
+
+    /// Sets the path of the portrait image displayed beside the text.
+    pub fn portrait<S: Into<String>>(mut self, path: S) -> Self {
+        self.dialogue.portrait = Some(path.into());
+        self
+    }
+
+    /// Sets the width and height of the portrait, in logical pixels.
+    pub fn portrait_size(mut self, size: f32) -> Self {
+        self.dialogue.portrait_size = size;
+        self
+    }
+
+    /// Sets the number of characters revealed each second. A rate of zero
+    /// reveals each line immediately.
+    pub fn chars_per_second(mut self, rate: f32) -> Self {
+        self.dialogue.chars_per_second = rate;
+        self
+    }
+
+    /// Sets the indicator displayed once a line has been fully revealed.
+    pub fn continue_indicator<T: Into<NodeText>>(mut self, indicator: T) -> Self {
+        self.dialogue.continue_indicator = indicator.into();
+        self
+    }
+
+    /// Sets the time, in seconds, the continue indicator takes to blink on and
+    /// off. A time of zero disables blinking.
+    pub fn blink_time(mut self, seconds: f32) -> Self {
+        self.dialogue.blink_time = seconds;
+        self
+    }
+
+    /// Sets the keys that skip to the end of a line, or continue to the next
+    /// line.
+    pub fn advance_keys<I: IntoIterator<Item = KeyCode>>(mut self, keys: I) -> Self {
+        self.dialogue.advance_keys = keys.into_iter().collect();
+        self
+    }
+
+    /// Sets the space between the portrait, name plate, and text, in logical
+    /// pixels.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.dialogue.spacing = spacing;
+        self
+    }
+}
+
+impl From<DialogueNodeBuilder> for UiNode {
+    fn from(builder: DialogueNodeBuilder) -> Self {
+        UiNode::Dialogue {
+            background: builder.background,
+            position: builder.position,
+            dialogue: builder.dialogue,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Builders for defining nodes that may be dragged and dropped.
+
+use std::any::Any;
+
+use crate::prelude::{NodeDraggable, NodeDropTarget, UiNode};
+
+/// An extension trait that allows any node to be dragged, or to accept dragged
+/// nodes.
+pub trait DragDropExt: Into<UiNode> {
+    /// Allows this node to be dragged onto drop targets that accept the type of
+    /// the given payload.
+    fn draggable<P: Any + Send + Sync>(self, payload: P) -> UiNode {
+        UiNode::WithDraggable {
+            node: Box::new(self.into()),
+            draggable: NodeDraggable::new(payload),
+        }
+    }
+
+    /// Sets the node that follows the cursor while this node is dragged,
+    /// instead of a plain node with its size and background color.
+    ///
+    /// This has no effect unless called after [`DragDropExt::draggable`].
+    fn ghost(self, ghost: impl Into<UiNode>) -> UiNode {
+        match self.into() {
+            UiNode::WithDraggable {
+                node,
+                mut draggable,
+            } => {
+                draggable.ghost = Some(Box::new(ghost.into()));
+                UiNode::WithDraggable { node, draggable }
+            }
+            node => node,
+        }
+    }
+
+    /// Allows dragged nodes with a payload of the given type to be dropped
+    /// onto this node.
+    ///
+    /// This may be called several times to accept multiple payload types.
+    fn drop_target<P: Any>(self) -> UiNode {
+        let (node, mut target) = match self.into() {
+            UiNode::WithDropTarget { node, target } => (node, target),
+            node => (Box::new(node), NodeDropTarget::default()),
+        };
+
+        target.accept::<P>();
+        UiNode::WithDropTarget { node, target }
+    }
+}
+
+impl<T: Into<UiNode>> DragDropExt for T {}
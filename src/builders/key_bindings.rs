@@ -0,0 +1,137 @@
+//! A builder for defining key binding list nodes.
+
+use std::any::TypeId;
+
+use bevy::prelude::*;
+
+use crate::prelude::{
+    BindingSource,
+    NodeBackground,
+    NodeBindingAction,
+    NodeKeyBindings,
+    NodePosition,
+    NodeText,
+    UiNode,
+};
+
+/// A builder for defining a key binding list node, such as a controls menu.
+#[derive(Debug, Default, Clone)]
+pub struct KeyBindingsNodeBuilder {
+    /// The background of the key binding list.
+    background: NodeBackground,
+
+    /// The position of the key binding list.
+    position: NodePosition,
+
+    /// The actions and style of the key binding list.
+    bindings: NodeKeyBindings,
+}
+
+impl KeyBindingsNodeBuilder {
+    /// Creates a new key binding list builder that edits the bindings stored
+    /// in the given [`BindingSource`] resource type.
+    pub fn new<T: BindingSource>() -> Self {
+        Self {
+            bindings: NodeKeyBindings {
+                source: Some(TypeId::of::<T>()),
+                ..default()
+            },
+            ..default()
+        }
+    }
+
+    /// Sets the background of the key binding list.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the key binding list.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Adds an action to the list, with the given identifier and label.
+    pub fn action(mut self, id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.bindings.actions.push(NodeBindingAction {
+            id: id.into(),
+            label: label.into(),
+        });
+        self
+    }
+
+    /// Sets the number of bindings each action may have, such as a primary
+    /// and secondary binding.
+    pub fn slots(mut self, slots: usize) -> Self {
+        self.bindings.slots = slots.max(1);
+        self
+    }
+
+    /// Sets the style of the action labels and binding buttons.
+    pub fn text<T: Into<NodeText>>(mut self, text: T) -> Self {
+        self.bindings.text = text.into();
+        self
+    }
+
+    /// Sets the width of the action labels.
+    pub fn label_width(mut self, width: Val) -> Self {
+        self.bindings.label_width = width;
+        self
+    }
+
+    /// Sets the width of each binding button and the height of each row, in
+    /// logical pixels.
+    pub fn button_size(mut self, width: f32, height: f32) -> Self {
+        self.bindings.button_width = width;
+        self.bindings.row_height = height;
+        self
+    }
+
+    /// Sets the space between each row and binding button, in logical pixels.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.bindings.spacing = spacing;
+        self
+    }
+
+    /// Sets the background of each binding button.
+    pub fn button_background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.bindings.button_background = background.into();
+        self
+    }
+
+    /// Sets the text displayed on a binding button while waiting for an
+    /// input.
+    pub fn capture_text(mut self, text: impl Into<String>) -> Self {
+        self.bindings.capture_text = text.into();
+        self
+    }
+
+    /// Sets the text displayed on a binding button with no binding.
+    pub fn unbound_text(mut self, text: impl Into<String>) -> Self {
+        self.bindings.unbound_text = text.into();
+        self
+    }
+
+    /// Sets the text color of bindings that are used by more than one action.
+    pub fn conflict_color(mut self, color: Color) -> Self {
+        self.bindings.conflict_color = color;
+        self
+    }
+
+    /// Sets the key that cancels capturing an input.
+    pub fn cancel_key(mut self, key: KeyCode) -> Self {
+        self.bindings.cancel_key = key;
+        self
+    }
+}
+
+impl From<KeyBindingsNodeBuilder> for UiNode {
+    fn from(builder: KeyBindingsNodeBuilder) -> Self {
+        UiNode::KeyBindings {
+            background: builder.background,
+            position: builder.position,
+            bindings: builder.bindings,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! A builder for defining log view nodes.
+
+use bevy::prelude::*;
+
+use crate::prelude::{NodeBackground, NodeLogView, NodePosition, NodeScroll, NodeText, UiNode};
+
+/// A builder for defining a log view node, such as a chat window or debug
+/// log.
+#[derive(Debug, Default, Clone)]
+pub struct LogViewNodeBuilder {
+    /// The background of the log view.
+    background: NodeBackground,
+
+    /// The position of the log view.
+    position: NodePosition,
+
+    /// The behavior and style of the log view.
+    log: NodeLogView,
+}
+
+impl LogViewNodeBuilder {
+    /// Sets the background of the log view.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the log view.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets the largest number of lines kept in the history.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.log.max_lines = max_lines;
+        self
+    }
+
+    /// Sets the style of plain lines appended to the log.
+    pub fn line_text<T: Into<NodeText>>(mut self, text: T) -> Self {
+        self.log.line_text = text.into();
+        self
+    }
+
+    /// Sets the space below each line, in logical pixels.
+    pub fn line_spacing(mut self, spacing: f32) -> Self {
+        self.log.line_spacing = spacing;
+        self
+    }
+
+    /// Prefixes each line with the time it was appended, using the given
+    /// color.
+    pub fn timestamps(mut self, color: Color) -> Self {
+        self.log.show_timestamps = true;
+        self.log.timestamp_color = color;
+        self
+    }
+
+    /// Fades out lines once they are older than the given number of seconds,
+    /// over the given fade time, unless the log view is hovered.
+    pub fn fade_after(mut self, seconds: f32, fade_time: f32) -> Self {
+        self.log.fade_after = Some(seconds);
+        self.log.fade_time = fade_time;
+        self
+    }
+
+    /// Sets how the lines of the log view are scrolled.
+    pub fn scroll(mut self, scroll: NodeScroll) -> Self {
+        self.log.scroll = scroll;
+        self
+    }
+}
+
+impl From<LogViewNodeBuilder> for UiNode {
+    fn from(builder: LogViewNodeBuilder) -> Self {
+        UiNode::LogView {
+            background: builder.background,
+            position: builder.position,
+            log: builder.log,
+        }
+    }
+}
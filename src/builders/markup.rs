@@ -0,0 +1,695 @@
+//! A parser for defining text using inline markup tags.
+
+use std::error::Error;
+use std::fmt;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::prelude::{NodeTextLink, TextBuilder, TextSectionBuilder};
+
+/// The fonts of a single font family, used to display bold and italic text.
+///
+/// Styles without a font fall back to the regular font.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FontFamily {
+    /// The regular font of the family.
+    pub regular: String,
+
+    /// The bold font of the family, if any.
+    pub bold: Option<String>,
+
+    /// The italic font of the family, if any.
+    pub italic: Option<String>,
+
+    /// The bold italic font of the family, if any.
+    pub bold_italic: Option<String>,
+}
+
+impl FontFamily {
+    /// Creates a new font family with the given regular font.
+    pub fn new<S: Into<String>>(regular: S) -> Self {
+        Self {
+            regular: regular.into(),
+            ..default()
+        }
+    }
+
+    /// Sets the bold font of the family.
+    pub fn bold<S: Into<String>>(mut self, font: S) -> Self {
+        self.bold = Some(font.into());
+        self
+    }
+
+    /// Sets the italic font of the family.
+    pub fn italic<S: Into<String>>(mut self, font: S) -> Self {
+        self.italic = Some(font.into());
+        self
+    }
+
+    /// Sets the bold italic font of the family.
+    pub fn bold_italic<S: Into<String>>(mut self, font: S) -> Self {
+        self.bold_italic = Some(font.into());
+        self
+    }
+
+    /// Gets the font to use for the given style.
+    ///
+    /// Bold italic text falls back to the bold font, then the italic font,
+    /// before falling back to the regular font.
+    pub fn font(&self, bold: bool, italic: bool) -> &str {
+        let font = match (bold, italic) {
+            (true, true) => self
+                .bold_italic
+                .as_ref()
+                .or(self.bold.as_ref())
+                .or(self.italic.as_ref()),
+            (true, false) => self.bold.as_ref(),
+            (false, true) => self.italic.as_ref(),
+            (false, false) => None,
+        };
+        font.unwrap_or(&self.regular)
+    }
+}
+
+/// A registry of named font families that may be used within text markup.
+///
+/// This may be stored as a resource so that it is available to any system
+/// that builds text.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct FontFamilies {
+    /// The font families, by name.
+    families: HashMap<String, FontFamily>,
+}
+
+impl FontFamilies {
+    /// Adds a font family with the given name, replacing any existing family
+    /// with the same name.
+    pub fn insert<S: Into<String>>(&mut self, name: S, family: FontFamily) {
+        self.families.insert(name.into(), family);
+    }
+
+    /// Adds a font family with the given name.
+    pub fn with<S: Into<String>>(mut self, name: S, family: FontFamily) -> Self {
+        self.insert(name, family);
+        self
+    }
+
+    /// Gets the font family with the given name.
+    pub fn get(&self, name: &str) -> Option<&FontFamily> {
+        self.families.get(name)
+    }
+}
+
+/// The kind of error found while parsing text markup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkupErrorKind {
+    /// A tag was opened with `[` but never closed with `]`.
+    UnterminatedTag,
+
+    /// A tag with an unknown name was used.
+    UnknownTag(String),
+
+    /// A tag that requires a value, such as `[color=red]`, was used without
+    /// one.
+    MissingValue(String),
+
+    /// A tag that does not take a value, such as `[b]`, was given one.
+    UnexpectedValue(String),
+
+    /// The value of a tag could not be parsed.
+    InvalidValue {
+        /// The name of the tag.
+        tag: String,
+
+        /// The value that could not be parsed.
+        value: String,
+    },
+
+    /// A font family that is not in the registry was used.
+    UnknownFontFamily(String),
+
+    /// A closing tag did not match the most recently opened tag.
+    MismatchedClose {
+        /// The name of the most recently opened tag, if any tag is open.
+        expected: Option<String>,
+
+        /// The name of the closing tag.
+        found: String,
+    },
+
+    /// A tag was opened but never closed.
+    UnclosedTag(String),
+}
+
+/// An error found while parsing text markup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkupError {
+    /// The byte offset of the tag that caused the error within the markup.
+    pub position: usize,
+
+    /// The line of the tag that caused the error, starting at 1.
+    pub line: usize,
+
+    /// The column of the tag that caused the error, in characters, starting
+    /// at 1.
+    pub column: usize,
+
+    /// The kind of error.
+    pub kind: MarkupErrorKind,
+}
+
+impl MarkupError {
+    /// Creates a new error for the tag at the given byte offset within the
+    /// given markup.
+    fn new(source: &str, position: usize, kind: MarkupErrorKind) -> Self {
+        let before = &source[.. position];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start ..].chars().count() + 1;
+
+        Self {
+            position,
+            line,
+            column,
+            kind,
+        }
+    }
+}
+
+impl fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.kind {
+            MarkupErrorKind::UnterminatedTag => write!(f, "tag is missing a closing `]`"),
+            MarkupErrorKind::UnknownTag(tag) => write!(f, "unknown tag `{tag}`"),
+            MarkupErrorKind::MissingValue(tag) => write!(f, "tag `{tag}` requires a value"),
+            MarkupErrorKind::UnexpectedValue(tag) => {
+                write!(f, "tag `{tag}` does not take a value")
+            }
+            MarkupErrorKind::InvalidValue { tag, value } => {
+                write!(f, "invalid value `{value}` for tag `{tag}`")
+            }
+            MarkupErrorKind::UnknownFontFamily(name) => {
+                write!(f, "unknown font family `{name}`")
+            }
+            MarkupErrorKind::MismatchedClose {
+                expected: Some(expected),
+                found,
+            } => write!(f, "expected `[/{expected}]`, found `[/{found}]`"),
+            MarkupErrorKind::MismatchedClose {
+                expected: None,
+                found,
+            } => write!(f, "closing tag `[/{found}]` has no matching opening tag"),
+            MarkupErrorKind::UnclosedTag(tag) => write!(f, "tag `{tag}` is never closed"),
+        }
+    }
+}
+
+impl Error for MarkupError {}
+
+/// The style of the text at a point within the markup.
+#[derive(Debug, Clone)]
+struct MarkupStyle {
+    /// The name of the font family, or `None` for Bevy's built-in default
+    /// font.
+    family: Option<String>,
+
+    /// Whether the text is bold.
+    bold: bool,
+
+    /// Whether the text is italic.
+    italic: bool,
+
+    /// The size of the text.
+    text_size: f32,
+
+    /// The color of the text.
+    color: Color,
+
+    /// The identifier of the link the text belongs to, if any.
+    link: Option<String>,
+}
+
+/// A parser that converts text markup into text sections.
+///
+/// The following tags are supported, and may be nested:
+///
+/// - `[b]bold[/b]` and `[i]italic[/i]`, which use the fonts of the current font
+///   family. Without a font family, these have no visible effect.
+/// - `[color=yellow]named[/color]` or `[color=#ffcc00]hex[/color]` colors.
+/// - `[size=24]sized[/size]` text, in logical pixels.
+/// - `[font=serif]family[/font]`, using a family from the registry.
+/// - `[img=ui/btn_a.png]`, an inline image sized to the current text size,
+///   which has no closing tag.
+/// - `[link=shop]clickable[/link]` text, which sends a
+///   [`LinkClicked`](crate::prelude::LinkClicked) event with the given
+///   identifier when clicked.
+///
+/// A literal `[` is written as `[[`.
+#[derive(Debug, Clone)]
+pub struct MarkupParser<'a> {
+    /// The registry used to look up font families.
+    families: Option<&'a FontFamilies>,
+
+    /// The style of text outside of any tags.
+    base: MarkupStyle,
+
+    /// The color of links while hovered.
+    link_hover_color: Color,
+
+    /// Whether links are underlined while hovered.
+    link_underline: bool,
+}
+
+impl Default for MarkupParser<'_> {
+    fn default() -> Self {
+        Self {
+            families: None,
+            base: MarkupStyle {
+                family: None,
+                bold: false,
+                italic: false,
+                text_size: 16.0,
+                color: Color::BLACK,
+                link: None,
+            },
+            link_hover_color: NodeTextLink::new("").hover_color,
+            link_underline: true,
+        }
+    }
+}
+
+impl<'a> MarkupParser<'a> {
+    /// Creates a new markup parser with the default text style and no font
+    /// families.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the registry used to look up font families.
+    pub fn families(mut self, families: &'a FontFamilies) -> Self {
+        self.families = Some(families);
+        self
+    }
+
+    /// Sets the font family of text outside of any `[font]` tags.
+    ///
+    /// The family must be in the registry when parsing.
+    pub fn family<S: Into<String>>(mut self, family: S) -> Self {
+        self.base.family = Some(family.into());
+        self
+    }
+
+    /// Sets the size of text outside of any `[size]` tags.
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.base.text_size = text_size;
+        self
+    }
+
+    /// Sets the color of text outside of any `[color]` tags.
+    pub fn color(mut self, color: Color) -> Self {
+        self.base.color = color;
+        self
+    }
+
+    /// Sets the color of links while hovered, and whether they are underlined
+    /// while hovered.
+    pub fn link_hover(mut self, color: Color, underline: bool) -> Self {
+        self.link_hover_color = color;
+        self.link_underline = underline;
+        self
+    }
+
+    /// Parses the given markup into a text builder with a section for each
+    /// run of text with the same style.
+    pub fn parse(&self, source: &str) -> Result<TextBuilder, MarkupError> {
+        if let Some(family) = &self.base.family {
+            self.family_font(family, false, false)
+                .ok_or_else(|| MarkupErrorKind::UnknownFontFamily(family.clone()))
+                .map_err(|kind| MarkupError::new(source, 0, kind))?;
+        }
+
+        let mut builder = TextBuilder::default();
+        let mut style = self.base.clone();
+        let mut open: Vec<(String, usize, MarkupStyle)> = Vec::new();
+        let mut run = String::new();
+
+        let mut rest = source;
+        while let Some(start) = rest.find('[') {
+            let offset = source.len() - rest.len();
+            run.push_str(&rest[.. start]);
+            rest = &rest[start + 1 ..];
+
+            if let Some(after) = rest.strip_prefix('[') {
+                run.push('[');
+                rest = after;
+                continue;
+            }
+
+            let position = offset + start;
+            let error = |kind| MarkupError::new(source, position, kind);
+
+            let end = rest
+                .find(']')
+                .ok_or_else(|| error(MarkupErrorKind::UnterminatedTag))?;
+            let tag = rest[.. end].trim();
+            rest = &rest[end + 1 ..];
+
+            if !run.is_empty() {
+                builder = builder.section(self.section(std::mem::take(&mut run), &style));
+            }
+
+            if let Some(name) = tag.strip_prefix('/') {
+                let name = name.trim();
+                match open.pop() {
+                    Some((opened, _, previous)) if opened == name => style = previous,
+                    top => {
+                        return Err(error(MarkupErrorKind::MismatchedClose {
+                            expected: top.map(|(opened, ..)| opened),
+                            found: name.to_string(),
+                        }));
+                    }
+                }
+                continue;
+            }
+
+            let (name, value) = match tag.split_once('=') {
+                Some((name, value)) => (name.trim(), Some(value.trim())),
+                None => (tag, None),
+            };
+
+            let previous = style.clone();
+            match (name, value) {
+                ("b" | "i", Some(_)) => {
+                    return Err(error(MarkupErrorKind::UnexpectedValue(name.to_string())));
+                }
+                ("b", None) => style.bold = true,
+                ("i", None) => style.italic = true,
+
+                ("img", Some(value)) => {
+                    builder = builder
+                        .section(TextSectionBuilder::image(value).text_size(style.text_size));
+                    continue;
+                }
+
+                ("link", Some(value)) => style.link = Some(value.to_string()),
+
+                ("color" | "size" | "font" | "img" | "link", None) => {
+                    return Err(error(MarkupErrorKind::MissingValue(name.to_string())));
+                }
+                ("color", Some(value)) => {
+                    style.color = parse_color(value).ok_or_else(|| {
+                        error(MarkupErrorKind::InvalidValue {
+                            tag: name.to_string(),
+                            value: value.to_string(),
+                        })
+                    })?;
+                }
+                ("size", Some(value)) => {
+                    style.text_size = value
+                        .parse::<f32>()
+                        .ok()
+                        .filter(|size| size.is_finite() && *size > 0.0)
+                        .ok_or_else(|| {
+                            error(MarkupErrorKind::InvalidValue {
+                                tag: name.to_string(),
+                                value: value.to_string(),
+                            })
+                        })?;
+                }
+                ("font", Some(value)) => {
+                    if self.families.and_then(|f| f.get(value)).is_none() {
+                        return Err(error(MarkupErrorKind::UnknownFontFamily(value.to_string())));
+                    }
+                    style.family = Some(value.to_string());
+                }
+
+                _ => return Err(error(MarkupErrorKind::UnknownTag(name.to_string()))),
+            }
+            open.push((name.to_string(), position, previous));
+        }
+
+        if let Some((name, position, _)) = open.pop() {
+            return Err(MarkupError::new(
+                source,
+                position,
+                MarkupErrorKind::UnclosedTag(name),
+            ));
+        }
+
+        run.push_str(rest);
+        if !run.is_empty() {
+            builder = builder.section(self.section(run, &style));
+        }
+
+        Ok(builder)
+    }
+
+    /// Gets the font of the given family for the given style, if the family
+    /// is in the registry.
+    fn family_font(&self, family: &str, bold: bool, italic: bool) -> Option<&str> {
+        self.families
+            .and_then(|families| families.get(family))
+            .map(|family| family.font(bold, italic))
+    }
+
+    /// Creates a text section with the given text and style.
+    fn section(&self, text: String, style: &MarkupStyle) -> TextSectionBuilder {
+        let font = style
+            .family
+            .as_deref()
+            .and_then(|family| self.family_font(family, style.bold, style.italic))
+            .unwrap_or_default();
+
+        let section = TextSectionBuilder::new(text)
+            .font(font)
+            .text_size(style.text_size)
+            .color(style.color);
+
+        match &style.link {
+            Some(link) => section
+                .link(link)
+                .link_hover(self.link_hover_color, self.link_underline),
+            None => section,
+        }
+    }
+}
+
+/// Parses a named color, or a hex color starting with `#`.
+fn parse_color(value: &str) -> Option<Color> {
+    if let Some(hex) = value.strip_prefix('#') {
+        return Color::hex(hex).ok();
+    }
+
+    let color = match value.to_ascii_lowercase().as_str() {
+        "white" => Color::WHITE,
+        "black" => Color::BLACK,
+        "gray" | "grey" => Color::GRAY,
+        "red" => Color::RED,
+        "green" => Color::GREEN,
+        "blue" => Color::BLUE,
+        "yellow" => Color::YELLOW,
+        "orange" => Color::ORANGE,
+        "cyan" => Color::CYAN,
+        "purple" => Color::PURPLE,
+        "pink" => Color::PINK,
+        "none" => Color::NONE,
+        _ => return None,
+    };
+    Some(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{NodeText, NodeTextSection};
+
+    /// Creates a registry with a `sans` family that has a bold font but no
+    /// italic fonts.
+    fn families() -> FontFamilies {
+        FontFamilies::default().with("sans", FontFamily::new("sans.ttf").bold("sans-bold.ttf"))
+    }
+
+    /// Parses the given markup into text sections.
+    fn sections(parser: &MarkupParser, source: &str) -> Vec<NodeTextSection> {
+        NodeText::from(parser.parse(source).unwrap()).sections
+    }
+
+    /// Parses the given markup, which must be invalid, and gets the line,
+    /// column, and kind of the error.
+    fn error(parser: &MarkupParser, source: &str) -> (usize, usize, MarkupErrorKind) {
+        let error = parser.parse(source).unwrap_err();
+        (error.line, error.column, error.kind)
+    }
+
+    #[test]
+    fn nested_tags_restore_outer_style() {
+        let families = families();
+        let parser = MarkupParser::new().families(&families).family("sans");
+        let sections = sections(&parser, "a[b]b[color=red]c[/color][/b]d");
+
+        let styles = sections
+            .iter()
+            .map(|s| (s.text.as_str(), s.font.as_str(), s.color))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            styles,
+            [
+                ("a", "sans.ttf", Color::BLACK),
+                ("b", "sans-bold.ttf", Color::BLACK),
+                ("c", "sans-bold.ttf", Color::RED),
+                ("d", "sans.ttf", Color::BLACK),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_link_keeps_inner_style() {
+        let sections = sections(
+            &MarkupParser::new(),
+            "[link=shop][size=24]Buy[/size] now[/link]",
+        );
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].text, "Buy");
+        assert_eq!(sections[0].text_size, 24.0);
+        assert_eq!(sections[1].text, " now");
+        assert_eq!(sections[1].text_size, 16.0);
+        for section in &sections {
+            assert_eq!(section.link.as_ref().map(|l| l.id.as_str()), Some("shop"));
+        }
+    }
+
+    #[test]
+    fn double_bracket_is_literal() {
+        let sections = sections(&MarkupParser::new(), "[[b]] [[[b]x[/b]");
+
+        let texts = sections.iter().map(|s| s.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, ["[b]] [", "x"]);
+    }
+
+    #[test]
+    fn markup_without_family_uses_default_font() {
+        let sections = NodeText::from(TextBuilder::markup("[b]E[/b]").unwrap()).sections;
+
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].text, "E");
+        assert_eq!(sections[0].font, "");
+    }
+
+    #[test]
+    fn errors_report_line_and_column() {
+        let parser = MarkupParser::new();
+
+        assert_eq!(
+            error(&parser, "ab[b"),
+            (1, 3, MarkupErrorKind::UnterminatedTag)
+        );
+        assert_eq!(
+            error(&parser, "é[x]"),
+            (1, 2, MarkupErrorKind::UnknownTag("x".to_string()))
+        );
+        assert_eq!(
+            error(&parser, "line\n[color]"),
+            (2, 1, MarkupErrorKind::MissingValue("color".to_string()))
+        );
+        assert_eq!(
+            error(&parser, "[b=1]"),
+            (1, 1, MarkupErrorKind::UnexpectedValue("b".to_string()))
+        );
+        assert_eq!(
+            error(&parser, "日本\n語 [size=-1]"),
+            (
+                2,
+                3,
+                MarkupErrorKind::InvalidValue {
+                    tag: "size".to_string(),
+                    value: "-1".to_string(),
+                }
+            )
+        );
+        assert_eq!(
+            error(&parser, "[color=nope]"),
+            (
+                1,
+                1,
+                MarkupErrorKind::InvalidValue {
+                    tag: "color".to_string(),
+                    value: "nope".to_string(),
+                }
+            )
+        );
+        assert_eq!(
+            error(&parser, "a\nb\n [font=serif]x[/font]"),
+            (
+                3,
+                2,
+                MarkupErrorKind::UnknownFontFamily("serif".to_string())
+            )
+        );
+        assert_eq!(
+            error(&parser, "[b]ü[/i]"),
+            (
+                1,
+                5,
+                MarkupErrorKind::MismatchedClose {
+                    expected: Some("b".to_string()),
+                    found: "i".to_string(),
+                }
+            )
+        );
+        assert_eq!(
+            error(&parser, "x[/b]"),
+            (
+                1,
+                2,
+                MarkupErrorKind::MismatchedClose {
+                    expected: None,
+                    found: "b".to_string(),
+                }
+            )
+        );
+        assert_eq!(
+            error(&parser, "a\n[b]x[[i]"),
+            (2, 1, MarkupErrorKind::UnclosedTag("b".to_string()))
+        );
+    }
+
+    #[test]
+    fn unknown_base_family_is_an_error() {
+        let families = families();
+        let parser = MarkupParser::new().families(&families).family("serif");
+
+        assert_eq!(
+            error(&parser, "text"),
+            (
+                1,
+                1,
+                MarkupErrorKind::UnknownFontFamily("serif".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn font_falls_back_to_available_styles() {
+        let family = FontFamily::new("regular");
+        assert_eq!(family.font(false, false), "regular");
+        assert_eq!(family.font(true, false), "regular");
+        assert_eq!(family.font(false, true), "regular");
+        assert_eq!(family.font(true, true), "regular");
+
+        let family = FontFamily::new("regular").italic("italic");
+        assert_eq!(family.font(true, false), "regular");
+        assert_eq!(family.font(false, true), "italic");
+        assert_eq!(family.font(true, true), "italic");
+
+        let family = family.bold("bold");
+        assert_eq!(family.font(true, false), "bold");
+        assert_eq!(family.font(true, true), "bold");
+
+        let family = family.bold_italic("bold-italic");
+        assert_eq!(family.font(true, true), "bold-italic");
+        assert_eq!(family.font(false, false), "regular");
+    }
+}
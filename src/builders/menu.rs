@@ -0,0 +1,240 @@
+//! Builders for defining menus, menu bars, and context menus.
+
+use bevy::prelude::*;
+
+use crate::prelude::{
+    MenuEntry,
+    MenuStyle,
+    NodeBackground,
+    NodeMenu,
+    NodeMenuBar,
+    NodeMenuBarItem,
+    NodePosition,
+    NodeText,
+    UiNode,
+};
+
+/// A builder for defining an action item within a menu.
+#[derive(Debug, Clone)]
+pub struct MenuItemBuilder {
+    /// The item being defined.
+    entry: MenuEntry,
+}
+
+impl MenuItemBuilder {
+    /// Creates a new action item with the given id and label.
+    ///
+    /// The id is sent with the menu action event when the item is clicked.
+    pub fn new<I: Into<String>, L: Into<String>>(id: I, label: L) -> Self {
+        Self {
+            entry: MenuEntry::Action {
+                id: id.into(),
+                label: label.into(),
+                accelerator: None,
+                enabled: true,
+                checked: None,
+            },
+        }
+    }
+
+    /// Sets the keyboard accelerator displayed on the right side of the item.
+    pub fn accelerator<T: Into<String>>(mut self, text: T) -> Self {
+        if let MenuEntry::Action { accelerator, .. } = &mut self.entry {
+            *accelerator = Some(text.into());
+        }
+        self
+    }
+
+    /// Disables the item, preventing it from being clicked.
+    pub fn disabled(mut self) -> Self {
+        if let MenuEntry::Action { enabled, .. } = &mut self.entry {
+            *enabled = false;
+        }
+        self
+    }
+
+    /// Makes the item checkable, with the given initial state.
+    pub fn checkable(mut self, checked: bool) -> Self {
+        if let MenuEntry::Action { checked: state, .. } = &mut self.entry {
+            *state = Some(checked);
+        }
+        self
+    }
+}
+
+impl From<MenuItemBuilder> for MenuEntry {
+    fn from(builder: MenuItemBuilder) -> Self {
+        builder.entry
+    }
+}
+
+/// A builder for defining the entries and appearance of a menu.
+#[derive(Debug, Default, Clone)]
+pub struct MenuBuilder {
+    /// The menu being defined.
+    menu: NodeMenu,
+}
+
+impl MenuBuilder {
+    /// Adds an entry to the menu.
+    pub fn item<T: Into<MenuEntry>>(mut self, entry: T) -> Self {
+        self.menu.entries.push(entry.into());
+        self
+    }
+
+    /// Adds an action item with the given id and label to the menu.
+    pub fn action<I: Into<String>, L: Into<String>>(self, id: I, label: L) -> Self {
+        self.item(MenuItemBuilder::new(id, label))
+    }
+
+    /// Adds a separator to the menu.
+    pub fn separator(self) -> Self {
+        self.item(MenuEntry::Separator)
+    }
+
+    /// Adds an item that opens the given nested menu while hovered.
+    ///
+    /// The style of the nested menu is ignored, and the style of this menu is
+    /// used instead.
+    pub fn submenu<L: Into<String>>(self, label: L, menu: MenuBuilder) -> Self {
+        self.item(MenuEntry::Submenu {
+            label: label.into(),
+            entries: menu.menu.entries,
+            enabled: true,
+        })
+    }
+
+    /// Adds a disabled item that would open the given nested menu.
+    pub fn disabled_submenu<L: Into<String>>(self, label: L, menu: MenuBuilder) -> Self {
+        self.item(MenuEntry::Submenu {
+            label: label.into(),
+            entries: menu.menu.entries,
+            enabled: false,
+        })
+    }
+
+    /// Sets how the popups of the menu are displayed.
+    pub fn style(mut self, style: MenuStyle) -> Self {
+        self.menu.style = style;
+        self
+    }
+
+    /// Sets the background of each popup.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.menu.style.background = background.into();
+        self
+    }
+
+    /// Sets the style of the text displayed on each item.
+    ///
+    /// Only the style of the first section is used.
+    pub fn text<T: Into<NodeText>>(mut self, text: T) -> Self {
+        self.menu.style.text = text.into();
+        self
+    }
+
+    /// Sets the height of each item, in logical pixels.
+    pub fn item_height(mut self, height: f32) -> Self {
+        self.menu.style.item_height = height;
+        self
+    }
+
+    /// Sets the smallest width of each popup, in logical pixels.
+    pub fn min_width(mut self, width: f32) -> Self {
+        self.menu.style.min_width = width;
+        self
+    }
+
+    /// Sets the background color of the hovered item.
+    pub fn hover_color(mut self, color: Color) -> Self {
+        self.menu.style.hover_color = color;
+        self
+    }
+}
+
+impl From<MenuBuilder> for NodeMenu {
+    fn from(builder: MenuBuilder) -> Self {
+        builder.menu
+    }
+}
+
+/// A builder for defining a menu bar node.
+#[derive(Debug, Default, Clone)]
+pub struct MenuBarNodeBuilder {
+    /// The background of the menu bar.
+    background: NodeBackground,
+
+    /// The position of the menu bar.
+    position: NodePosition,
+
+    /// The menus of the menu bar.
+    bar: NodeMenuBar,
+}
+
+impl MenuBarNodeBuilder {
+    /// Sets the background of the menu bar.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the menu bar.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Adds a menu to the end of the menu bar, opened by a button with the
+    /// given label.
+    pub fn menu<L: Into<String>, M: Into<NodeMenu>>(mut self, label: L, menu: M) -> Self {
+        self.bar.items.push(NodeMenuBarItem {
+            label: label.into(),
+            menu: menu.into(),
+        });
+        self
+    }
+
+    /// Sets the style of the text displayed on each button.
+    ///
+    /// Only the style of the first section is used.
+    pub fn text<T: Into<NodeText>>(mut self, text: T) -> Self {
+        self.bar.text = text.into();
+        self
+    }
+
+    /// Sets the horizontal padding of each button.
+    pub fn button_padding(mut self, padding: Val) -> Self {
+        self.bar.button_padding = padding;
+        self
+    }
+
+    /// Sets the background color of the button whose menu is open or
+    /// hovered.
+    pub fn hover_color(mut self, color: Color) -> Self {
+        self.bar.hover_color = color;
+        self
+    }
+}
+
+impl From<MenuBarNodeBuilder> for UiNode {
+    fn from(builder: MenuBarNodeBuilder) -> Self {
+        UiNode::MenuBar {
+            background: builder.background,
+            position: builder.position,
+            bar: builder.bar,
+        }
+    }
+}
+
+/// An extension trait that allows a context menu to be added to any node.
+pub trait ContextMenuExt: Into<UiNode> {
+    /// Opens the given menu at the cursor when this node is right-clicked.
+    fn context_menu<T: Into<NodeMenu>>(self, menu: T) -> UiNode {
+        UiNode::WithContextMenu {
+            node: Box::new(self.into()),
+            menu: menu.into(),
+        }
+    }
+}
+
+impl<T: Into<UiNode>> ContextMenuExt for T {}
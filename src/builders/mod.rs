@@ -3,14 +3,56 @@
 
 mod background;
 mod bundle;
+mod collapsible;
+mod color_picker;
+mod dialogue;
+mod drag;
+mod key_bindings;
+mod log;
+mod markup;
+mod menu;
+mod modal;
 mod node;
 mod position;
+mod progress;
+mod radio;
+mod scroll;
+mod slot_grid;
+mod spinner;
+mod split;
+mod table;
+mod tabs;
 mod tex_scaling;
 mod text;
+mod tooltip;
+mod tree;
+mod virtual_list;
+mod window;
 
 pub use background::*;
 pub use bundle::*;
+pub use collapsible::*;
+pub use color_picker::*;
+pub use dialogue::*;
+pub use drag::*;
+pub use key_bindings::*;
+pub use log::*;
+pub use markup::*;
+pub use menu::*;
+pub use modal::*;
 pub use node::*;
 pub use position::*;
+pub use progress::*;
+pub use radio::*;
+pub use scroll::*;
+pub use slot_grid::*;
+pub use spinner::*;
+pub use split::*;
+pub use table::*;
+pub use tabs::*;
 pub use tex_scaling::*;
 pub use text::*;
+pub use tooltip::*;
+pub use tree::*;
+pub use virtual_list::*;
+pub use window::*;
@@ -0,0 +1,184 @@
+//! Builders for defining modal dialogs and the buttons that close them.
+
+use bevy::prelude::*;
+
+use crate::prelude::{
+    AnchorPoint,
+    ModalResult,
+    NodeBackground,
+    NodeModal,
+    NodePosition,
+    NodeText,
+    UiNode,
+};
+
+/// A builder for defining a modal dialog node.
+#[derive(Debug, Default, Clone)]
+pub struct ModalNodeBuilder {
+    /// The background of the dialog.
+    background: NodeBackground,
+
+    /// The position of the dialog within the screen.
+    position: NodePosition,
+
+    /// How the modal blocks the UI below it.
+    modal: NodeModal,
+
+    /// The children of the dialog.
+    children: Vec<UiNode>,
+}
+
+impl ModalNodeBuilder {
+    /// Creates a confirmation dialog that displays the given message above a
+    /// confirm button and a cancel button.
+    pub fn confirm<M, C, X>(message: M, confirm_label: C, cancel_label: X) -> Self
+    where
+        M: Into<NodeText>,
+        C: Into<NodeText>,
+        X: Into<NodeText>,
+    {
+        let button_background = NodeBackground::Color {
+            color: Color::rgb(0.3, 0.3, 0.3),
+        };
+        let button_position = NodePosition::Relative {
+            width: Val::Px(120.0),
+            height: Val::Px(32.0),
+        };
+
+        Self::default()
+            .background(NodeBackground::Color {
+                color: Color::rgb(0.15, 0.15, 0.15),
+            })
+            .position(NodePosition::Anchored {
+                anchor: AnchorPoint::Center,
+                width: Val::Px(400.0),
+                height: Val::Px(180.0),
+                margin: Val::Px(0.0),
+            })
+            .child(UiNode::Text {
+                background: NodeBackground::None,
+                position: NodePosition::Relative {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(75.0),
+                },
+                text: message.into(),
+            })
+            .child(UiNode::Panel {
+                background: NodeBackground::None,
+                position: NodePosition::Relative {
+                    width: Val::Percent(100.0),
+                    height: Val::Auto,
+                },
+                children: vec![
+                    ModalButtonNodeBuilder::new(ModalResult::Confirm, confirm_label)
+                        .background(button_background.clone())
+                        .position(button_position.clone())
+                        .into(),
+                    ModalButtonNodeBuilder::new(ModalResult::Cancel, cancel_label)
+                        .background(button_background)
+                        .position(button_position)
+                        .into(),
+                ]
+                .into(),
+            })
+    }
+
+    /// Sets the identifier of the modal, which is included in the event sent
+    /// when the modal is closed.
+    pub fn id<T: Into<String>>(mut self, id: T) -> Self {
+        self.modal.id = id.into();
+        self
+    }
+
+    /// Sets the color of the full-screen scrim displayed behind the dialog.
+    pub fn scrim(mut self, color: Color) -> Self {
+        self.modal.scrim = color;
+        self
+    }
+
+    /// Sets whether the modal may be cancelled by clicking on the scrim or by
+    /// pressing escape.
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.modal.dismissible = dismissible;
+        self
+    }
+
+    /// Sets the background of the dialog.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the dialog within the screen.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Adds a child to the dialog.
+    pub fn child<T: Into<UiNode>>(mut self, child: T) -> Self {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl From<ModalNodeBuilder> for UiNode {
+    fn from(builder: ModalNodeBuilder) -> Self {
+        UiNode::Modal {
+            background: builder.background,
+            position: builder.position,
+            modal: builder.modal,
+            children: builder.children.into(),
+        }
+    }
+}
+
+/// A builder for defining a button that closes the modal it is within.
+#[derive(Debug, Default, Clone)]
+pub struct ModalButtonNodeBuilder {
+    /// The background of the button.
+    background: NodeBackground,
+
+    /// The position of the button.
+    position: NodePosition,
+
+    /// The label of the button.
+    text: NodeText,
+
+    /// The result to close the modal with.
+    result: ModalResult,
+}
+
+impl ModalButtonNodeBuilder {
+    /// Creates a new modal button with the given result and label.
+    pub fn new<T: Into<NodeText>>(result: ModalResult, text: T) -> Self {
+        Self {
+            text: text.into(),
+            result,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the background of the button.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the button.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+}
+
+impl From<ModalButtonNodeBuilder> for UiNode {
+    fn from(builder: ModalButtonNodeBuilder) -> Self {
+        UiNode::ModalButton {
+            background: builder.background,
+            position: builder.position,
+            text: builder.text,
+            result: builder.result,
+        }
+    }
+}
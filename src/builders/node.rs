@@ -1,6 +1,36 @@
 //! A builder for defining a [`UiNode`].
 
-use crate::prelude::{NodeBackground, NodePosition, NodeText, UiNode};
+use crate::prelude::{
+    AccordionNodeBuilder,
+    BindingSource,
+    CollapsibleNodeBuilder,
+    ColorPickerNodeBuilder,
+    DialogueNodeBuilder,
+    KeyBindingsNodeBuilder,
+    LogViewNodeBuilder,
+    MenuBarNodeBuilder,
+    ModalButtonNodeBuilder,
+    ModalNodeBuilder,
+    ModalResult,
+    NodeBackground,
+    NodePosition,
+    NodeScroll,
+    NodeText,
+    ProgressBarNodeBuilder,
+    RadioGroupNodeBuilder,
+    RowBuilder,
+    SlotGridNodeBuilder,
+    SpinnerNodeBuilder,
+    SplitDirection,
+    SplitNodeBuilder,
+    TableNodeBuilder,
+    TabsNodeBuilder,
+    TreeItemNodeBuilder,
+    TreeNodeBuilder,
+    UiNode,
+    VirtualListNodeBuilder,
+    WindowNodeBuilder,
+};
 
 /// A builder for defining a [`UiNode`].
 #[derive(Debug, Default, Clone)]
@@ -24,6 +54,149 @@ impl UiNodeBuilder {
             ..Default::default()
         }
     }
+
+    /// Sets the type of the node to be a scroll view.
+    pub fn scroll_view() -> ScrollViewNodeBuilder {
+        ScrollViewNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be a virtual list.
+    ///
+    /// Only the rows that are currently visible are spawned, with each row
+    /// being created by the given row builder as it scrolls into view.
+    pub fn virtual_list<T: Into<RowBuilder>>(
+        item_count: usize,
+        row_height: f32,
+        row_builder: T,
+    ) -> VirtualListNodeBuilder {
+        VirtualListNodeBuilder::new(item_count, row_height, row_builder)
+    }
+
+    /// Sets the type of the node to be a table.
+    pub fn table() -> TableNodeBuilder {
+        TableNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be a tab container.
+    pub fn tabs() -> TabsNodeBuilder {
+        TabsNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be a modal dialog.
+    ///
+    /// The dialog is displayed above all other UI, behind a scrim that blocks
+    /// input to the UI below it.
+    pub fn modal() -> ModalNodeBuilder {
+        ModalNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be a confirmation dialog, with the given
+    /// message and labels for the confirm and cancel buttons.
+    pub fn confirm_dialog<M, C, X>(
+        message: M,
+        confirm_label: C,
+        cancel_label: X,
+    ) -> ModalNodeBuilder
+    where
+        M: Into<NodeText>,
+        C: Into<NodeText>,
+        X: Into<NodeText>,
+    {
+        ModalNodeBuilder::confirm(message, confirm_label, cancel_label)
+    }
+
+    /// Sets the type of the node to be a collapsible section with the given
+    /// header text.
+    pub fn collapsible<T: Into<NodeText>>(header: T) -> CollapsibleNodeBuilder {
+        CollapsibleNodeBuilder::new(header)
+    }
+
+    /// Sets the type of the node to be an accordion, where only one of its
+    /// collapsible sections may be expanded at a time.
+    pub fn accordion() -> AccordionNodeBuilder {
+        AccordionNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be a color picker.
+    pub fn color_picker() -> ColorPickerNodeBuilder {
+        ColorPickerNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be a dialogue box.
+    pub fn dialogue() -> DialogueNodeBuilder {
+        DialogueNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be a log view.
+    pub fn log_view() -> LogViewNodeBuilder {
+        LogViewNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be a key binding list that edits the
+    /// bindings stored in the given resource type.
+    pub fn key_bindings<T: BindingSource>() -> KeyBindingsNodeBuilder {
+        KeyBindingsNodeBuilder::new::<T>()
+    }
+
+    /// Sets the type of the node to be a slot grid with the given number of
+    /// columns and rows.
+    pub fn slot_grid(columns: u16, rows: u16) -> SlotGridNodeBuilder {
+        SlotGridNodeBuilder::new(columns, rows)
+    }
+
+    /// Sets the type of the node to be a menu bar.
+    pub fn menu_bar() -> MenuBarNodeBuilder {
+        MenuBarNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be a progress bar.
+    pub fn progress_bar() -> ProgressBarNodeBuilder {
+        ProgressBarNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be a radial progress bar, with a ring of
+    /// the given thickness as a fraction of its radius.
+    pub fn radial_progress(thickness: f32) -> ProgressBarNodeBuilder {
+        ProgressBarNodeBuilder::radial(thickness)
+    }
+
+    /// Sets the type of the node to be a radio group.
+    pub fn radio_group() -> RadioGroupNodeBuilder {
+        RadioGroupNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be a numeric spinner.
+    pub fn spinner() -> SpinnerNodeBuilder {
+        SpinnerNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be a split pane that divides its area
+    /// between two panes in the given direction.
+    pub fn split(direction: SplitDirection) -> SplitNodeBuilder {
+        SplitNodeBuilder::new(direction)
+    }
+
+    /// Sets the type of the node to be a tree view.
+    pub fn tree() -> TreeNodeBuilder {
+        TreeNodeBuilder::default()
+    }
+
+    /// Sets the type of the node to be an item within a tree view, with the
+    /// given label.
+    pub fn tree_item<T: Into<NodeText>>(label: T) -> TreeItemNodeBuilder {
+        TreeItemNodeBuilder::new(label)
+    }
+
+    /// Sets the type of the node to be a floating window with the given title.
+    pub fn window<T: Into<NodeText>>(title: T) -> WindowNodeBuilder {
+        WindowNodeBuilder::new(title)
+    }
+
+    /// Sets the type of the node to be a button that closes the modal it is
+    /// within using the given result.
+    pub fn modal_button<T: Into<NodeText>>(result: ModalResult, text: T) -> ModalButtonNodeBuilder {
+        ModalButtonNodeBuilder::new(result, text)
+    }
 }
 
 /// A builder for defining a canvas node.
@@ -134,3 +307,56 @@ impl From<TextNodeBuilder> for UiNode {
         }
     }
 }
+
+/// A builder for defining a scroll view node.
+#[derive(Debug, Default, Clone)]
+pub struct ScrollViewNodeBuilder {
+    /// The background of the scroll view.
+    background: NodeBackground,
+
+    /// The position of the scroll view.
+    position: NodePosition,
+
+    /// How the contents of the scroll view are scrolled.
+    scroll: NodeScroll,
+
+    /// The children of the scroll view.
+    children: Vec<UiNode>,
+}
+
+impl ScrollViewNodeBuilder {
+    /// Sets the background of the scroll view.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the scroll view.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets how the contents of the scroll view are scrolled.
+    pub fn scroll<T: Into<NodeScroll>>(mut self, scroll: T) -> Self {
+        self.scroll = scroll.into();
+        self
+    }
+
+    /// Adds a child to the scroll view.
+    pub fn child<T: Into<UiNode>>(mut self, child: T) -> Self {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl From<ScrollViewNodeBuilder> for UiNode {
+    fn from(builder: ScrollViewNodeBuilder) -> Self {
+        UiNode::ScrollView {
+            background: builder.background,
+            position: builder.position,
+            scroll: builder.scroll,
+            children: builder.children.into(),
+        }
+    }
+}
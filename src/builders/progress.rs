@@ -0,0 +1,140 @@
+//! A builder for defining a progress bar node.
+
+use bevy::prelude::*;
+
+use crate::prelude::{
+    NodeBackground,
+    NodePosition,
+    NodeProgress,
+    NodeText,
+    ProgressDirection,
+    ProgressStyle,
+    UiNode,
+    ValueBinding,
+};
+
+/// A builder for defining a progress bar node.
+#[derive(Debug, Default, Clone)]
+pub struct ProgressBarNodeBuilder {
+    /// The background of the progress bar.
+    background: NodeBackground,
+
+    /// The position of the progress bar.
+    position: NodePosition,
+
+    /// How the progress bar displays its value.
+    progress: NodeProgress,
+}
+
+impl ProgressBarNodeBuilder {
+    /// Creates a new radial progress bar builder, which sweeps a ring of the
+    /// given thickness around the center of the node.
+    ///
+    /// The thickness is a fraction of the radius of the ring.
+    pub fn radial(thickness: f32) -> Self {
+        let mut builder = Self::default();
+        builder.progress.style = ProgressStyle::Radial {
+            fill: Color::WHITE,
+            track: Color::rgba(0.0, 0.0, 0.0, 0.25),
+            thickness,
+        };
+        builder
+    }
+
+    /// Sets the background of the progress bar, displayed behind the fill.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the progress bar.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets the initial value of the progress bar, between `0.0` and `1.0`.
+    pub fn value(mut self, value: f32) -> Self {
+        self.progress.value = value;
+        self
+    }
+
+    /// Sets the direction in which the fill grows.
+    pub fn direction(mut self, direction: ProgressDirection) -> Self {
+        self.progress.direction = direction;
+        self
+    }
+
+    /// Sets the background of the fill.
+    ///
+    /// This turns a radial progress bar into a linear one.
+    pub fn fill<T: Into<NodeBackground>>(mut self, fill: T) -> Self {
+        self.progress.style = ProgressStyle::Linear { fill: fill.into() };
+        self
+    }
+
+    /// Sets the colors of the filled and unfilled parts of the ring of a
+    /// radial progress bar.
+    ///
+    /// This has no effect on linear progress bars.
+    pub fn ring_colors(mut self, fill_color: Color, track_color: Color) -> Self {
+        if let ProgressStyle::Radial { fill, track, .. } = &mut self.progress.style {
+            *fill = fill_color;
+            *track = track_color;
+        }
+        self
+    }
+
+    /// Sets the label displayed over the progress bar.
+    pub fn label<T: Into<NodeText>>(mut self, label: T) -> Self {
+        self.progress.label = Some(label.into());
+        self
+    }
+
+    /// Sets the label to display the value of the progress bar as a
+    /// percentage.
+    ///
+    /// The first section of the label is used to display the percentage. If no
+    /// label is set, white text is used.
+    pub fn percent_label(mut self) -> Self {
+        self.progress.percent_label = true;
+        self
+    }
+
+    /// Binds the value of the progress bar to the given binding, which is read
+    /// every frame.
+    pub fn bind(mut self, binding: ValueBinding) -> Self {
+        self.progress.binding = Some(binding);
+        self
+    }
+
+    /// Binds the value of the progress bar to a value read from the given
+    /// resource every frame.
+    pub fn bind_resource<R, F>(self, func: F) -> Self
+    where
+        R: Resource,
+        F: Fn(&R) -> f32 + Send + Sync + 'static,
+    {
+        self.bind(ValueBinding::resource(func))
+    }
+
+    /// Binds the value of the progress bar to a value read from a component of
+    /// the given entity every frame.
+    pub fn bind_component<C, F>(self, entity: Entity, func: F) -> Self
+    where
+        C: Component,
+        F: Fn(&C) -> f32 + Send + Sync + 'static,
+    {
+        self.bind(ValueBinding::component(entity, func))
+    }
+}
+
+impl From<ProgressBarNodeBuilder> for UiNode {
+    fn from(builder: ProgressBarNodeBuilder) -> Self {
+        UiNode::ProgressBar {
+            background: builder.background,
+            position: builder.position,
+            progress: builder.progress,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! A builder for defining a radio group node.
+
+use bevy::prelude::*;
+
+use crate::prelude::{NodeBackground, NodePosition, NodeRadioGroup, NodeText, UiNode};
+
+/// A builder for defining a radio group node.
+#[derive(Debug, Default, Clone)]
+pub struct RadioGroupNodeBuilder {
+    /// The background of the radio group.
+    background: NodeBackground,
+
+    /// The position of the radio group.
+    position: NodePosition,
+
+    /// The options within the radio group.
+    radio: NodeRadioGroup,
+}
+
+impl RadioGroupNodeBuilder {
+    /// Sets the background of the radio group.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the radio group.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Adds an option to the radio group with the given label.
+    pub fn option<T: Into<NodeText>>(mut self, label: T) -> Self {
+        self.radio.options.push(label.into());
+        self
+    }
+
+    /// Sets the index of the option that is initially selected.
+    pub fn selected(mut self, index: usize) -> Self {
+        self.radio.selected = Some(index);
+        self
+    }
+
+    /// Lays out the options in a row instead of a column.
+    pub fn horizontal(mut self) -> Self {
+        self.radio.horizontal = true;
+        self
+    }
+
+    /// Sets the space between each option, and between an indicator and its
+    /// label.
+    pub fn gap(mut self, gap: Val) -> Self {
+        self.radio.gap = gap;
+        self
+    }
+
+    /// Sets the size of the indicator displayed next to each option.
+    pub fn indicator_size(mut self, size: Val) -> Self {
+        self.radio.indicator_size = size;
+        self
+    }
+
+    /// Sets the backgrounds of the indicators of unselected and selected
+    /// options.
+    pub fn indicators<U, S>(mut self, unselected: U, selected: S) -> Self
+    where
+        U: Into<NodeBackground>,
+        S: Into<NodeBackground>,
+    {
+        self.radio.indicator = unselected.into();
+        self.radio.selected_indicator = selected.into();
+        self
+    }
+}
+
+impl From<RadioGroupNodeBuilder> for UiNode {
+    fn from(builder: RadioGroupNodeBuilder) -> Self {
+        UiNode::RadioGroup {
+            background: builder.background,
+            position: builder.position,
+            radio: builder.radio,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! A builder for defining how the contents of a node are scrolled.
+
+use crate::prelude::{NodeBackground, NodeScroll, NodeScrollbar, ScrollDirection};
+
+/// A builder for defining how the contents of a node are scrolled.
+#[derive(Debug, Clone)]
+pub struct ScrollBuilder {
+    /// The axes along which the contents may be scrolled.
+    direction: ScrollDirection,
+
+    /// The number of logical pixels to scroll per mouse wheel line or key
+    /// press.
+    scroll_speed: f32,
+
+    /// The rate at which scrolling momentum decays, if any.
+    momentum: Option<f32>,
+
+    /// The scrollbars to display, if any.
+    scrollbar: Option<NodeScrollbar>,
+}
+
+impl ScrollBuilder {
+    /// Sets the axes along which the contents may be scrolled.
+    pub fn direction(mut self, direction: ScrollDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the number of logical pixels to scroll per mouse wheel line or key
+    /// press.
+    pub fn scroll_speed(mut self, scroll_speed: f32) -> Self {
+        self.scroll_speed = scroll_speed;
+        self
+    }
+
+    /// Enables scrolling momentum.
+    ///
+    /// The decay value defines how quickly the contents slow down after being
+    /// scrolled. Higher values cause the contents to stop sooner.
+    pub fn momentum(mut self, decay: f32) -> Self {
+        self.momentum = Some(decay);
+        self
+    }
+
+    /// Sets the scrollbars to display.
+    pub fn scrollbar<T: Into<NodeScrollbar>>(mut self, scrollbar: T) -> Self {
+        self.scrollbar = Some(scrollbar.into());
+        self
+    }
+
+    /// Hides the scrollbars.
+    ///
+    /// The contents may still be scrolled using the mouse wheel, keyboard, or
+    /// gamepad.
+    pub fn no_scrollbar(mut self) -> Self {
+        self.scrollbar = None;
+        self
+    }
+}
+
+impl Default for ScrollBuilder {
+    fn default() -> Self {
+        let scroll = NodeScroll::default();
+        Self {
+            direction: scroll.direction,
+            scroll_speed: scroll.scroll_speed,
+            momentum: scroll.momentum,
+            scrollbar: scroll.scrollbar,
+        }
+    }
+}
+
+impl From<ScrollBuilder> for NodeScroll {
+    fn from(builder: ScrollBuilder) -> Self {
+        NodeScroll {
+            direction: builder.direction,
+            scroll_speed: builder.scroll_speed,
+            momentum: builder.momentum,
+            scrollbar: builder.scrollbar,
+        }
+    }
+}
+
+/// A builder for defining how the scrollbars of a scroll view are displayed.
+#[derive(Debug, Clone)]
+pub struct ScrollbarBuilder {
+    /// The thickness of the scrollbar, in logical pixels.
+    thickness: f32,
+
+    /// The background of the scrollbar track.
+    track: NodeBackground,
+
+    /// The background of the draggable scrollbar thumb.
+    thumb: NodeBackground,
+}
+
+impl ScrollbarBuilder {
+    /// Sets the thickness of the scrollbar, in logical pixels.
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets the background of the scrollbar track.
+    pub fn track<T: Into<NodeBackground>>(mut self, track: T) -> Self {
+        self.track = track.into();
+        self
+    }
+
+    /// Sets the background of the draggable scrollbar thumb.
+    pub fn thumb<T: Into<NodeBackground>>(mut self, thumb: T) -> Self {
+        self.thumb = thumb.into();
+        self
+    }
+}
+
+impl Default for ScrollbarBuilder {
+    fn default() -> Self {
+        let scrollbar = NodeScrollbar::default();
+        Self {
+            thickness: scrollbar.thickness,
+            track: scrollbar.track,
+            thumb: scrollbar.thumb,
+        }
+    }
+}
+
+impl From<ScrollbarBuilder> for NodeScrollbar {
+    fn from(builder: ScrollbarBuilder) -> Self {
+        NodeScrollbar {
+            thickness: builder.thickness,
+            track: builder.track,
+            thumb: builder.thumb,
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! A builder for defining slot grid nodes.
+
+use bevy::prelude::*;
+
+use crate::prelude::{AnchorPoint, NodeBackground, NodePosition, NodeSlotGrid, NodeText, UiNode};
+
+/// A builder for defining a slot grid node, such as an inventory or hotbar.
+#[derive(Debug, Default, Clone)]
+pub struct SlotGridNodeBuilder {
+    /// The background of the slot grid.
+    background: NodeBackground,
+
+    /// The position of the slot grid.
+    position: NodePosition,
+
+    /// The layout and style of the slots.
+    grid: NodeSlotGrid,
+}
+
+impl SlotGridNodeBuilder {
+    /// Creates a new slot grid builder with the given number of columns and
+    /// rows.
+    pub fn new(columns: u16, rows: u16) -> Self {
+        Self {
+            grid: NodeSlotGrid {
+                columns,
+                rows,
+                ..default()
+            },
+            ..default()
+        }
+    }
+
+    /// Sets the background of the slot grid.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the slot grid.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Binds the slot grid to the given entity, whose
+    /// [`SlotSource`](crate::prelude::SlotSource) component provides the
+    /// contents of the slots.
+    pub fn source(mut self, source: Entity) -> Self {
+        self.grid.source = Some(source);
+        self
+    }
+
+    /// Sets the width and height of each slot, in logical pixels.
+    pub fn slot_size(mut self, size: f32) -> Self {
+        self.grid.slot_size = size;
+        self
+    }
+
+    /// Sets the space between each slot, in logical pixels.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.grid.spacing = spacing;
+        self
+    }
+
+    /// Sets the background of each slot, which is usually a sliced frame
+    /// image.
+    pub fn slot_background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.grid.slot_background = background.into();
+        self
+    }
+
+    /// Sets the space between the edge of a slot and its item icon, in
+    /// logical pixels.
+    pub fn icon_padding(mut self, padding: f32) -> Self {
+        self.grid.icon_padding = padding;
+        self
+    }
+
+    /// Sets the style of the stack count text, and the corner of each slot it
+    /// is displayed in.
+    pub fn count_text<T: Into<NodeText>>(mut self, text: T, anchor: AnchorPoint) -> Self {
+        self.grid.count_text = text.into();
+        self.grid.count_anchor = anchor;
+        self
+    }
+
+    /// Displays a number label on the first ten slots, and allows them to be
+    /// selected by pressing the matching number key.
+    pub fn hotkeys(mut self) -> Self {
+        self.grid.hotkeys = true;
+        self
+    }
+
+    /// Sets the style of the hotkey number labels.
+    pub fn hotkey_text<T: Into<NodeText>>(mut self, text: T) -> Self {
+        self.grid.hotkey_text = text.into();
+        self
+    }
+
+    /// Sets the initially selected slot.
+    pub fn selected(mut self, index: usize) -> Self {
+        self.grid.selected = Some(index);
+        self
+    }
+
+    /// Sets the border color and width of the selected slot.
+    pub fn selection(mut self, color: Color, width: f32) -> Self {
+        self.grid.selection_color = color;
+        self.grid.selection_width = width;
+        self
+    }
+}
+
+impl From<SlotGridNodeBuilder> for UiNode {
+    fn from(builder: SlotGridNodeBuilder) -> Self {
+        UiNode::SlotGrid {
+            background: builder.background,
+            position: builder.position,
+            grid: builder.grid,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! A builder for defining a numeric spinner node.
+
+use bevy::prelude::*;
+
+use crate::prelude::{NodeBackground, NodePosition, NodeSpinner, NodeText, NumberFormat, UiNode};
+
+/// A builder for defining a numeric spinner node.
+#[derive(Debug, Default, Clone)]
+pub struct SpinnerNodeBuilder {
+    /// The background of the spinner.
+    background: NodeBackground,
+
+    /// The position of the spinner.
+    position: NodePosition,
+
+    /// The value and settings of the spinner.
+    spinner: NodeSpinner,
+}
+
+impl SpinnerNodeBuilder {
+    /// Sets the background of the spinner.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the spinner.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets the initial value of the spinner.
+    pub fn value(mut self, value: f32) -> Self {
+        self.spinner.value = value;
+        self
+    }
+
+    /// Sets the smallest and largest values the spinner may hold.
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.spinner.min = min;
+        self.spinner.max = max;
+        self
+    }
+
+    /// Sets the amount the value changes per button press, arrow key, or scrub
+    /// increment.
+    pub fn step(mut self, step: f32) -> Self {
+        self.spinner.step = step;
+        self
+    }
+
+    /// Sets the spinner to only hold whole numbers.
+    pub fn integer(mut self) -> Self {
+        self.spinner.format = NumberFormat::Integer;
+        self
+    }
+
+    /// Sets the number of digits displayed after the decimal point.
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.spinner.format = NumberFormat::Float { decimals };
+        self
+    }
+
+    /// Sets the distance, in logical pixels, the label must be dragged to
+    /// change the value by one step.
+    pub fn scrub_distance(mut self, distance: f32) -> Self {
+        self.spinner.scrub_distance = distance;
+        self
+    }
+
+    /// Sets the style of the text used to display the value and the button
+    /// labels.
+    ///
+    /// Only the style of the first section is used.
+    pub fn text<T: Into<NodeText>>(mut self, text: T) -> Self {
+        self.spinner.text = text.into();
+        self
+    }
+
+    /// Sets the background of the increment and decrement buttons.
+    pub fn button_background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.spinner.button_background = background.into();
+        self
+    }
+
+    /// Sets the width of the increment and decrement buttons.
+    pub fn button_width(mut self, width: Val) -> Self {
+        self.spinner.button_width = width;
+        self
+    }
+}
+
+impl From<SpinnerNodeBuilder> for UiNode {
+    fn from(builder: SpinnerNodeBuilder) -> Self {
+        UiNode::Spinner {
+            background: builder.background,
+            position: builder.position,
+            spinner: builder.spinner,
+        }
+    }
+}
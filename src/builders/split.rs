@@ -0,0 +1,119 @@
+//! A builder for defining a split pane node.
+
+use bevy::prelude::*;
+
+use crate::prelude::{NodeBackground, NodePosition, NodeSplit, SplitDirection, UiNode};
+
+/// A builder for defining a split pane node.
+#[derive(Debug, Clone)]
+pub struct SplitNodeBuilder {
+    /// The background of the split pane.
+    background: NodeBackground,
+
+    /// The position of the split pane.
+    position: NodePosition,
+
+    /// How the area is divided between the panes.
+    split: NodeSplit,
+
+    /// The contents of the first pane.
+    first: UiNode,
+
+    /// The contents of the second pane.
+    second: UiNode,
+}
+
+impl SplitNodeBuilder {
+    /// Creates a new split pane builder that divides its area in the given
+    /// direction, with two empty panes.
+    pub fn new(direction: SplitDirection) -> Self {
+        let empty = || UiNode::Panel {
+            background: default(),
+            position: NodePosition::Relative {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+            },
+            children: default(),
+        };
+
+        Self {
+            background: default(),
+            position: default(),
+            split: NodeSplit {
+                direction,
+                ..default()
+            },
+            first: empty(),
+            second: empty(),
+        }
+    }
+
+    /// Sets the background of the split pane.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the split pane.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets the contents of the first pane, on the left or top.
+    pub fn first<T: Into<UiNode>>(mut self, node: T) -> Self {
+        self.first = node.into();
+        self
+    }
+
+    /// Sets the contents of the second pane, on the right or bottom.
+    pub fn second<T: Into<UiNode>>(mut self, node: T) -> Self {
+        self.second = node.into();
+        self
+    }
+
+    /// Sets the initial fraction of the area given to the first pane.
+    pub fn ratio(mut self, ratio: f32) -> Self {
+        self.split.ratio = ratio;
+        self
+    }
+
+    /// Sets the smallest sizes of the first and second panes while dragging
+    /// the divider, in logical pixels.
+    pub fn min_sizes(mut self, first: f32, second: f32) -> Self {
+        self.split.min_sizes = [first, second];
+        self
+    }
+
+    /// Sets the thickness of the divider, in logical pixels.
+    pub fn divider_size(mut self, size: f32) -> Self {
+        self.split.divider_size = size;
+        self
+    }
+
+    /// Sets the colors of the divider while idle and while hovered or
+    /// dragged.
+    pub fn divider_colors(mut self, color: Color, hover_color: Color) -> Self {
+        self.split.divider_color = color;
+        self.split.divider_hover_color = hover_color;
+        self
+    }
+
+    /// Sets whether double-clicking the divider collapses the smaller pane.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.split.collapsible = collapsible;
+        self
+    }
+}
+
+impl From<SplitNodeBuilder> for UiNode {
+    fn from(builder: SplitNodeBuilder) -> Self {
+        UiNode::Split {
+            background: builder.background,
+            position: builder.position,
+            split: builder.split,
+            first: Box::new(builder.first),
+            second: Box::new(builder.second),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! A builder for defining a table node.
+
+use bevy::prelude::*;
+
+use crate::prelude::{
+    AnchorPoint,
+    NodeBackground,
+    NodePosition,
+    NodeTable,
+    NodeTableColumn,
+    NodeTableRow,
+    NodeText,
+    SortOrder,
+    UiNode,
+};
+
+/// A builder for defining a table node.
+#[derive(Debug, Default, Clone)]
+pub struct TableNodeBuilder {
+    /// The background of the table.
+    background: NodeBackground,
+
+    /// The position of the table.
+    position: NodePosition,
+
+    /// The columns and rows of the table.
+    table: NodeTable,
+}
+
+impl TableNodeBuilder {
+    /// Sets the background of the table.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the table.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Adds a sortable column to the table with the given header text, width,
+    /// and cell alignment.
+    pub fn column<T: Into<NodeText>>(mut self, header: T, width: f32, anchor: AnchorPoint) -> Self {
+        self.table.columns.push(NodeTableColumn {
+            header: header.into(),
+            width,
+            anchor,
+            ..default()
+        });
+        self
+    }
+
+    /// Adds a column to the table using the given definition.
+    pub fn column_def(mut self, column: NodeTableColumn) -> Self {
+        self.table.columns.push(column);
+        self
+    }
+
+    /// Adds a row to the table.
+    pub fn row<T: Into<NodeTableRow>>(mut self, row: T) -> Self {
+        self.table.rows.push(row.into());
+        self
+    }
+
+    /// Adds multiple rows to the table.
+    pub fn rows<I>(mut self, rows: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<NodeTableRow>,
+    {
+        self.table.rows.extend(rows.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the column and order the rows are initially sorted by.
+    pub fn sort_by(mut self, column: usize, order: SortOrder) -> Self {
+        self.table.sort = Some((column, order));
+        self
+    }
+
+    /// Prevents the columns from being resized.
+    pub fn fixed_columns(mut self) -> Self {
+        self.table.resizable = false;
+        self
+    }
+
+    /// Only spawns the visible rows, along with the given number of additional
+    /// rows above and below the visible area.
+    pub fn virtualized(mut self, buffer: usize) -> Self {
+        self.table.virtualized = Some(buffer);
+        self
+    }
+
+    /// Sets the height of the header, in logical pixels.
+    pub fn header_height(mut self, height: f32) -> Self {
+        self.table.header_height = height;
+        self
+    }
+
+    /// Sets the background of the header.
+    pub fn header_background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.table.header_background = background.into();
+        self
+    }
+
+    /// Sets the height of each row, in logical pixels.
+    pub fn row_height(mut self, height: f32) -> Self {
+        self.table.row_height = height;
+        self
+    }
+
+    /// Sets the background colors of even and odd rows.
+    pub fn row_colors(mut self, even: Color, odd: Color) -> Self {
+        self.table.row_colors = [even, odd];
+        self
+    }
+
+    /// Sets the background color of the selected row.
+    pub fn selected_color(mut self, color: Color) -> Self {
+        self.table.selected_color = color;
+        self
+    }
+
+    /// Sets the style of the text displayed in text cells.
+    ///
+    /// Only the style of the first section is used.
+    pub fn cell_text<T: Into<NodeText>>(mut self, text: T) -> Self {
+        self.table.cell_text = text.into();
+        self
+    }
+
+    /// Sets the text displayed next to the header of a column sorted in
+    /// ascending and descending order.
+    pub fn sort_icons<A, D>(mut self, ascending: A, descending: D) -> Self
+    where
+        A: Into<String>,
+        D: Into<String>,
+    {
+        self.table.sort_icons = [ascending.into(), descending.into()];
+        self
+    }
+}
+
+impl From<TableNodeBuilder> for UiNode {
+    fn from(builder: TableNodeBuilder) -> Self {
+        UiNode::Table {
+            background: builder.background,
+            position: builder.position,
+            table: builder.table,
+        }
+    }
+}
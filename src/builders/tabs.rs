@@ -0,0 +1,101 @@
+//! A builder for defining a tab container node.
+
+use bevy::prelude::*;
+
+use crate::prelude::{NodeBackground, NodePosition, NodeTab, NodeTabs, NodeText, UiNode};
+
+/// A builder for defining a tab container node.
+#[derive(Debug, Default, Clone)]
+pub struct TabsNodeBuilder {
+    /// The background of the tab container.
+    background: NodeBackground,
+
+    /// The position of the tab container.
+    position: NodePosition,
+
+    /// The tabs within the container.
+    tabs: NodeTabs,
+}
+
+impl TabsNodeBuilder {
+    /// Sets the background of the tab container.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the tab container.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Adds a tab to the container with the given title and contents.
+    pub fn tab<T, C>(mut self, title: T, content: C) -> Self
+    where
+        T: Into<NodeText>,
+        C: Into<UiNode>,
+    {
+        self.tabs.tabs.push(NodeTab {
+            title: title.into(),
+            content: content.into(),
+        });
+        self
+    }
+
+    /// Sets the index of the tab that is initially active.
+    pub fn active(mut self, active: usize) -> Self {
+        self.tabs.active = active;
+        self
+    }
+
+    /// Sets the contents of each tab to only be built when the tab is shown
+    /// for the first time.
+    pub fn lazy(mut self) -> Self {
+        self.tabs.lazy = true;
+        self
+    }
+
+    /// Sets the background of the header strip containing the tab buttons.
+    pub fn header_background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.tabs.header_background = background.into();
+        self
+    }
+
+    /// Sets the height of the header strip.
+    pub fn header_height(mut self, height: Val) -> Self {
+        self.tabs.header_height = height;
+        self
+    }
+
+    /// Sets the background of each tab button.
+    ///
+    /// The background is tinted using the tab colors.
+    pub fn tab_background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.tabs.tab_background = background.into();
+        self
+    }
+
+    /// Sets the width of each tab button.
+    pub fn tab_width(mut self, width: Val) -> Self {
+        self.tabs.tab_width = width;
+        self
+    }
+
+    /// Sets the tint colors of inactive and active tab buttons.
+    pub fn tab_colors(mut self, color: Color, active_color: Color) -> Self {
+        self.tabs.tab_color = color;
+        self.tabs.active_tab_color = active_color;
+        self
+    }
+}
+
+impl From<TabsNodeBuilder> for UiNode {
+    fn from(builder: TabsNodeBuilder) -> Self {
+        UiNode::Tabs {
+            background: builder.background,
+            position: builder.position,
+            tabs: builder.tabs,
+        }
+    }
+}
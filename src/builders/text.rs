@@ -3,7 +3,14 @@
 use bevy::prelude::*;
 use bevy::text::BreakLineOn;
 
-use crate::prelude::{AnchorPoint, NodeText, NodeTextSection};
+use crate::prelude::{
+    AnchorPoint,
+    MarkupError,
+    MarkupParser,
+    NodeText,
+    NodeTextLink,
+    NodeTextSection,
+};
 
 /// A builder for defining how text is displayed within a node.
 #[derive(Debug, Clone)]
@@ -19,6 +26,21 @@ pub struct TextBuilder {
 }
 
 impl TextBuilder {
+    /// Creates a new text builder from the given markup, such as
+    /// `"Press [color=yellow][b]E[/b][/color] to use"`, using the default text
+    /// style.
+    ///
+    /// Use a [`MarkupParser`] to change the default style or to use font
+    /// families. See the parser for the supported tags.
+    ///
+    /// Without a font family, all text uses Bevy's built-in default font, so
+    /// `[b]` and `[i]` have no visible effect. Register a
+    /// [`FontFamily`](crate::prelude::FontFamily) with bold and italic fonts
+    /// and set it with [`MarkupParser::family`] to display styled text.
+    pub fn markup(source: &str) -> Result<Self, MarkupError> {
+        MarkupParser::default().parse(source)
+    }
+
     /// Sets the anchor point for the text.
     pub fn anchor_point(mut self, anchor_point: AnchorPoint) -> Self {
         self.anchor_point = anchor_point;
@@ -72,6 +94,12 @@ pub struct TextSectionBuilder {
 
     /// The color of the text.
     color: Color,
+
+    /// The path of the inline image displayed instead of text, if any.
+    image: Option<String>,
+
+    /// The link of the section, if any.
+    link: Option<NodeTextLink>,
 }
 
 impl TextSectionBuilder {
@@ -82,6 +110,18 @@ impl TextSectionBuilder {
             font: Default::default(),
             text_size: 16.0,
             color: Color::BLACK,
+            image: None,
+            link: None,
+        }
+    }
+
+    /// Creates a new text section builder that displays the image at the
+    /// given path inline with the text, scaled to the line height of the text
+    /// size.
+    pub fn image<S: Into<String>>(path: S) -> Self {
+        Self {
+            image: Some(path.into()),
+            ..Self::new("")
         }
     }
 
@@ -102,6 +142,26 @@ impl TextSectionBuilder {
         self.color = color;
         self
     }
+
+    /// Makes the section a link with the given identifier, which is sent
+    /// with a [`LinkClicked`](crate::prelude::LinkClicked) event when
+    /// clicked.
+    pub fn link<S: Into<String>>(mut self, id: S) -> Self {
+        self.link = Some(NodeTextLink::new(id));
+        self
+    }
+
+    /// Sets the color of the link while hovered, and whether it is underlined
+    /// while hovered.
+    ///
+    /// This has no effect unless the section is a link.
+    pub fn link_hover(mut self, color: Color, underline: bool) -> Self {
+        if let Some(link) = &mut self.link {
+            link.hover_color = color;
+            link.underline = underline;
+        }
+        self
+    }
 }
 
 impl From<TextSectionBuilder> for NodeTextSection {
@@ -111,6 +171,8 @@ impl From<TextSectionBuilder> for NodeTextSection {
             font: builder.font,
             text_size: builder.text_size,
             color: builder.color,
+            image: builder.image,
+            link: builder.link,
         }
     }
 }
@@ -0,0 +1,63 @@
+//! A builder for defining the tooltip of a node.
+
+use crate::prelude::{AnchorPoint, NodeTooltip, TooltipTarget, UiNode};
+
+/// A builder for defining the tooltip of a node.
+#[derive(Debug, Default, Clone)]
+pub struct TooltipBuilder {
+    /// The tooltip being built.
+    tooltip: NodeTooltip,
+}
+
+impl TooltipBuilder {
+    /// Creates a new tooltip builder with the given contents.
+    pub fn new<T: Into<NodeTooltip>>(content: T) -> Self {
+        Self {
+            tooltip: content.into(),
+        }
+    }
+
+    /// Sets the time, in seconds, the node must be hovered before the tooltip
+    /// is shown.
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.tooltip.delay = Some(delay);
+        self
+    }
+
+    /// Positions the tooltip next to the cursor.
+    pub fn at_cursor(mut self) -> Self {
+        self.tooltip.target = TooltipTarget::Cursor;
+        self
+    }
+
+    /// Positions the tooltip next to the hovered node.
+    pub fn at_node(mut self) -> Self {
+        self.tooltip.target = TooltipTarget::Node;
+        self
+    }
+
+    /// Sets the preferred side of the target to display the tooltip on.
+    pub fn anchor(mut self, anchor: AnchorPoint) -> Self {
+        self.tooltip.anchor = anchor;
+        self
+    }
+}
+
+impl From<TooltipBuilder> for NodeTooltip {
+    fn from(builder: TooltipBuilder) -> Self {
+        builder.tooltip
+    }
+}
+
+/// An extension trait that allows a tooltip to be added to any node.
+pub trait TooltipExt: Into<UiNode> {
+    /// Displays the given tooltip while this node is hovered.
+    fn tooltip<T: Into<NodeTooltip>>(self, tooltip: T) -> UiNode {
+        UiNode::WithTooltip {
+            node: Box::new(self.into()),
+            tooltip: tooltip.into(),
+        }
+    }
+}
+
+impl<T: Into<UiNode>> TooltipExt for T {}
@@ -0,0 +1,149 @@
+//! Builders for defining tree views and their items.
+
+use bevy::prelude::*;
+
+use crate::prelude::{
+    ChildrenBuilder,
+    NodeBackground,
+    NodePosition,
+    NodeText,
+    NodeTree,
+    NodeTreeItem,
+    UiNode,
+};
+
+/// A builder for defining a tree view node.
+#[derive(Debug, Default, Clone)]
+pub struct TreeNodeBuilder {
+    /// The background of the tree view.
+    background: NodeBackground,
+
+    /// The position of the tree view.
+    position: NodePosition,
+
+    /// How the items of the tree view are displayed and selected.
+    tree: NodeTree,
+
+    /// The top-level items of the tree view.
+    children: Vec<UiNode>,
+}
+
+impl TreeNodeBuilder {
+    /// Sets the background of the tree view.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the tree view.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Allows more than one item to be selected at a time, using control and
+    /// shift clicks.
+    pub fn multi_select(mut self) -> Self {
+        self.tree.multi_select = true;
+        self
+    }
+
+    /// Sets the indentation of each level of the tree.
+    pub fn indent(mut self, indent: Val) -> Self {
+        self.tree.indent = indent;
+        self
+    }
+
+    /// Sets the height of the row of each item.
+    pub fn row_height(mut self, height: Val) -> Self {
+        self.tree.row_height = height;
+        self
+    }
+
+    /// Sets the text displayed on the arrows of collapsed and expanded items.
+    pub fn icons<E, C>(mut self, expand: E, collapse: C) -> Self
+    where
+        E: Into<String>,
+        C: Into<String>,
+    {
+        self.tree.expand_icon = expand.into();
+        self.tree.collapse_icon = collapse.into();
+        self
+    }
+
+    /// Sets the background color of the row of each selected item.
+    pub fn selected_color(mut self, color: Color) -> Self {
+        self.tree.selected_color = color;
+        self
+    }
+
+    /// Adds a top-level item to the tree view.
+    pub fn item<T: Into<UiNode>>(mut self, item: T) -> Self {
+        self.children.push(item.into());
+        self
+    }
+}
+
+impl From<TreeNodeBuilder> for UiNode {
+    fn from(builder: TreeNodeBuilder) -> Self {
+        UiNode::Tree {
+            background: builder.background,
+            position: builder.position,
+            tree: builder.tree,
+            children: builder.children.into(),
+        }
+    }
+}
+
+/// A builder for defining an item within a tree view.
+#[derive(Debug, Default, Clone)]
+pub struct TreeItemNodeBuilder {
+    /// The label and state of the item.
+    item: NodeTreeItem,
+
+    /// The children of the item.
+    children: Vec<UiNode>,
+}
+
+impl TreeItemNodeBuilder {
+    /// Creates a new tree item builder with the given label.
+    pub fn new<T: Into<NodeText>>(label: T) -> Self {
+        Self {
+            item: NodeTreeItem {
+                label: label.into(),
+                ..default()
+            },
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the item to be initially expanded.
+    pub fn expanded(mut self) -> Self {
+        self.item.expanded = true;
+        self
+    }
+
+    /// Adds a child item to the item.
+    pub fn child<T: Into<UiNode>>(mut self, child: T) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Sets the children of the item to be created by the given function when
+    /// the item is expanded for the first time.
+    ///
+    /// Any children added directly to the item are ignored.
+    pub fn lazy_children<T: Into<ChildrenBuilder>>(mut self, builder: T) -> Self {
+        self.item.lazy_children = Some(builder.into());
+        self
+    }
+}
+
+impl From<TreeItemNodeBuilder> for UiNode {
+    fn from(builder: TreeItemNodeBuilder) -> Self {
+        UiNode::TreeItem {
+            item: builder.item,
+            children: builder.children.into(),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! A builder for defining a virtual list node.
+
+use crate::prelude::{
+    NodeBackground,
+    NodePosition,
+    NodeScroll,
+    NodeVirtualList,
+    RowBuilder,
+    UiNode,
+};
+
+/// A builder for defining a virtual list node.
+#[derive(Debug, Default, Clone)]
+pub struct VirtualListNodeBuilder {
+    /// The background of the list.
+    background: NodeBackground,
+
+    /// The position of the list.
+    position: NodePosition,
+
+    /// How the contents of the list are scrolled.
+    scroll: NodeScroll,
+
+    /// The items of the list.
+    list: NodeVirtualList,
+}
+
+impl VirtualListNodeBuilder {
+    /// Creates a new virtual list builder with the given item count, row
+    /// height, and function for creating the node of each row.
+    pub fn new<T: Into<RowBuilder>>(item_count: usize, row_height: f32, row_builder: T) -> Self {
+        Self {
+            list: NodeVirtualList {
+                item_count,
+                row_height,
+                row_builder: row_builder.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Sets the background of the list.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the list.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets how the contents of the list are scrolled.
+    ///
+    /// The scroll direction is ignored, as virtual lists may only be scrolled
+    /// vertically.
+    pub fn scroll<T: Into<NodeScroll>>(mut self, scroll: T) -> Self {
+        self.scroll = scroll.into();
+        self
+    }
+
+    /// Sets the number of additional rows to keep spawned above and below the
+    /// visible area.
+    pub fn buffer(mut self, buffer: usize) -> Self {
+        self.list.buffer = buffer;
+        self
+    }
+}
+
+impl From<VirtualListNodeBuilder> for UiNode {
+    fn from(builder: VirtualListNodeBuilder) -> Self {
+        UiNode::VirtualList {
+            background: builder.background,
+            position: builder.position,
+            scroll: builder.scroll,
+            list: builder.list,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! A builder for defining a floating window node.
+
+use bevy::prelude::*;
+
+use crate::prelude::{NodeBackground, NodePosition, NodeText, NodeWindow, UiNode};
+
+/// A builder for defining a floating window node.
+#[derive(Debug, Clone)]
+pub struct WindowNodeBuilder {
+    /// The background of the window.
+    background: NodeBackground,
+
+    /// The position of the window.
+    position: NodePosition,
+
+    /// The title bar and behavior of the window.
+    window: NodeWindow,
+
+    /// The contents of the window.
+    children: Vec<UiNode>,
+}
+
+impl WindowNodeBuilder {
+    /// Creates a new window builder with the given title.
+    pub fn new<T: Into<NodeText>>(title: T) -> Self {
+        Self {
+            background: NodeBackground::Color {
+                color: Color::rgb(0.12, 0.12, 0.12),
+            },
+            position: NodePosition::Absolute {
+                x: Val::Px(0.0),
+                y: Val::Px(0.0),
+                width: Val::Px(320.0),
+                height: Val::Px(240.0),
+            },
+            window: NodeWindow {
+                title: title.into(),
+                ..default()
+            },
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the background of the window.
+    pub fn background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the position of the window.
+    ///
+    /// Windows are always positioned absolutely, so relative positions only
+    /// define the initial size.
+    pub fn position<T: Into<NodePosition>>(mut self, position: T) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Adds a child node to the contents of the window.
+    pub fn child<T: Into<UiNode>>(mut self, child: T) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Sets whether the title bar displays a button that closes the window.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.window.closable = closable;
+        self
+    }
+
+    /// Sets whether the title bar displays a button that minimizes the
+    /// window.
+    pub fn minimizable(mut self, minimizable: bool) -> Self {
+        self.window.minimizable = minimizable;
+        self
+    }
+
+    /// Sets whether the window may be resized by dragging its edges and
+    /// corners.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.window.resizable = resizable;
+        self
+    }
+
+    /// Sets the window to be initially minimized.
+    pub fn minimized(mut self) -> Self {
+        self.window.minimized = true;
+        self
+    }
+
+    /// Sets the smallest and largest sizes the window may be resized to, in
+    /// logical pixels.
+    pub fn size_limits(mut self, min: Vec2, max: Vec2) -> Self {
+        self.window.min_size = min;
+        self.window.max_size = max;
+        self
+    }
+
+    /// Sets the height of the title bar, in logical pixels.
+    pub fn title_bar_height(mut self, height: f32) -> Self {
+        self.window.title_bar_height = height;
+        self
+    }
+
+    /// Sets the background of the title bar.
+    pub fn title_bar_background<T: Into<NodeBackground>>(mut self, background: T) -> Self {
+        self.window.title_bar_background = background.into();
+        self
+    }
+
+    /// Stores the position and size of the window under the given key when it
+    /// is moved or resized, and restores them when a window with the same key
+    /// is created.
+    pub fn persist<T: Into<String>>(mut self, key: T) -> Self {
+        self.window.persist_key = Some(key.into());
+        self
+    }
+}
+
+impl From<WindowNodeBuilder> for UiNode {
+    fn from(builder: WindowNodeBuilder) -> Self {
+        UiNode::Window {
+            background: builder.background,
+            position: builder.position,
+            window: builder.window,
+            children: builder.children.into(),
+        }
+    }
+}
@@ -13,11 +13,12 @@ use bevy::prelude::*;
 pub mod blocks;
 pub mod builders;
 pub mod nodes;
+pub mod widgets;
 
 #[doc(hidden)]
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{blocks::*, builders::*, nodes::*, StreamlineUIPlugin};
+    pub use crate::{blocks::*, builders::*, nodes::*, widgets::*, StreamlineUIPlugin};
 }
 
 /// This plugin provides a full-featured UI system for Bevy to make creating
@@ -27,5 +28,35 @@ pub mod prelude {
 /// buttons, GUIs, popups, etc, to be created and managed with minimal effort.
 pub struct StreamlineUIPlugin;
 impl Plugin for StreamlineUIPlugin {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            widgets::CollapsiblePlugin,
+            widgets::ColorPickerPlugin,
+            widgets::DialoguePlugin,
+            widgets::DragPlugin,
+            widgets::FocusPlugin,
+            widgets::OverlayPlugin,
+            widgets::MenuPlugin,
+            widgets::ModalPlugin,
+            widgets::ProgressPlugin,
+            widgets::RadioPlugin,
+            widgets::ScrollViewPlugin,
+            widgets::SpinnerPlugin,
+        ))
+        .add_plugins((
+            widgets::KeyBindingsPlugin,
+            widgets::LinkPlugin,
+            widgets::LogViewPlugin,
+            widgets::SlotGridPlugin,
+            widgets::SplitPlugin,
+            widgets::TablePlugin,
+            widgets::TabsPlugin,
+            widgets::ToastPlugin,
+            widgets::TooltipPlugin,
+            widgets::TreePlugin,
+            widgets::VirtualKeyboardPlugin,
+            widgets::VirtualListPlugin,
+            widgets::FloatingWindowPlugin,
+        ));
+    }
 }
@@ -8,15 +8,72 @@
 //! built.
 
 use bevy::prelude::*;
+use bevy::text::BreakLineOn;
 
 use crate::prelude::{
     DataBlock,
     NodeBackground,
     NodeBundleBuilder,
+    NodeBundleType,
     NodeChildren,
+    NodeCollapsible,
+    NodeColorPicker,
+    NodeDialogue,
+    NodeDraggable,
+    NodeDropTarget,
+    NodeKeyBindings,
+    NodeLogView,
+    NodeMenu,
+    NodeMenuBar,
+    NodeModal,
     NodePosition,
+    NodeProgress,
+    NodeRadioGroup,
+    NodeScroll,
+    NodeSlotGrid,
+    NodeSpinner,
+    NodeSplit,
+    NodeTable,
+    NodeTabs,
     NodeText,
+    NodeTextSection,
+    NodeTooltip,
+    NodeTree,
+    NodeTreeItem,
+    NodeVirtualList,
+    NodeWindow,
+    ScrollDirection,
 };
+use crate::widgets::{
+    build_collapsible,
+    build_color_picker,
+    build_dialogue,
+    build_key_bindings,
+    build_log_view,
+    build_menu_bar,
+    build_progress_bar,
+    build_radio_group,
+    build_scrollbars,
+    build_slot_grid,
+    build_spinner,
+    build_split,
+    build_table,
+    build_tabs,
+    build_tree_item,
+    build_window,
+    Accordion,
+    ContextMenuTarget,
+    Draggable,
+    DropTarget,
+    Menu,
+    ModalButton,
+    ModalResult,
+    Tooltip,
+};
+
+/// The height of inline images within text, relative to the text size, which
+/// approximates the line height of most fonts.
+const INLINE_LINE_HEIGHT: f32 = 1.2;
 
 /// A trait for UI node builders that can be built into entities.
 #[derive(Debug, Clone)]
@@ -51,22 +108,368 @@ pub enum UiNode {
         /// The text data for the text.
         text: NodeText,
     },
+
+    /// A scroll view is a container node that clips its children and allows
+    /// them to be scrolled.
+    ScrollView {
+        /// The background of the scroll view.
+        background: NodeBackground,
+
+        /// The position of the scroll view.
+        position: NodePosition,
+
+        /// How the contents of the scroll view are scrolled.
+        scroll: NodeScroll,
+
+        /// The children of the scroll view.
+        children: NodeChildren,
+    },
+
+    /// A virtual list is a vertical scroll view that only spawns the rows that
+    /// are currently visible, allowing it to display a very large number of
+    /// items.
+    VirtualList {
+        /// The background of the list.
+        background: NodeBackground,
+
+        /// The position of the list.
+        position: NodePosition,
+
+        /// How the contents of the list are scrolled.
+        ///
+        /// Virtual lists may only be scrolled vertically.
+        scroll: NodeScroll,
+
+        /// The items of the list.
+        list: NodeVirtualList,
+    },
+
+    /// A table displays rows of cells beneath a header of columns, which may
+    /// be sorted, resized, and selected.
+    Table {
+        /// The background of the table.
+        background: NodeBackground,
+
+        /// The position of the table.
+        position: NodePosition,
+
+        /// The columns and rows of the table.
+        table: NodeTable,
+    },
+
+    /// A tab container displays a strip of tab buttons along with the contents
+    /// of the active tab.
+    Tabs {
+        /// The background of the tab container.
+        background: NodeBackground,
+
+        /// The position of the tab container.
+        position: NodePosition,
+
+        /// The tabs within the container.
+        tabs: NodeTabs,
+    },
+
+    /// A modal is a dialog that is displayed above all other UI, with a
+    /// full-screen scrim that blocks input to the UI below it.
+    ///
+    /// Modals are always placed within the overlay root, regardless of where
+    /// they are built.
+    Modal {
+        /// The background of the dialog.
+        background: NodeBackground,
+
+        /// The position of the dialog within the screen.
+        position: NodePosition,
+
+        /// How the modal blocks the UI below it.
+        modal: NodeModal,
+
+        /// The children of the dialog.
+        children: NodeChildren,
+    },
+
+    /// A modal button is a button that closes the modal it is within when
+    /// clicked.
+    ModalButton {
+        /// The background of the button.
+        background: NodeBackground,
+
+        /// The position of the button.
+        position: NodePosition,
+
+        /// The label of the button.
+        text: NodeText,
+
+        /// The result to close the modal with.
+        result: ModalResult,
+    },
+
+    /// A collapsible section has a header that may be clicked to expand or
+    /// collapse the body below it.
+    Collapsible {
+        /// The background of the section.
+        background: NodeBackground,
+
+        /// The position of the section.
+        position: NodePosition,
+
+        /// The header and behavior of the section.
+        section: NodeCollapsible,
+
+        /// The contents of the body of the section.
+        children: NodeChildren,
+    },
+
+    /// An accordion is a column of collapsible sections, of which only one
+    /// may be expanded at a time.
+    Accordion {
+        /// The background of the accordion.
+        background: NodeBackground,
+
+        /// The position of the accordion.
+        position: NodePosition,
+
+        /// The sections of the accordion.
+        children: NodeChildren,
+    },
+
+    /// A color picker edits a color using a saturation/value square, a hue
+    /// bar, an optional alpha bar, a hex entry field, and preset swatches.
+    ColorPicker {
+        /// The background of the color picker.
+        background: NodeBackground,
+
+        /// The position of the color picker.
+        position: NodePosition,
+
+        /// The initial color and layout of the color picker.
+        picker: NodeColorPicker,
+    },
+
+    /// A dialogue box reveals lines of text character by character, with an
+    /// optional speaker name plate and portrait.
+    Dialogue {
+        /// The background of the dialogue box.
+        background: NodeBackground,
+
+        /// The position of the dialogue box.
+        position: NodePosition,
+
+        /// The initial line and style of the dialogue box.
+        dialogue: NodeDialogue,
+    },
+
+    /// A log view is a scroll view of appended lines of text, such as a chat
+    /// window or debug log.
+    LogView {
+        /// The background of the log view.
+        background: NodeBackground,
+
+        /// The position of the log view.
+        position: NodePosition,
+
+        /// The behavior and style of the log view.
+        log: NodeLogView,
+    },
+
+    /// A key binding list is a list of actions, each with buttons that display
+    /// and rebind the inputs bound to the action.
+    KeyBindings {
+        /// The background of the key binding list.
+        background: NodeBackground,
+
+        /// The position of the key binding list.
+        position: NodePosition,
+
+        /// The actions and style of the key binding list.
+        bindings: NodeKeyBindings,
+    },
+
+    /// A slot grid is a grid of inventory slots, each displaying an item icon
+    /// and stack count, of which one may be selected.
+    SlotGrid {
+        /// The background of the slot grid.
+        background: NodeBackground,
+
+        /// The position of the slot grid.
+        position: NodePosition,
+
+        /// The layout and style of the slots.
+        grid: NodeSlotGrid,
+    },
+
+    /// A menu bar is a row of buttons that each open a dropdown menu.
+    MenuBar {
+        /// The background of the menu bar.
+        background: NodeBackground,
+
+        /// The position of the menu bar.
+        position: NodePosition,
+
+        /// The menus of the menu bar.
+        bar: NodeMenuBar,
+    },
+
+    /// A progress bar displays a value between `0.0` and `1.0` as a fill that
+    /// grows across the bar, or around a ring for radial progress bars.
+    ProgressBar {
+        /// The background of the progress bar, displayed behind the fill.
+        background: NodeBackground,
+
+        /// The position of the progress bar.
+        position: NodePosition,
+
+        /// How the progress bar displays its value.
+        progress: NodeProgress,
+    },
+
+    /// A radio group contains a set of mutually exclusive options, of which at
+    /// most one may be selected.
+    RadioGroup {
+        /// The background of the radio group.
+        background: NodeBackground,
+
+        /// The position of the radio group.
+        position: NodePosition,
+
+        /// The options within the radio group.
+        radio: NodeRadioGroup,
+    },
+
+    /// A spinner is a numeric entry node, with buttons that step the value up
+    /// and down and a label that may be dragged or typed into.
+    Spinner {
+        /// The background of the spinner.
+        background: NodeBackground,
+
+        /// The position of the spinner.
+        position: NodePosition,
+
+        /// The value and settings of the spinner.
+        spinner: NodeSpinner,
+    },
+
+    /// A split pane divides its area between two panes, with a divider
+    /// between them that may be dragged to resize the panes.
+    Split {
+        /// The background of the split pane.
+        background: NodeBackground,
+
+        /// The position of the split pane.
+        position: NodePosition,
+
+        /// How the area is divided between the panes.
+        split: NodeSplit,
+
+        /// The contents of the first pane, on the left or top.
+        first: Box<UiNode>,
+
+        /// The contents of the second pane, on the right or bottom.
+        second: Box<UiNode>,
+    },
+
+    /// A tree view displays a hierarchy of items that may be expanded,
+    /// collapsed, and selected.
+    Tree {
+        /// The background of the tree view.
+        background: NodeBackground,
+
+        /// The position of the tree view.
+        position: NodePosition,
+
+        /// How the items of the tree view are displayed and selected.
+        tree: NodeTree,
+
+        /// The top-level items of the tree view.
+        children: NodeChildren,
+    },
+
+    /// A tree item is a single item within a tree view, with a row that
+    /// displays its label and a container for its child items.
+    ///
+    /// Tree items must be placed within a tree view.
+    TreeItem {
+        /// The label and state of the item.
+        item: NodeTreeItem,
+
+        /// The child items of the item.
+        children: NodeChildren,
+    },
+
+    /// A floating window has a title bar that may be dragged to move the
+    /// window, along with buttons to minimize and close it.
+    ///
+    /// Windows are always positioned absolutely within their parent.
+    Window {
+        /// The background of the window.
+        background: NodeBackground,
+
+        /// The position of the window.
+        position: NodePosition,
+
+        /// The title bar and behavior of the window.
+        window: NodeWindow,
+
+        /// The contents of the window.
+        children: NodeChildren,
+    },
+
+    /// Wraps another node to open a context menu when it is right-clicked.
+    WithContextMenu {
+        /// The node that opens the context menu.
+        node: Box<UiNode>,
+
+        /// The context menu to open.
+        menu: NodeMenu,
+    },
+
+    /// Wraps another node to allow it to be dragged onto drop targets.
+    WithDraggable {
+        /// The node that may be dragged.
+        node: Box<UiNode>,
+
+        /// The payload carried by the node while dragged.
+        draggable: NodeDraggable,
+    },
+
+    /// Wraps another node to accept dragged nodes with certain payload types.
+    WithDropTarget {
+        /// The node that dragged nodes may be dropped onto.
+        node: Box<UiNode>,
+
+        /// The payload types accepted by the node.
+        target: NodeDropTarget,
+    },
+
+    /// Wraps another node to display a tooltip while it is hovered.
+    WithTooltip {
+        /// The node that displays the tooltip.
+        node: Box<UiNode>,
+
+        /// The tooltip to display.
+        tooltip: NodeTooltip,
+    },
 }
 
 impl UiNode {
     /// Consumes this [`UiNode`] and creates a new UI entity hierarchy.
-    pub fn build(self, cmd: &mut Commands, asset_server: &AssetServer) {
-        self.build_node(cmd, asset_server, None);
+    ///
+    /// Returns the root entity of the hierarchy.
+    pub fn build(self, cmd: &mut Commands, asset_server: &AssetServer) -> Entity {
+        self.build_node(cmd, asset_server, None)
     }
 
     /// Consumes this [`UiNode`] and creates a new UI entity hierarchy with an
     /// optional parent.
+    ///
+    /// Returns the root entity of the hierarchy.
     pub(crate) fn build_node(
         self,
         cmd: &mut Commands,
         asset_server: &AssetServer,
         parent: Option<Entity>,
-    ) {
+    ) -> Entity {
         match self {
             UiNode::Canvas { children } => {
                 let mut node = NodeBundleBuilder::default();
@@ -79,7 +482,7 @@ impl UiNode {
                 style.height = Val::Percent(100.0);
 
                 children.apply_to_node(&mut node, asset_server);
-                node.build(cmd, asset_server);
+                node.build(cmd, asset_server)
             }
 
             UiNode::Panel {
@@ -93,7 +496,7 @@ impl UiNode {
                 background.apply_to_node(&mut node, asset_server);
                 position.apply_to_node(&mut node, asset_server);
                 children.apply_to_node(&mut node, asset_server);
-                node.build(cmd, asset_server);
+                node.build(cmd, asset_server)
             }
 
             UiNode::Text {
@@ -109,11 +512,461 @@ impl UiNode {
                 text.apply_to_parent(&mut container_node, asset_server);
                 let container_id = container_node.build(cmd, asset_server);
 
+                if text.has_images() {
+                    build_inline_text(cmd, asset_server, container_id, text);
+                    return container_id;
+                }
+
                 let mut text_node = NodeBundleBuilder::default();
                 text_node.set_parent(Some(container_id));
 
                 text.apply_to_node(&mut text_node, asset_server);
                 text_node.build(cmd, asset_server);
+
+                container_id
+            }
+
+            UiNode::ScrollView {
+                background,
+                position,
+                scroll,
+                children,
+            } => {
+                let mut view_node = NodeBundleBuilder::default();
+                view_node.set_parent(parent);
+
+                background.apply_to_node(&mut view_node, asset_server);
+                position.apply_to_node(&mut view_node, asset_server);
+                build_scroll_view(cmd, asset_server, view_node, scroll, children)
+            }
+
+            UiNode::VirtualList {
+                background,
+                position,
+                mut scroll,
+                list,
+            } => {
+                let mut view_node = NodeBundleBuilder::default();
+                view_node.set_parent(parent);
+
+                background.apply_to_node(&mut view_node, asset_server);
+                position.apply_to_node(&mut view_node, asset_server);
+                list.apply_to_node(&mut view_node, asset_server);
+
+                scroll.direction = ScrollDirection::Vertical;
+                build_scroll_view(cmd, asset_server, view_node, scroll, default())
+            }
+
+            UiNode::Table {
+                background,
+                position,
+                table,
+            } => {
+                let mut container_node = NodeBundleBuilder::default();
+                container_node.set_parent(parent);
+
+                background.apply_to_node(&mut container_node, asset_server);
+                position.apply_to_node(&mut container_node, asset_server);
+                build_table(cmd, asset_server, container_node, table)
+            }
+
+            UiNode::Tabs {
+                background,
+                position,
+                tabs,
+            } => {
+                let mut container_node = NodeBundleBuilder::default();
+                container_node.set_parent(parent);
+
+                background.apply_to_node(&mut container_node, asset_server);
+                position.apply_to_node(&mut container_node, asset_server);
+                build_tabs(cmd, asset_server, container_node, tabs)
+            }
+
+            UiNode::Modal {
+                background,
+                position,
+                modal,
+                children,
+            } => {
+                let mut scrim_node = NodeBundleBuilder::default();
+                modal.apply_to_parent(&mut scrim_node, asset_server);
+                let scrim_id = scrim_node.build(cmd, asset_server);
+
+                let mut dialog_node = NodeBundleBuilder::default();
+                dialog_node.set_parent(Some(scrim_id));
+
+                background.apply_to_node(&mut dialog_node, asset_server);
+                position.apply_to_node(&mut dialog_node, asset_server);
+                modal.apply_to_node(&mut dialog_node, asset_server);
+                children.apply_to_node(&mut dialog_node, asset_server);
+                dialog_node.build(cmd, asset_server);
+
+                scrim_id
+            }
+
+            UiNode::ModalButton {
+                background,
+                position,
+                text,
+                result,
+            } => {
+                let mut button_node = NodeBundleBuilder::default();
+                button_node.set_parent(parent);
+
+                background.apply_to_node(&mut button_node, asset_server);
+                position.apply_to_node(&mut button_node, asset_server);
+                text.apply_to_parent(&mut button_node, asset_server);
+                button_node.bundle_type(NodeBundleType::Button);
+                button_node.insert(ModalButton { result });
+                let button_id = button_node.build(cmd, asset_server);
+
+                let mut text_node = NodeBundleBuilder::default();
+                text_node.set_parent(Some(button_id));
+
+                text.apply_to_node(&mut text_node, asset_server);
+                text_node.build(cmd, asset_server);
+
+                button_id
+            }
+
+            UiNode::Collapsible {
+                background,
+                position,
+                section,
+                children,
+            } => {
+                let mut section_node = NodeBundleBuilder::default();
+                section_node.set_parent(parent);
+
+                background.apply_to_node(&mut section_node, asset_server);
+                position.apply_to_node(&mut section_node, asset_server);
+                build_collapsible(cmd, asset_server, section_node, section, children)
+            }
+
+            UiNode::Accordion {
+                background,
+                position,
+                children,
+            } => {
+                let mut node = NodeBundleBuilder::default();
+                node.set_parent(parent);
+                node.insert(Accordion);
+                node.get_style_mut().flex_direction = FlexDirection::Column;
+
+                background.apply_to_node(&mut node, asset_server);
+                position.apply_to_node(&mut node, asset_server);
+                children.apply_to_node(&mut node, asset_server);
+                node.build(cmd, asset_server)
+            }
+
+            UiNode::ColorPicker {
+                background,
+                position,
+                picker,
+            } => {
+                let mut picker_node = NodeBundleBuilder::default();
+                picker_node.set_parent(parent);
+
+                background.apply_to_node(&mut picker_node, asset_server);
+                position.apply_to_node(&mut picker_node, asset_server);
+                build_color_picker(cmd, asset_server, picker_node, picker)
+            }
+
+            UiNode::Dialogue {
+                background,
+                position,
+                dialogue,
+            } => {
+                let mut dialogue_node = NodeBundleBuilder::default();
+                dialogue_node.set_parent(parent);
+
+                background.apply_to_node(&mut dialogue_node, asset_server);
+                position.apply_to_node(&mut dialogue_node, asset_server);
+                build_dialogue(cmd, asset_server, dialogue_node, dialogue)
+            }
+
+            UiNode::LogView {
+                background,
+                position,
+                log,
+            } => {
+                let mut view_node = NodeBundleBuilder::default();
+                view_node.set_parent(parent);
+
+                background.apply_to_node(&mut view_node, asset_server);
+                position.apply_to_node(&mut view_node, asset_server);
+                build_log_view(cmd, asset_server, view_node, log)
+            }
+
+            UiNode::KeyBindings {
+                background,
+                position,
+                bindings,
+            } => {
+                let mut list_node = NodeBundleBuilder::default();
+                list_node.set_parent(parent);
+
+                background.apply_to_node(&mut list_node, asset_server);
+                position.apply_to_node(&mut list_node, asset_server);
+                build_key_bindings(cmd, asset_server, list_node, bindings)
+            }
+
+            UiNode::SlotGrid {
+                background,
+                position,
+                grid,
+            } => {
+                let mut grid_node = NodeBundleBuilder::default();
+                grid_node.set_parent(parent);
+
+                background.apply_to_node(&mut grid_node, asset_server);
+                position.apply_to_node(&mut grid_node, asset_server);
+                build_slot_grid(cmd, asset_server, grid_node, grid)
+            }
+
+            UiNode::MenuBar {
+                background,
+                position,
+                bar,
+            } => {
+                let mut bar_node = NodeBundleBuilder::default();
+                bar_node.set_parent(parent);
+
+                background.apply_to_node(&mut bar_node, asset_server);
+                position.apply_to_node(&mut bar_node, asset_server);
+                build_menu_bar(cmd, asset_server, bar_node, bar)
+            }
+
+            UiNode::ProgressBar {
+                background,
+                position,
+                progress,
+            } => {
+                let mut container_node = NodeBundleBuilder::default();
+                container_node.set_parent(parent);
+
+                background.apply_to_node(&mut container_node, asset_server);
+                position.apply_to_node(&mut container_node, asset_server);
+                build_progress_bar(cmd, asset_server, container_node, progress)
+            }
+
+            UiNode::RadioGroup {
+                background,
+                position,
+                radio,
+            } => {
+                let mut group_node = NodeBundleBuilder::default();
+                group_node.set_parent(parent);
+
+                background.apply_to_node(&mut group_node, asset_server);
+                position.apply_to_node(&mut group_node, asset_server);
+                build_radio_group(cmd, asset_server, group_node, radio)
+            }
+
+            UiNode::Spinner {
+                background,
+                position,
+                spinner,
+            } => {
+                let mut container_node = NodeBundleBuilder::default();
+                container_node.set_parent(parent);
+
+                background.apply_to_node(&mut container_node, asset_server);
+                position.apply_to_node(&mut container_node, asset_server);
+                build_spinner(cmd, asset_server, container_node, spinner)
+            }
+
+            UiNode::Split {
+                background,
+                position,
+                split,
+                first,
+                second,
+            } => {
+                let mut split_node = NodeBundleBuilder::default();
+                split_node.set_parent(parent);
+
+                background.apply_to_node(&mut split_node, asset_server);
+                position.apply_to_node(&mut split_node, asset_server);
+                build_split(cmd, asset_server, split_node, split, *first, *second)
+            }
+
+            UiNode::Tree {
+                background,
+                position,
+                tree,
+                children,
+            } => {
+                let mut node = NodeBundleBuilder::default();
+                node.set_parent(parent);
+
+                background.apply_to_node(&mut node, asset_server);
+                position.apply_to_node(&mut node, asset_server);
+                tree.apply_to_node(&mut node, asset_server);
+                children.apply_to_node(&mut node, asset_server);
+                node.build(cmd, asset_server)
+            }
+
+            UiNode::TreeItem { item, children } => {
+                let mut item_node = NodeBundleBuilder::default();
+                item_node.set_parent(parent);
+                build_tree_item(cmd, asset_server, item_node, item, children)
+            }
+
+            UiNode::Window {
+                background,
+                position,
+                window,
+                children,
+            } => {
+                let mut window_node = NodeBundleBuilder::default();
+                window_node.set_parent(parent);
+
+                background.apply_to_node(&mut window_node, asset_server);
+                position.apply_to_node(&mut window_node, asset_server);
+                build_window(cmd, asset_server, window_node, window, children)
+            }
+
+            UiNode::WithContextMenu { node, menu } => {
+                let node_id = node.build_node(cmd, asset_server, parent);
+                cmd.entity(node_id).insert((
+                    Menu::from(menu),
+                    ContextMenuTarget,
+                    Interaction::default(),
+                ));
+
+                node_id
+            }
+
+            UiNode::WithDraggable { node, draggable } => {
+                let node_id = node.build_node(cmd, asset_server, parent);
+                cmd.entity(node_id)
+                    .insert((Draggable::from(draggable), Interaction::default()));
+
+                node_id
+            }
+
+            UiNode::WithDropTarget { node, target } => {
+                let node_id = node.build_node(cmd, asset_server, parent);
+                cmd.entity(node_id).insert(DropTarget::from(target));
+
+                node_id
+            }
+
+            UiNode::WithTooltip { node, tooltip } => {
+                let node_id = node.build_node(cmd, asset_server, parent);
+                cmd.entity(node_id)
+                    .insert((Tooltip::from(tooltip), Interaction::default()));
+
+                node_id
+            }
+        }
+    }
+}
+
+/// Consumes the given viewport node builder and creates a scroll view, along
+/// with its contents and scrollbars.
+///
+/// Returns the entity of the viewport.
+pub(crate) fn build_scroll_view(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut view_node: NodeBundleBuilder,
+    scroll: NodeScroll,
+    children: NodeChildren,
+) -> Entity {
+    scroll.apply_to_parent(&mut view_node, asset_server);
+    let view_id = view_node.build(cmd, asset_server);
+
+    let direction = scroll.direction;
+    let scrollbar = scroll.scrollbar.clone();
+
+    let mut content_node = NodeBundleBuilder::default();
+    content_node.set_parent(Some(view_id));
+
+    scroll.apply_to_node(&mut content_node, asset_server);
+    children.apply_to_node(&mut content_node, asset_server);
+    content_node.build(cmd, asset_server);
+
+    if let Some(scrollbar) = scrollbar {
+        build_scrollbars(cmd, asset_server, view_id, direction, scrollbar);
+    }
+
+    view_id
+}
+
+/// Creates text that contains inline images as a wrapped row of word and image
+/// nodes within the given container, so that the images flow with the text.
+fn build_inline_text(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    container: Entity,
+    text: NodeText,
+) {
+    let mut row = NodeBundleBuilder::default();
+    row.set_parent(Some(container));
+
+    let style = row.get_style_mut();
+    style.flex_direction = FlexDirection::Row;
+    style.flex_wrap = FlexWrap::Wrap;
+    style.align_items = AlignItems::Center;
+    style.justify_content = match text.anchor_point.direction().x {
+        d if d < 0 => JustifyContent::FlexStart,
+        0 => JustifyContent::Center,
+        _ => JustifyContent::FlexEnd,
+    };
+    let row_id = row.build(cmd, asset_server);
+
+    for section in text.sections {
+        if let Some(image) = section.image {
+            let mut image_node = NodeBundleBuilder::default();
+            image_node.set_parent(Some(row_id));
+            image_node.bundle_type(NodeBundleType::Image);
+            image_node.insert(UiImage::new(asset_server.load(image)));
+            image_node.get_style_mut().height = Val::Px(section.text_size * INLINE_LINE_HEIGHT);
+            image_node.build(cmd, asset_server);
+            continue;
+        }
+
+        for (index, line) in section.text.split('\n').enumerate() {
+            if index > 0 {
+                // A full-width node with no height forces the next word onto a
+                // new line. Empty lines have no words to take up space, so the
+                // node is given the height of a line instead.
+                let mut line_break = NodeBundleBuilder::default();
+                line_break.set_parent(Some(row_id));
+
+                let style = line_break.get_style_mut();
+                style.width = Val::Percent(100.0);
+                if line.is_empty() {
+                    style.height = Val::Px(section.text_size * INLINE_LINE_HEIGHT);
+                }
+                line_break.build(cmd, asset_server);
+            }
+
+            // Each line of a link is kept in a single node, so that the whole
+            // link is hovered and underlined together.
+            let words = match section.link {
+                Some(_) if !line.is_empty() => vec![line],
+                Some(_) => Vec::new(),
+                None => line.split_inclusive(' ').collect(),
+            };
+
+            for word in words {
+                let mut word_node = NodeBundleBuilder::default();
+                word_node.set_parent(Some(row_id));
+
+                NodeText {
+                    anchor_point: text.anchor_point,
+                    sections: vec![NodeTextSection {
+                        text: word.to_string(),
+                        ..section.clone()
+                    }],
+                    line_break: BreakLineOn::NoWrap,
+                }
+                .apply_to_node(&mut word_node, asset_server);
+                word_node.build(cmd, asset_server);
             }
         }
     }
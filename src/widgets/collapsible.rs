@@ -0,0 +1,308 @@
+//! Contains the components, events, and systems that expand and collapse
+//! collapsible sections and accordions.
+
+use bevy::prelude::*;
+
+use crate::prelude::{DataBlock, NodeBundleBuilder, NodeChildren, NodeCollapsible};
+
+/// A component that stores the state of a collapsible section.
+///
+/// Game code may write to [`Collapsible::expanded`] to expand or collapse the
+/// section.
+#[derive(Debug, Clone, Component)]
+pub struct Collapsible {
+    /// Whether the body of the section is expanded.
+    pub expanded: bool,
+
+    /// The time, in seconds, the body takes to expand or collapse.
+    pub animation_time: f32,
+
+    /// The expanded state that is currently displayed, if it has been
+    /// displayed.
+    pub(crate) shown: Option<bool>,
+
+    /// How far the body has expanded, from `0.0` when collapsed to `1.0` when
+    /// fully expanded.
+    progress: f32,
+
+    /// The text displayed on the icon while collapsed and expanded.
+    icons: [String; 2],
+
+    /// The text node of the icon.
+    icon: Entity,
+
+    /// The node that clips the body while it animates.
+    body: Entity,
+
+    /// The node that contains the children of the section.
+    content: Entity,
+}
+
+/// A marker component for a node whose collapsible section children are
+/// grouped as an accordion, where only one section may be expanded at a time.
+#[derive(Debug, Default, Clone, Copy, Component, Reflect)]
+#[reflect(Component)]
+pub struct Accordion;
+
+/// An event that is sent when a collapsible section is expanded or collapsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct CollapsibleToggled {
+    /// The collapsible section entity.
+    pub section: Entity,
+
+    /// Whether the section is now expanded.
+    pub expanded: bool,
+}
+
+/// A component for the header of a collapsible section.
+#[derive(Debug, Clone, Copy, Component)]
+struct CollapsibleHeader {
+    /// The section this header belongs to.
+    section: Entity,
+}
+
+/// This plugin handles expanding and collapsing collapsible sections.
+pub(crate) struct CollapsiblePlugin;
+impl Plugin for CollapsiblePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Accordion>()
+            .add_event::<CollapsibleToggled>()
+            .add_systems(
+                Update,
+                (
+                    toggle_collapsible_on_click,
+                    collapse_accordion_siblings,
+                    update_collapsibles,
+                    animate_collapsibles,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Consumes the given section node builder and creates a collapsible section,
+/// along with its header and body.
+///
+/// Returns the entity of the section.
+pub(crate) fn build_collapsible(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut section_node: NodeBundleBuilder,
+    section: NodeCollapsible,
+    children: NodeChildren,
+) -> Entity {
+    section_node.get_style_mut().flex_direction = FlexDirection::Column;
+    let section_id = section_node.build(cmd, asset_server);
+
+    let mut header = NodeBundleBuilder::default();
+    header.set_parent(Some(section_id));
+    section
+        .header_background
+        .apply_to_node(&mut header, asset_server);
+    header.insert((
+        CollapsibleHeader {
+            section: section_id,
+        },
+        Interaction::default(),
+    ));
+
+    let style = header.get_style_mut();
+    style.flex_shrink = 0.0;
+    style.height = section.header_height;
+    style.align_items = AlignItems::Center;
+    let header_id = header.build(cmd, asset_server);
+
+    let mut icon_text = section.header.clone();
+    icon_text.sections.truncate(1);
+    if let Some(text) = icon_text.sections.first_mut() {
+        text.text.clear();
+    }
+
+    let mut icon = NodeBundleBuilder::default();
+    icon.set_parent(Some(header_id));
+
+    let style = icon.get_style_mut();
+    style.flex_shrink = 0.0;
+    style.width = Val::Px(20.0);
+    style.justify_content = JustifyContent::Center;
+    let icon_id = icon.build(cmd, asset_server);
+
+    let mut icon = NodeBundleBuilder::default();
+    icon.set_parent(Some(icon_id));
+    icon_text.apply_to_node(&mut icon, asset_server);
+    let icon_text_id = icon.build(cmd, asset_server);
+
+    let mut label = NodeBundleBuilder::default();
+    label.set_parent(Some(header_id));
+    section.header.apply_to_node(&mut label, asset_server);
+    label.build(cmd, asset_server);
+
+    let mut body = NodeBundleBuilder::default();
+    body.set_parent(Some(section_id));
+
+    let style = body.get_style_mut();
+    style.flex_direction = FlexDirection::Column;
+    style.overflow = Overflow::clip();
+    style.display = Display::None;
+    let body_id = body.build(cmd, asset_server);
+
+    let mut content = NodeBundleBuilder::default();
+    content.set_parent(Some(body_id));
+
+    let style = content.get_style_mut();
+    style.flex_direction = FlexDirection::Column;
+    style.flex_shrink = 0.0;
+    children.apply_to_node(&mut content, asset_server);
+    let content_id = content.build(cmd, asset_server);
+
+    cmd.entity(section_id).insert(Collapsible {
+        expanded: section.expanded,
+        animation_time: section.animation_time,
+        shown: None,
+        progress: if section.expanded { 1.0 } else { 0.0 },
+        icons: [section.expand_icon, section.collapse_icon],
+        icon: icon_text_id,
+        body: body_id,
+        content: content_id,
+    });
+
+    section_id
+}
+
+/// Toggles a collapsible section when its header is clicked.
+fn toggle_collapsible_on_click(
+    headers: Query<(&Interaction, &CollapsibleHeader), Changed<Interaction>>,
+    mut sections: Query<&mut Collapsible>,
+) {
+    for (interaction, header) in &headers {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let Ok(mut section) = sections.get_mut(header.section) {
+            section.expanded = !section.expanded;
+        }
+    }
+}
+
+/// Collapses the other sections of an accordion when one of its sections is
+/// expanded.
+fn collapse_accordion_siblings(
+    accordions: Query<&Children, With<Accordion>>,
+    parents: Query<&Parent>,
+    mut sections: Query<(Entity, &mut Collapsible)>,
+) {
+    let opened = sections
+        .iter_mut()
+        .filter(|(_, section)| {
+            section.is_changed() && section.expanded && section.shown != Some(true)
+        })
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
+
+    for section_id in opened {
+        let Some(siblings) = parents
+            .get(section_id)
+            .ok()
+            .and_then(|parent| accordions.get(parent.get()).ok())
+        else {
+            continue;
+        };
+
+        for sibling in siblings.iter().filter(|sibling| **sibling != section_id) {
+            if let Ok((_, mut sibling)) = sections.get_mut(*sibling) {
+                if sibling.expanded {
+                    sibling.expanded = false;
+                }
+            }
+        }
+    }
+}
+
+/// Updates the icon of each section that has been expanded or collapsed, and
+/// sends an event when its state changes.
+fn update_collapsibles(
+    mut toggled: EventWriter<CollapsibleToggled>,
+    mut sections: Query<(Entity, &mut Collapsible), Changed<Collapsible>>,
+    mut texts: Query<&mut Text>,
+) {
+    for (section_id, mut section) in &mut sections {
+        if section.shown == Some(section.expanded) {
+            continue;
+        }
+
+        if section.shown.is_some() {
+            toggled.send(CollapsibleToggled {
+                section: section_id,
+                expanded: section.expanded,
+            });
+        }
+
+        section.shown = Some(section.expanded);
+
+        if let Ok(mut text) = texts.get_mut(section.icon) {
+            if let Some(text) = text.sections.first_mut() {
+                text.value
+                    .clone_from(&section.icons[section.expanded as usize]);
+            }
+        }
+    }
+}
+
+/// Expands and collapses the body of each section, animating its height
+/// between zero and the height of its contents.
+fn animate_collapsibles(
+    time: Res<Time>,
+    mut sections: Query<&mut Collapsible>,
+    contents: Query<&Node>,
+    mut bodies: Query<&mut Style>,
+) {
+    for mut section in &mut sections {
+        let target = if section.expanded { 1.0 } else { 0.0 };
+        let Ok(mut style) = bodies.get_mut(section.body) else {
+            continue;
+        };
+
+        let settled = match target > 0.5 {
+            true => style.display == Display::Flex && style.height == Val::Auto,
+            false => style.display == Display::None,
+        };
+
+        if section.progress == target && settled {
+            continue;
+        }
+
+        let progress = match section.animation_time > 0.0 {
+            true => {
+                let step = time.delta_seconds() / section.animation_time;
+                match section.expanded {
+                    true => (section.progress + step).min(1.0),
+                    false => (section.progress - step).max(0.0),
+                }
+            }
+            false => target,
+        };
+
+        // The progress is only written when it changes, so that sections
+        // which have settled are not marked as changed.
+        if section.progress != progress {
+            section.progress = progress;
+        }
+
+        if progress <= 0.0 {
+            style.display = Display::None;
+            style.height = Val::Auto;
+        } else if progress >= 1.0 {
+            style.display = Display::Flex;
+            style.height = Val::Auto;
+        } else {
+            let content_height = contents
+                .get(section.content)
+                .map_or(0.0, |node| node.size().y);
+            let eased = progress * progress * (3.0 - 2.0 * progress);
+
+            style.display = Display::Flex;
+            style.height = Val::Px(content_height * eased);
+        }
+    }
+}
@@ -0,0 +1,697 @@
+//! Contains the components, events, and systems that edit the color of a
+//! color picker.
+
+use bevy::asset::embedded_asset;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef};
+use bevy::ui::RelativeCursorPosition;
+
+use super::register_ui_material;
+use crate::prelude::{
+    BackInput,
+    DataBlock,
+    Focusable,
+    NodeBundleBuilder,
+    NodeBundleType,
+    NodeColorPicker,
+    NodeText,
+    UiFocus,
+    VirtualKeyPressed,
+    VirtualKeyboardTarget,
+};
+
+/// The path of the shader used to draw the parts of a color picker.
+const COLOR_PICKER_SHADER: &str = "embedded://bevy_streamline_ui/widgets/color_picker.wgsl";
+
+/// The size of the marker within the saturation/value square, and the
+/// thickness of the markers on the hue and alpha bars, in logical pixels.
+const MARKER_SIZE: f32 = 10.0;
+
+/// The largest difference between two color channels for them to be treated
+/// as equal when syncing the hue, saturation, and value of a color picker.
+const CHANNEL_EPSILON: f32 = 1e-4;
+
+/// A component that stores the color of a color picker.
+///
+/// Game code may write to [`ColorPicker::color`] to change the color.
+#[derive(Debug, Clone, Component)]
+pub struct ColorPicker {
+    /// The current color of the color picker.
+    pub color: Color,
+
+    /// Whether the color picker displays a slider for the alpha channel.
+    pub show_alpha: bool,
+
+    /// The color that is currently displayed, if it has been displayed.
+    pub(crate) shown: Option<Color>,
+
+    /// The hue, saturation, value, and alpha of the color, which are kept
+    /// separately so that the hue is not lost when the saturation or value is
+    /// zero.
+    hsva: Vec4,
+
+    /// The text that has been typed into the hex entry field, while it is
+    /// being edited.
+    entry: Option<String>,
+
+    /// The nodes that display the color.
+    parts: ColorPickerParts,
+}
+
+impl ColorPicker {
+    /// Returns true if a hex value is currently being typed into the color
+    /// picker.
+    pub fn is_editing(&self) -> bool {
+        self.entry.is_some()
+    }
+
+    /// Sets the color from the current hue, saturation, value, and alpha.
+    fn apply_hsva(&mut self, hsva: Vec4) {
+        let color = hsva_to_color(hsva);
+        self.hsva = hsva;
+        if self.color != color {
+            self.color = color;
+        }
+    }
+
+    /// Parses the typed hex value and applies it as the new color, ending the
+    /// current edit. Text that is not a valid hex color is discarded.
+    fn commit_entry(&mut self) {
+        let Some(entry) = self.entry.take() else {
+            return;
+        };
+
+        if let Ok(color) = Color::hex(entry.trim()) {
+            self.color = match self.show_alpha {
+                true => color,
+                false => color.with_a(self.color.a()),
+            };
+        }
+    }
+}
+
+/// The nodes of a color picker that are updated when its color changes.
+#[derive(Debug, Clone, Copy)]
+struct ColorPickerParts {
+    /// The saturation/value square.
+    square: Entity,
+
+    /// The alpha bar, if displayed.
+    alpha: Option<Entity>,
+
+    /// The marker within the saturation/value square.
+    square_marker: Entity,
+
+    /// The marker on the hue bar.
+    hue_marker: Entity,
+
+    /// The marker on the alpha bar, if displayed.
+    alpha_marker: Option<Entity>,
+
+    /// The node that previews the color.
+    preview: Entity,
+
+    /// The text of the hex entry field.
+    hex_text: Entity,
+}
+
+/// An event that is sent when the color of a color picker changes.
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct ColorChanged {
+    /// The color picker entity.
+    pub picker: Entity,
+
+    /// The previous color of the color picker.
+    pub previous: Color,
+
+    /// The new color of the color picker.
+    pub color: Color,
+}
+
+/// The UI material used to draw the saturation/value square, hue bar, and
+/// alpha bar of a color picker.
+#[derive(Debug, Clone, Asset, TypePath, AsBindGroup)]
+pub struct ColorPickerMaterial {
+    /// The current color in sRGB space, used by the alpha bar.
+    #[uniform(0)]
+    pub color: Vec4,
+
+    /// The part that is drawn and the current hue, packed into the `x` and
+    /// `y` components.
+    #[uniform(1)]
+    pub params: Vec4,
+}
+
+impl UiMaterial for ColorPickerMaterial {
+    fn fragment_shader() -> ShaderRef {
+        COLOR_PICKER_SHADER.into()
+    }
+}
+
+/// The part of a color picker that may be dragged to edit the color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorArea {
+    /// The saturation/value square.
+    Square,
+
+    /// The hue bar.
+    Hue,
+
+    /// The alpha bar.
+    Alpha,
+}
+
+impl ColorArea {
+    /// Gets the mode passed to the shader to draw this area.
+    fn mode(self) -> f32 {
+        match self {
+            ColorArea::Square => 0.0,
+            ColorArea::Hue => 1.0,
+            ColorArea::Alpha => 2.0,
+        }
+    }
+}
+
+/// A component for a part of a color picker that edits the color while
+/// dragged.
+#[derive(Debug, Clone, Copy, Component)]
+struct ColorPickerArea {
+    /// The color picker this area belongs to.
+    picker: Entity,
+
+    /// Which part of the color picker this is.
+    area: ColorArea,
+
+    /// Whether the area is currently being dragged.
+    dragging: bool,
+}
+
+/// A component for a preset color that is applied to a color picker when
+/// clicked.
+#[derive(Debug, Clone, Copy, Component)]
+struct ColorSwatch {
+    /// The color picker this swatch belongs to.
+    picker: Entity,
+
+    /// The color of the swatch.
+    color: Color,
+}
+
+/// A component for the hex entry field of a color picker.
+#[derive(Debug, Clone, Copy, Component)]
+struct ColorPickerField {
+    /// The color picker this field belongs to.
+    picker: Entity,
+}
+
+/// This plugin handles editing the colors of color pickers.
+pub(crate) struct ColorPickerPlugin;
+impl Plugin for ColorPickerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ColorChanged>().add_systems(
+            Update,
+            (
+                drag_color_picker_areas,
+                pick_color_swatches,
+                type_color_picker_hex,
+                commit_unfocused_color_pickers,
+                update_color_pickers,
+            )
+                .chain(),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        embedded_asset!(app, "color_picker.wgsl");
+        register_ui_material::<ColorPickerMaterial>(app);
+    }
+}
+
+/// Consumes the given container node builder and creates a color picker,
+/// along with its square, bars, entry field, and swatches.
+///
+/// Returns the entity of the color picker.
+pub(crate) fn build_color_picker(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut container: NodeBundleBuilder,
+    picker: NodeColorPicker,
+) -> Entity {
+    let spacing = Val::Px(picker.spacing);
+    let bar = picker.bar_thickness;
+    let full_width = picker.size + picker.spacing + bar;
+
+    let style = container.get_style_mut();
+    style.flex_direction = FlexDirection::Column;
+    style.row_gap = spacing;
+    let picker_id = container.build(cmd, asset_server);
+
+    let mut top = NodeBundleBuilder::default();
+    top.set_parent(Some(picker_id));
+    top.get_style_mut().column_gap = spacing;
+    let top_id = top.build(cmd, asset_server);
+
+    let area = |cmd: &mut Commands, parent: Entity, area: ColorArea, size: Vec2| {
+        let mut node = NodeBundleBuilder::default();
+        node.set_parent(Some(parent));
+        node.bundle_type(NodeBundleType::Material);
+        node.insert((
+            asset_server.add(ColorPickerMaterial {
+                color: Vec4::ONE,
+                params: Vec4::new(area.mode(), 0.0, 0.0, 0.0),
+            }),
+            ColorPickerArea {
+                picker: picker_id,
+                area,
+                dragging: false,
+            },
+            Interaction::default(),
+            RelativeCursorPosition::default(),
+        ));
+
+        let style = node.get_style_mut();
+        style.flex_shrink = 0.0;
+        style.width = Val::Px(size.x);
+        style.height = Val::Px(size.y);
+        node.build(cmd, asset_server)
+    };
+
+    let marker = |cmd: &mut Commands, parent: Entity, size: Vec2| {
+        cmd.spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: if size.x > 0.0 {
+                        Val::Px(size.x)
+                    } else {
+                        Val::Percent(100.0)
+                    },
+                    height: if size.y > 0.0 {
+                        Val::Px(size.y)
+                    } else {
+                        Val::Percent(100.0)
+                    },
+                    margin: UiRect {
+                        left: Val::Px(-size.x / 2.0),
+                        top: Val::Px(-size.y / 2.0),
+                        ..default()
+                    },
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                border_color: BorderColor(Color::WHITE),
+                ..default()
+            },
+            Interaction::default(),
+        ))
+        .set_parent(parent)
+        .id()
+    };
+
+    let square = area(cmd, top_id, ColorArea::Square, Vec2::splat(picker.size));
+    let square_marker = marker(cmd, square, Vec2::splat(MARKER_SIZE));
+
+    let hue = area(cmd, top_id, ColorArea::Hue, Vec2::new(bar, picker.size));
+    let hue_marker = marker(cmd, hue, Vec2::new(0.0, MARKER_SIZE / 2.0));
+
+    let (alpha, alpha_marker) = match picker.show_alpha {
+        true => {
+            let alpha = area(cmd, picker_id, ColorArea::Alpha, Vec2::new(full_width, bar));
+            let alpha_marker = marker(cmd, alpha, Vec2::new(MARKER_SIZE / 2.0, 0.0));
+            (Some(alpha), Some(alpha_marker))
+        }
+        false => (None, None),
+    };
+
+    let mut bottom = NodeBundleBuilder::default();
+    bottom.set_parent(Some(picker_id));
+
+    let style = bottom.get_style_mut();
+    style.width = Val::Px(full_width);
+    style.column_gap = spacing;
+    let bottom_id = bottom.build(cmd, asset_server);
+
+    let mut preview = NodeBundleBuilder::default();
+    preview.set_parent(Some(bottom_id));
+    preview.insert(BackgroundColor(picker.color));
+
+    let style = preview.get_style_mut();
+    style.flex_shrink = 0.0;
+    style.width = Val::Px(bar * 2.0);
+    style.min_height = Val::Px(bar);
+    let preview_id = preview.build(cmd, asset_server);
+
+    let mut field = NodeBundleBuilder::default();
+    field.set_parent(Some(bottom_id));
+    field.insert((
+        ColorPickerField { picker: picker_id },
+        BackgroundColor(picker.field_color),
+        Interaction::default(),
+        Focusable,
+        VirtualKeyboardTarget::default(),
+    ));
+
+    let style = field.get_style_mut();
+    style.flex_grow = 1.0;
+    style.align_items = AlignItems::Center;
+    style.padding = UiRect::horizontal(Val::Px(4.0));
+    let field_id = field.build(cmd, asset_server);
+
+    let mut text: NodeText = picker.text.clone();
+    text.sections.truncate(1);
+
+    let mut hex_text = NodeBundleBuilder::default();
+    hex_text.set_parent(Some(field_id));
+    text.apply_to_node(&mut hex_text, asset_server);
+    let hex_text_id = hex_text.build(cmd, asset_server);
+
+    if !picker.swatches.is_empty() {
+        let mut swatches = NodeBundleBuilder::default();
+        swatches.set_parent(Some(picker_id));
+
+        let style = swatches.get_style_mut();
+        style.width = Val::Px(full_width);
+        style.flex_wrap = FlexWrap::Wrap;
+        style.row_gap = Val::Px(4.0);
+        style.column_gap = Val::Px(4.0);
+        let swatches_id = swatches.build(cmd, asset_server);
+
+        for color in picker.swatches {
+            let mut swatch = NodeBundleBuilder::default();
+            swatch.set_parent(Some(swatches_id));
+            swatch.insert((
+                ColorSwatch {
+                    picker: picker_id,
+                    color,
+                },
+                BackgroundColor(color),
+                Interaction::default(),
+            ));
+
+            let style = swatch.get_style_mut();
+            style.width = Val::Px(bar);
+            style.height = Val::Px(bar);
+            swatch.build(cmd, asset_server);
+        }
+    }
+
+    cmd.entity(picker_id).insert(ColorPicker {
+        color: picker.color,
+        show_alpha: picker.show_alpha,
+        shown: None,
+        hsva: color_to_hsva(picker.color, Vec4::ZERO),
+        entry: None,
+        parts: ColorPickerParts {
+            square,
+            alpha,
+            square_marker,
+            hue_marker,
+            alpha_marker,
+            preview: preview_id,
+            hex_text: hex_text_id,
+        },
+    });
+
+    picker_id
+}
+
+/// Converts the given hue, saturation, value, and alpha into a color.
+fn hsva_to_color(hsva: Vec4) -> Color {
+    let h = hsva.x.rem_euclid(1.0) * 6.0;
+    let c = hsva.z * hsva.y;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let m = hsva.z - c;
+
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    Color::rgba(r + m, g + m, b + m, hsva.w)
+}
+
+/// Converts the given color into its hue, saturation, value, and alpha.
+///
+/// The hue and saturation of the previous value are kept when they can not
+/// be determined from the color, such as for grays and black.
+fn color_to_hsva(color: Color, previous: Vec4) -> Vec4 {
+    let [r, g, b, a] = color.as_rgba_f32();
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+
+    let hue = match delta <= 0.0 {
+        true => previous.x,
+        false if max == r => ((g - b) / delta).rem_euclid(6.0) / 6.0,
+        false if max == g => ((b - r) / delta + 2.0) / 6.0,
+        false => ((r - g) / delta + 4.0) / 6.0,
+    };
+
+    let saturation = match max <= 0.0 {
+        true => previous.y,
+        false => delta / max,
+    };
+
+    Vec4::new(hue, saturation, max, a)
+}
+
+/// Formats the given color as a hex string, including the alpha channel if
+/// requested.
+fn color_to_hex(color: Color, alpha: bool) -> String {
+    let [r, g, b, a] = color.as_rgba_u8();
+    match alpha {
+        true => format!("#{r:02X}{g:02X}{b:02X}{a:02X}"),
+        false => format!("#{r:02X}{g:02X}{b:02X}"),
+    }
+}
+
+/// Edits the color of a color picker while its square or one of its bars is
+/// dragged.
+fn drag_color_picker_areas(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut areas: Query<(&Interaction, &RelativeCursorPosition, &mut ColorPickerArea)>,
+    mut pickers: Query<&mut ColorPicker>,
+) {
+    for (interaction, cursor, mut area) in &mut areas {
+        if mouse.just_pressed(MouseButton::Left) && *interaction == Interaction::Pressed {
+            area.dragging = true;
+        } else if !mouse.pressed(MouseButton::Left) {
+            area.dragging = false;
+        }
+
+        if !area.dragging {
+            continue;
+        }
+
+        let (Some(pos), Ok(mut picker)) = (cursor.normalized, pickers.get_mut(area.picker)) else {
+            continue;
+        };
+
+        let pos = pos.clamp(Vec2::ZERO, Vec2::ONE);
+        let mut hsva = picker.hsva;
+        match area.area {
+            ColorArea::Square => {
+                hsva.y = pos.x;
+                hsva.z = 1.0 - pos.y;
+            }
+            ColorArea::Hue => hsva.x = pos.y,
+            ColorArea::Alpha => hsva.w = pos.x,
+        }
+
+        if picker.hsva != hsva {
+            picker.entry = None;
+            picker.apply_hsva(hsva);
+        }
+    }
+}
+
+/// Applies the color of a swatch to its color picker when clicked.
+fn pick_color_swatches(
+    swatches: Query<(&Interaction, &ColorSwatch), Changed<Interaction>>,
+    mut pickers: Query<&mut ColorPicker>,
+) {
+    for (interaction, swatch) in &swatches {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let Ok(mut picker) = pickers.get_mut(swatch.picker) {
+            picker.entry = None;
+            picker.color = match picker.show_alpha {
+                true => swatch.color,
+                false => swatch.color.with_a(picker.color.a()),
+            };
+        }
+    }
+}
+
+/// Handles typed entry for the focused hex entry field, from either the
+/// keyboard or the virtual keyboard.
+fn type_color_picker_hex(
+    mut keyboard: EventReader<KeyboardInput>,
+    mut virtual_keys: EventReader<VirtualKeyPressed>,
+    focus: Res<UiFocus>,
+    mut back: ResMut<BackInput>,
+    fields: Query<&ColorPickerField>,
+    mut pickers: Query<&mut ColorPicker>,
+) {
+    let Some((field_id, mut picker)) = focus.entity.and_then(|entity| {
+        let field = fields.get(entity).ok()?;
+        pickers.get_mut(field.picker).ok().map(|p| (entity, p))
+    }) else {
+        keyboard.clear();
+        virtual_keys.clear();
+        return;
+    };
+
+    let keys = keyboard
+        .read()
+        .filter(|input| input.state == ButtonState::Pressed)
+        .map(|input| &input.logical_key);
+    let virtual_keys = virtual_keys
+        .read()
+        .filter(|input| input.target == field_id)
+        .map(|input| &input.key);
+
+    for key in keys.chain(virtual_keys) {
+        if *key == Key::Escape && picker.entry.is_some() {
+            back.consume();
+        }
+
+        type_color_picker_key(&mut picker, key);
+    }
+}
+
+/// Applies a single pressed key to the hex entry of the given color picker.
+///
+/// Typing starts a new entry. Enter applies the typed value, while escape
+/// discards it.
+fn type_color_picker_key(picker: &mut ColorPicker, key: &Key) {
+    match key {
+        Key::Character(chars) => {
+            for c in chars.chars() {
+                let entry = picker.entry.get_or_insert_with(String::new);
+                if (c.is_ascii_hexdigit() || c == '#') && entry.len() < 9 {
+                    entry.push(c.to_ascii_uppercase());
+                }
+            }
+        }
+
+        Key::Backspace => {
+            if let Some(entry) = &mut picker.entry {
+                entry.pop();
+            }
+        }
+
+        Key::Enter => picker.commit_entry(),
+        Key::Escape => picker.entry = None,
+        _ => {}
+    }
+}
+
+/// Applies the typed value of any color picker whose hex entry field has lost
+/// focus.
+fn commit_unfocused_color_pickers(
+    focus: Res<UiFocus>,
+    fields: Query<(Entity, &ColorPickerField)>,
+    mut pickers: Query<&mut ColorPicker>,
+) {
+    for (entity, field) in &fields {
+        let Ok(mut picker) = pickers.get_mut(field.picker) else {
+            continue;
+        };
+
+        if picker.is_editing() && !focus.is_focused(entity) {
+            picker.commit_entry();
+        }
+    }
+}
+
+/// Updates the markers, materials, preview, and text of each color picker that
+/// has changed, and sends an event when its color changes.
+fn update_color_pickers(
+    mut changed: EventWriter<ColorChanged>,
+    mut materials: ResMut<Assets<ColorPickerMaterial>>,
+    mut pickers: Query<(Entity, &mut ColorPicker), Changed<ColorPicker>>,
+    handles: Query<&Handle<ColorPickerMaterial>>,
+    mut styles: Query<&mut Style>,
+    mut backgrounds: Query<&mut BackgroundColor>,
+    mut texts: Query<&mut Text>,
+) {
+    for (picker_id, mut picker) in &mut pickers {
+        // The color was written directly, so the hue, saturation, and value
+        // must be recalculated from it.
+        let expected = hsva_to_color(picker.hsva).as_rgba_f32();
+        let actual = picker.color.as_rgba_f32();
+        if expected
+            .iter()
+            .zip(actual)
+            .any(|(a, b)| (a - b).abs() > CHANNEL_EPSILON)
+        {
+            picker.hsva = color_to_hsva(picker.color, picker.hsva);
+        }
+
+        if picker.shown != Some(picker.color) {
+            if let Some(previous) = picker.shown {
+                changed.send(ColorChanged {
+                    picker: picker_id,
+                    previous,
+                    color: picker.color,
+                });
+            }
+            picker.shown = Some(picker.color);
+        }
+
+        let hsva = picker.hsva;
+        let parts = picker.parts;
+
+        if let Some(material) = handles
+            .get(parts.square)
+            .ok()
+            .and_then(|handle| materials.get_mut(handle))
+        {
+            material.params.y = hsva.x;
+        }
+
+        if let Some(material) = parts
+            .alpha
+            .and_then(|alpha| handles.get(alpha).ok())
+            .and_then(|handle| materials.get_mut(handle))
+        {
+            material.color = Vec4::from_array(picker.color.as_rgba_f32());
+        }
+
+        if let Ok(mut style) = styles.get_mut(parts.square_marker) {
+            style.left = Val::Percent(hsva.y * 100.0);
+            style.top = Val::Percent((1.0 - hsva.z) * 100.0);
+        }
+
+        if let Ok(mut style) = styles.get_mut(parts.hue_marker) {
+            style.top = Val::Percent(hsva.x * 100.0);
+        }
+
+        if let Some(Ok(mut style)) = parts.alpha_marker.map(|marker| styles.get_mut(marker)) {
+            style.left = Val::Percent(hsva.w * 100.0);
+        }
+
+        if let Ok(mut background) = backgrounds.get_mut(parts.preview) {
+            background.0 = picker.color;
+        }
+
+        let display = match &picker.entry {
+            Some(entry) => format!("{entry}|"),
+            None => color_to_hex(picker.color, picker.show_alpha),
+        };
+
+        if let Ok(mut text) = texts.get_mut(parts.hex_text) {
+            if let Some(section) = text.sections.first_mut() {
+                section.value = display;
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// Draws the saturation/value square, hue bar, and alpha bar of a color picker.
+
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+// The current color in sRGB space, used by the alpha bar.
+@group(1) @binding(0) var<uniform> color: vec4<f32>;
+
+// x: 0.0 for the saturation/value square, 1.0 for the hue bar, 2.0 for the
+// alpha bar. y: the current hue, between 0.0 and 1.0.
+@group(1) @binding(1) var<uniform> params: vec4<f32>;
+
+// The size, in logical pixels, of each cell of the checkerboard behind the
+// alpha bar.
+const CHECKER_SIZE: f32 = 6.0;
+
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> vec3<f32> {
+    let k = vec3<f32>(1.0, 2.0 / 3.0, 1.0 / 3.0);
+    let p = abs(fract(vec3<f32>(h) + k) * 6.0 - 3.0);
+    return v * mix(vec3<f32>(1.0), clamp(p - 1.0, vec3<f32>(0.0), vec3<f32>(1.0)), s);
+}
+
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let low = c / 12.92;
+    let high = pow((c + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, c <= vec3<f32>(0.04045));
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    var rgb: vec3<f32>;
+
+    if params.x < 0.5 {
+        rgb = hsv_to_rgb(params.y, in.uv.x, 1.0 - in.uv.y);
+    } else if params.x < 1.5 {
+        rgb = hsv_to_rgb(in.uv.y, 1.0, 1.0);
+    } else {
+        let cell = floor(in.uv * in.size / CHECKER_SIZE);
+        let checker = select(0.5, 0.8, fract((cell.x + cell.y) * 0.5) < 0.25);
+        rgb = mix(vec3<f32>(checker), color.rgb, in.uv.x);
+    }
+
+    return vec4<f32>(srgb_to_linear(rgb), 1.0);
+}
@@ -0,0 +1,747 @@
+//! Contains the components, events, and systems used to reveal the lines of a
+//! dialogue box.
+
+use bevy::prelude::*;
+
+use crate::prelude::{
+    AnchorPoint,
+    DataBlock,
+    NodeBundleBuilder,
+    NodeBundleType,
+    NodeDialogue,
+    NodePosition,
+    NodeText,
+    UiFocus,
+};
+
+/// A component that stores the state of a dialogue box.
+///
+/// Game code calls [`Dialogue::say`] to display a new line, and may write to
+/// the public fields to change the speaker, portrait, or reveal rate.
+#[derive(Debug, Clone, Component)]
+pub struct Dialogue {
+    /// The name displayed on the name plate. The name plate is hidden if not
+    /// set.
+    pub speaker: Option<String>,
+
+    /// The portrait image displayed beside the text. The portrait is hidden if
+    /// not set.
+    pub portrait: Option<Handle<Image>>,
+
+    /// The number of characters revealed each second. A rate of zero reveals
+    /// each line immediately.
+    pub chars_per_second: f32,
+
+    /// The time, in seconds, the continue indicator takes to blink on and off.
+    /// A time of zero disables blinking.
+    pub blink_time: f32,
+
+    /// The keys that skip to the end of the line, or continue to the next line
+    /// once the line has been fully revealed.
+    pub advance_keys: Vec<KeyCode>,
+
+    /// The line that will be displayed next, if one has been requested.
+    pending: Option<NodeText>,
+
+    /// The line that is currently displayed.
+    line: DialogueLine,
+
+    /// The number of characters of the line that have been revealed.
+    revealed: usize,
+
+    /// The fraction of a character carried over between frames.
+    budget: f32,
+
+    /// The current multiplier of the reveal rate.
+    speed: f32,
+
+    /// The time, in seconds, remaining in the current pause.
+    pause: f32,
+
+    /// The index of the next cue of the line to apply.
+    next_cue: usize,
+
+    /// The number of revealed characters that is currently displayed, if it
+    /// has been displayed.
+    pub(crate) shown: Option<usize>,
+
+    /// Whether the finished event has been sent for the current line.
+    finished_sent: bool,
+
+    /// The nodes that display the dialogue.
+    parts: DialogueParts,
+}
+
+impl Dialogue {
+    /// Replaces the current line with the given text, which is revealed from
+    /// the start.
+    ///
+    /// Inline images and links are not supported within dialogue lines. Image
+    /// sections are skipped, and link sections are displayed as plain text.
+    pub fn say<T: Into<NodeText>>(&mut self, text: T) {
+        self.pending = Some(text.into());
+    }
+
+    /// Reveals the rest of the current line immediately.
+    pub fn skip(&mut self) {
+        self.revealed = self.line.len;
+        self.next_cue = self.line.cues.len();
+        self.pause = 0.0;
+    }
+
+    /// Returns true if the current line has been fully revealed, including any
+    /// pause at the end of the line.
+    pub fn is_finished(&self) -> bool {
+        self.pending.is_none()
+            && self.revealed >= self.line.len
+            && self.next_cue >= self.line.cues.len()
+            && self.pause <= 0.0
+    }
+}
+
+/// The nodes of a dialogue box that are updated when its state changes.
+#[derive(Debug, Clone, Copy)]
+struct DialogueParts {
+    /// The portrait image node.
+    portrait: Entity,
+
+    /// The name plate node.
+    name_plate: Entity,
+
+    /// The text node of the name plate.
+    name: Entity,
+
+    /// The text node of the line.
+    text: Entity,
+
+    /// The continue indicator node.
+    indicator: Entity,
+}
+
+/// A cue within a line of dialogue, applied once the reveal reaches it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DialogueCue {
+    /// Pauses the reveal for the given number of seconds.
+    Pause(f32),
+
+    /// Multiplies the reveal rate by the given factor.
+    Speed(f32),
+}
+
+impl DialogueCue {
+    /// Parses the contents of a cue, such as `pause=0.5`.
+    fn parse(tag: &str) -> Option<Self> {
+        let (name, value) = tag.split_once('=')?;
+        let value = value.trim().parse::<f32>().ok().filter(|v| v.is_finite())?;
+
+        // A speed of zero would stop the reveal, so that the line would never
+        // finish.
+        match name.trim() {
+            "pause" if value >= 0.0 => Some(DialogueCue::Pause(value)),
+            "speed" if value > 0.0 => Some(DialogueCue::Speed(value)),
+            _ => None,
+        }
+    }
+}
+
+/// A line of dialogue with its cues removed.
+#[derive(Debug, Default, Clone)]
+struct DialogueLine {
+    /// The displayed text of each section.
+    sections: Vec<String>,
+
+    /// The cues of the line, along with the number of characters that are
+    /// revealed before each is applied.
+    cues: Vec<(usize, DialogueCue)>,
+
+    /// The total number of characters in the line.
+    len: usize,
+}
+
+impl DialogueLine {
+    /// Removes the cues from the given text, returning the line and updating
+    /// the text of each section to the displayed text.
+    fn parse(text: &mut NodeText) -> Self {
+        let mut line = DialogueLine::default();
+
+        for section in &mut text.sections {
+            let mut displayed = String::new();
+            let mut chars = section.text.chars().peekable();
+
+            while let Some(c) = chars.next() {
+                if c != '{' {
+                    displayed.push(c);
+                    line.len += 1;
+                    continue;
+                }
+
+                if chars.next_if_eq(&'{').is_some() {
+                    displayed.push('{');
+                    line.len += 1;
+                    continue;
+                }
+
+                let mut tag = String::new();
+                let mut terminated = false;
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        terminated = true;
+                        break;
+                    }
+                    tag.push(c);
+                }
+
+                match DialogueCue::parse(&tag).filter(|_| terminated) {
+                    Some(cue) => line.cues.push((line.len, cue)),
+                    None => {
+                        // Unknown or unterminated cues are displayed as
+                        // written.
+                        let literal = match terminated {
+                            true => format!("{{{tag}}}"),
+                            false => format!("{{{tag}"),
+                        };
+                        line.len += literal.chars().count();
+                        displayed.push_str(&literal);
+                    }
+                }
+            }
+
+            section.text = displayed.clone();
+            line.sections.push(displayed);
+        }
+
+        line
+    }
+}
+
+/// An event that is sent when the current line of a dialogue box has been
+/// fully revealed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct DialogueLineFinished {
+    /// The dialogue box entity.
+    pub dialogue: Entity,
+}
+
+/// An event that is sent when the player asks to continue past a fully
+/// revealed line, usually answered by calling [`Dialogue::say`] with the next
+/// line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct DialogueContinue {
+    /// The dialogue box entity.
+    pub dialogue: Entity,
+}
+
+/// This plugin handles revealing the lines of dialogue boxes.
+pub(crate) struct DialoguePlugin;
+impl Plugin for DialoguePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DialogueLineFinished>()
+            .add_event::<DialogueContinue>()
+            .add_systems(
+                Update,
+                (
+                    start_dialogue_lines,
+                    advance_dialogues,
+                    reveal_dialogue_text,
+                    update_dialogues,
+                    blink_dialogue_indicators,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Consumes the given container node builder and creates a dialogue box, along
+/// with its portrait, name plate, text, and continue indicator.
+///
+/// Returns the entity of the dialogue box.
+pub(crate) fn build_dialogue(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut container: NodeBundleBuilder,
+    dialogue: NodeDialogue,
+) -> Entity {
+    let spacing = Val::Px(dialogue.spacing);
+    container.insert(Interaction::default());
+    container.get_style_mut().column_gap = spacing;
+    let dialogue_id = container.build(cmd, asset_server);
+
+    let mut portrait = NodeBundleBuilder::default();
+    portrait.set_parent(Some(dialogue_id));
+    portrait.bundle_type(NodeBundleType::Image);
+    portrait.insert(UiImage::default());
+
+    let style = portrait.get_style_mut();
+    style.flex_shrink = 0.0;
+    style.width = Val::Px(dialogue.portrait_size);
+    style.height = Val::Px(dialogue.portrait_size);
+    style.display = Display::None;
+    let portrait_id = portrait.build(cmd, asset_server);
+
+    let mut body = NodeBundleBuilder::default();
+    body.set_parent(Some(dialogue_id));
+
+    let style = body.get_style_mut();
+    style.flex_grow = 1.0;
+    style.flex_direction = FlexDirection::Column;
+    style.row_gap = spacing;
+    let body_id = body.build(cmd, asset_server);
+
+    let mut name_plate = NodeBundleBuilder::default();
+    name_plate.set_parent(Some(body_id));
+    dialogue
+        .speaker_background
+        .apply_to_node(&mut name_plate, asset_server);
+
+    let style = name_plate.get_style_mut();
+    style.align_self = AlignSelf::FlexStart;
+    style.padding = UiRect::axes(Val::Px(8.0), Val::Px(4.0));
+    style.display = Display::None;
+    let name_plate_id = name_plate.build(cmd, asset_server);
+
+    let mut name_text = dialogue.speaker_text.clone();
+    name_text.sections.truncate(1);
+
+    let mut name = NodeBundleBuilder::default();
+    name.set_parent(Some(name_plate_id));
+    name_text.apply_to_node(&mut name, asset_server);
+    let name_id = name.build(cmd, asset_server);
+
+    let mut text = NodeBundleBuilder::default();
+    text.set_parent(Some(body_id));
+    NodeText::default().apply_to_node(&mut text, asset_server);
+    let text_id = text.build(cmd, asset_server);
+
+    let mut indicator = NodeBundleBuilder::default();
+    indicator.set_parent(Some(dialogue_id));
+    indicator.insert(Visibility::Hidden);
+    dialogue
+        .continue_indicator
+        .apply_to_node(&mut indicator, asset_server);
+    NodePosition::Anchored {
+        anchor: AnchorPoint::BottomRight,
+        width: Val::Auto,
+        height: Val::Auto,
+        margin: spacing,
+    }
+    .apply_to_node(&mut indicator, asset_server);
+    let indicator_id = indicator.build(cmd, asset_server);
+
+    cmd.entity(dialogue_id).insert(Dialogue {
+        speaker: dialogue.speaker,
+        portrait: dialogue.portrait.map(|path| asset_server.load(path)),
+        chars_per_second: dialogue.chars_per_second,
+        blink_time: dialogue.blink_time,
+        advance_keys: dialogue.advance_keys,
+        pending: Some(dialogue.text),
+        line: DialogueLine::default(),
+        revealed: 0,
+        budget: 0.0,
+        speed: 1.0,
+        pause: 0.0,
+        next_cue: 0,
+        shown: None,
+        finished_sent: false,
+        parts: DialogueParts {
+            portrait: portrait_id,
+            name_plate: name_plate_id,
+            name: name_id,
+            text: text_id,
+            indicator: indicator_id,
+        },
+    });
+
+    dialogue_id
+}
+
+/// Starts revealing the requested line of each dialogue box.
+///
+/// Each section of the line is displayed as two sections: the revealed text,
+/// followed by the rest of the text drawn transparent. This keeps words from
+/// jumping between lines as they are revealed.
+fn start_dialogue_lines(
+    asset_server: Res<AssetServer>,
+    mut dialogues: Query<&mut Dialogue>,
+    mut texts: Query<&mut Text>,
+) {
+    for mut dialogue in &mut dialogues {
+        // The pending line is checked first, so that dialogue boxes without a
+        // new line are not marked as changed.
+        if dialogue.pending.is_none() {
+            continue;
+        }
+
+        let Some(mut line) = dialogue.pending.take() else {
+            continue;
+        };
+
+        // Inline images are not revealed by the typewriter, and are skipped so
+        // that the sections of the line match the sections of the text. Links
+        // are not supported either, and are left as plain text.
+        line.sections.retain(|section| section.image.is_none());
+        dialogue.line = DialogueLine::parse(&mut line);
+        dialogue.revealed = 0;
+        dialogue.budget = 0.0;
+        dialogue.speed = 1.0;
+        dialogue.pause = 0.0;
+        dialogue.next_cue = 0;
+        dialogue.shown = None;
+        dialogue.finished_sent = false;
+
+        let Ok(mut text) = texts.get_mut(dialogue.parts.text) else {
+            continue;
+        };
+
+        let mut line = line.into_text(&asset_server);
+        line.sections = line
+            .sections
+            .into_iter()
+            .flat_map(|section| {
+                let mut hidden = section.clone();
+                hidden.style.color = Color::NONE;
+                [section, hidden]
+            })
+            .collect();
+        *text = line;
+    }
+}
+
+/// Skips to the end of the current line of a dialogue box when it is clicked
+/// or an advance key is pressed, or requests the next line if it has already
+/// been fully revealed.
+///
+/// Advance keys are ignored while another node has keyboard focus.
+fn advance_dialogues(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    focus: Res<UiFocus>,
+    mut continued: EventWriter<DialogueContinue>,
+    mut dialogues: Query<(Entity, Ref<Interaction>, &ViewVisibility, &mut Dialogue)>,
+) {
+    for (entity, interaction, visibility, mut dialogue) in &mut dialogues {
+        let clicked = interaction.is_changed() && *interaction == Interaction::Pressed;
+        let key = focus.entity.is_none()
+            && visibility.get()
+            && keyboard.any_just_pressed(dialogue.advance_keys.iter().copied());
+
+        if !clicked && !key {
+            continue;
+        }
+
+        match dialogue.is_finished() {
+            true => {
+                continued.send(DialogueContinue { dialogue: entity });
+            }
+            false => dialogue.skip(),
+        }
+    }
+}
+
+/// Reveals the characters of each dialogue box over time, applying the cues
+/// of the line as they are reached.
+fn reveal_dialogue_text(time: Res<Time>, mut dialogues: Query<&mut Dialogue>) {
+    for mut dialogue in &mut dialogues {
+        if dialogue.is_finished() {
+            continue;
+        }
+
+        if dialogue.chars_per_second <= 0.0 {
+            dialogue.skip();
+            continue;
+        }
+
+        let dialogue = &mut *dialogue;
+        if dialogue.pause > 0.0 {
+            dialogue.pause -= time.delta_seconds();
+            if dialogue.pause > 0.0 {
+                continue;
+            }
+            dialogue.pause = 0.0;
+        }
+
+        dialogue.budget += time.delta_seconds() * dialogue.chars_per_second * dialogue.speed;
+
+        loop {
+            if let Some(&(index, cue)) = dialogue.line.cues.get(dialogue.next_cue) {
+                if index <= dialogue.revealed {
+                    dialogue.next_cue += 1;
+                    match cue {
+                        DialogueCue::Pause(seconds) => {
+                            dialogue.pause = seconds;
+                            dialogue.budget = 0.0;
+                            break;
+                        }
+                        DialogueCue::Speed(speed) => dialogue.speed = speed,
+                    }
+                    continue;
+                }
+            }
+
+            if dialogue.revealed >= dialogue.line.len || dialogue.budget < 1.0 {
+                break;
+            }
+
+            dialogue.revealed += 1;
+            dialogue.budget -= 1.0;
+        }
+    }
+}
+
+/// Updates the text, name plate, and portrait of each dialogue box that has
+/// changed, and sends an event when its line has been fully revealed.
+fn update_dialogues(
+    mut finished: EventWriter<DialogueLineFinished>,
+    mut dialogues: Query<(Entity, &mut Dialogue), Changed<Dialogue>>,
+    mut texts: Query<&mut Text>,
+    mut styles: Query<&mut Style>,
+    mut images: Query<&mut UiImage>,
+    mut visibilities: Query<&mut Visibility>,
+) {
+    for (dialogue_id, mut dialogue) in &mut dialogues {
+        let parts = dialogue.parts;
+
+        if dialogue.shown != Some(dialogue.revealed) {
+            dialogue.shown = Some(dialogue.revealed);
+
+            if let Ok(mut text) = texts.get_mut(parts.text) {
+                let mut remaining = dialogue.revealed;
+                for (index, section) in dialogue.line.sections.iter().enumerate() {
+                    let count = remaining.min(section.chars().count());
+                    remaining -= count;
+
+                    let split = section
+                        .char_indices()
+                        .nth(count)
+                        .map_or(section.len(), |(i, _)| i);
+                    let (shown, hidden) = section.split_at(split);
+
+                    if let Some(section) = text.sections.get_mut(index * 2) {
+                        section.value = shown.to_string();
+                    }
+
+                    if let Some(section) = text.sections.get_mut(index * 2 + 1) {
+                        section.value = hidden.to_string();
+                    }
+                }
+            }
+        }
+
+        if dialogue.is_finished() && !dialogue.finished_sent {
+            dialogue.finished_sent = true;
+            finished.send(DialogueLineFinished {
+                dialogue: dialogue_id,
+            });
+        }
+
+        if !dialogue.is_finished() {
+            if let Ok(mut visibility) = visibilities.get_mut(parts.indicator) {
+                *visibility = Visibility::Hidden;
+            }
+        }
+
+        let display = |shown: bool| match shown {
+            true => Display::Flex,
+            false => Display::None,
+        };
+
+        if let Ok(mut style) = styles.get_mut(parts.name_plate) {
+            style.display = display(dialogue.speaker.is_some());
+        }
+
+        if let (Some(speaker), Ok(mut text)) = (&dialogue.speaker, texts.get_mut(parts.name)) {
+            if let Some(section) = text.sections.first_mut() {
+                if section.value != *speaker {
+                    section.value.clone_from(speaker);
+                }
+            }
+        }
+
+        if let Ok(mut style) = styles.get_mut(parts.portrait) {
+            style.display = display(dialogue.portrait.is_some());
+        }
+
+        if let (Some(portrait), Ok(mut image)) =
+            (&dialogue.portrait, images.get_mut(parts.portrait))
+        {
+            if image.texture != *portrait {
+                image.texture = portrait.clone();
+            }
+        }
+    }
+}
+
+/// Blinks the continue indicator of each dialogue box whose line has been
+/// fully revealed.
+fn blink_dialogue_indicators(
+    time: Res<Time>,
+    dialogues: Query<&Dialogue>,
+    mut visibilities: Query<&mut Visibility>,
+) {
+    for dialogue in &dialogues {
+        if !dialogue.is_finished() {
+            continue;
+        }
+
+        let Ok(mut visibility) = visibilities.get_mut(dialogue.parts.indicator) else {
+            continue;
+        };
+
+        let blink = dialogue.blink_time > 0.0
+            && (time.elapsed_seconds() / dialogue.blink_time) as u32 % 2 == 1;
+        let shown = match blink {
+            true => Visibility::Hidden,
+            false => Visibility::Inherited,
+        };
+
+        if *visibility != shown {
+            *visibility = shown;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::prelude::{DialogueNodeBuilder, NodeTextSection, UiNode};
+    use crate::widgets::test_app;
+
+    /// Parses a line with a section for each of the given strings, returning
+    /// the line and the displayed text of each section.
+    fn parse(sections: &[&str]) -> DialogueLine {
+        let mut text = NodeText {
+            sections: sections
+                .iter()
+                .map(|text| NodeTextSection {
+                    text: text.to_string(),
+                    ..default()
+                })
+                .collect(),
+            ..default()
+        };
+
+        let line = DialogueLine::parse(&mut text);
+        let displayed = text.sections.into_iter().map(|section| section.text);
+        assert!(displayed.eq(line.sections.iter().cloned()));
+        line
+    }
+
+    /// Creates an app with a dialogue box that reveals the given line at 10
+    /// characters per second, advancing time by 0.1 seconds each update.
+    fn dialogue_app(text: &str) -> (App, Entity) {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+
+        let mut line = NodeText::styled(18.0, Color::WHITE);
+        line.sections[0].text = text.to_string();
+
+        let dialogue =
+            app.world
+                .run_system_once(move |mut cmd: Commands, asset_server: Res<AssetServer>| {
+                    let builder = DialogueNodeBuilder::default()
+                        .text(line.clone())
+                        .chars_per_second(10.0);
+                    UiNode::from(builder).build(&mut cmd, &asset_server)
+                });
+
+        // The first update only starts the clock.
+        app.update();
+        (app, dialogue)
+    }
+
+    /// Gets the dialogue component of the given dialogue box.
+    fn dialogue(app: &App, entity: Entity) -> &Dialogue {
+        app.world.get::<Dialogue>(entity).unwrap()
+    }
+
+    #[test]
+    fn double_braces_are_displayed() {
+        let line = parse(&["a {{b}"]);
+        assert_eq!(line.sections, ["a {b}"]);
+        assert_eq!(line.len, 5);
+        assert!(line.cues.is_empty());
+    }
+
+    #[test]
+    fn unterminated_cues_are_displayed() {
+        let line = parse(&["a {pause=1"]);
+        assert_eq!(line.sections, ["a {pause=1"]);
+        assert_eq!(line.len, 10);
+        assert!(line.cues.is_empty());
+    }
+
+    #[test]
+    fn unknown_cues_are_displayed() {
+        let line = parse(&["{wait=1}a"]);
+        assert_eq!(line.sections, ["{wait=1}a"]);
+        assert!(line.cues.is_empty());
+    }
+
+    #[test]
+    fn non_positive_speeds_are_displayed() {
+        let line = parse(&["{speed=0}a{speed=-2}"]);
+        assert_eq!(line.sections, ["{speed=0}a{speed=-2}"]);
+        assert!(line.cues.is_empty());
+
+        assert_eq!(
+            DialogueCue::parse("speed=0.5"),
+            Some(DialogueCue::Speed(0.5))
+        );
+        assert_eq!(DialogueCue::parse("pause=0"), Some(DialogueCue::Pause(0.0)));
+        assert_eq!(DialogueCue::parse("pause=-1"), None);
+        assert_eq!(DialogueCue::parse("pause=inf"), None);
+    }
+
+    #[test]
+    fn cues_are_indexed_across_sections() {
+        let line = parse(&["ab{pause=1}c", "{speed=2}de", "f{pause=0.5}"]);
+        assert_eq!(line.sections, ["abc", "de", "f"]);
+        assert_eq!(line.len, 6);
+        assert_eq!(
+            line.cues,
+            [
+                (2, DialogueCue::Pause(1.0)),
+                (3, DialogueCue::Speed(2.0)),
+                (6, DialogueCue::Pause(0.5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn pause_at_end_of_line_delays_finish() {
+        let (mut app, entity) = dialogue_app("ab{pause=0.5}");
+
+        // Two characters are revealed over the first two updates, and the
+        // pause is reached on the third.
+        for _ in 0 .. 3 {
+            app.update();
+        }
+        assert_eq!(dialogue(&app, entity).revealed, 2);
+        assert!(!dialogue(&app, entity).is_finished());
+
+        for _ in 0 .. 4 {
+            app.update();
+            assert!(!dialogue(&app, entity).is_finished());
+        }
+
+        app.update();
+        assert!(dialogue(&app, entity).is_finished());
+    }
+
+    #[test]
+    fn skip_ends_pause_at_end_of_line() {
+        let (mut app, entity) = dialogue_app("ab{pause=5}");
+        for _ in 0 .. 3 {
+            app.update();
+        }
+        assert!(!dialogue(&app, entity).is_finished());
+
+        app.world.get_mut::<Dialogue>(entity).unwrap().skip();
+        assert!(dialogue(&app, entity).is_finished());
+    }
+}
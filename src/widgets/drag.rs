@@ -0,0 +1,463 @@
+//! Contains the components, events, and systems used to drag nodes and drop
+//! them onto other nodes.
+
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::window::PrimaryWindow;
+
+use super::{BackInput, UiLayer, cursor_position};
+use crate::prelude::{DragPayload, NodeBundleBuilder, NodeDraggable, NodeDropTarget, UiNode};
+
+/// The distance, in logical pixels, the cursor must move while a draggable
+/// node is pressed before the drag starts.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// The width of the outline drawn around highlighted drop targets, in logical
+/// pixels.
+const HIGHLIGHT_WIDTH: f32 = 2.0;
+
+/// A component for a node that may be dragged onto a [`DropTarget`].
+#[derive(Debug, Clone, Component)]
+pub struct Draggable {
+    /// The data carried by the node while it is dragged.
+    pub payload: DragPayload,
+
+    /// The opacity of the node that follows the cursor while it is dragged.
+    pub ghost_opacity: f32,
+
+    /// The node that is built to follow the cursor while dragged. If not set,
+    /// a plain node with the size and background color of the dragged node is
+    /// used.
+    pub(crate) ghost: Option<UiNode>,
+}
+
+impl Draggable {
+    /// Creates a new draggable component with the given payload.
+    pub fn new<T: Send + Sync + 'static>(payload: T) -> Self {
+        NodeDraggable::new(payload).into()
+    }
+}
+
+impl From<NodeDraggable> for Draggable {
+    fn from(draggable: NodeDraggable) -> Self {
+        Self {
+            payload: draggable.payload,
+            ghost_opacity: draggable.ghost_opacity,
+            ghost: draggable.ghost.map(|ghost| *ghost),
+        }
+    }
+}
+
+/// A component for a node that accepts dragged nodes with certain payload
+/// types.
+///
+/// While an accepted payload is dragged, an [`Outline`] is drawn around the
+/// node. Any outline the node already has is restored when the drag ends.
+#[derive(Debug, Clone, Component)]
+pub struct DropTarget {
+    /// The data block defining the accepted payload types and highlight
+    /// colors.
+    pub target: NodeDropTarget,
+
+    /// The outline color currently applied to the node, if highlighted.
+    highlight: Option<Color>,
+
+    /// The outline the node had before it was highlighted.
+    restore: Option<Outline>,
+}
+
+impl DropTarget {
+    /// Creates a new drop target that accepts payloads of the given type.
+    pub fn new<T: 'static>() -> Self {
+        let mut target = NodeDropTarget::default();
+        target.accept::<T>();
+        target.into()
+    }
+
+    /// Returns true if the given payload may be dropped onto this node.
+    pub fn accepts(&self, payload: &DragPayload) -> bool {
+        self.target.accepts.contains(&payload.value_type_id())
+    }
+}
+
+impl From<NodeDropTarget> for DropTarget {
+    fn from(target: NodeDropTarget) -> Self {
+        Self {
+            target,
+            highlight: None,
+            restore: None,
+        }
+    }
+}
+
+/// An event that is sent when a node starts being dragged.
+#[derive(Debug, Clone, Event)]
+pub struct DragStarted {
+    /// The dragged node.
+    pub source: Entity,
+
+    /// The payload of the dragged node.
+    pub payload: DragPayload,
+}
+
+/// An event that is sent when a dragged node is dropped onto a drop target
+/// that accepts its payload.
+#[derive(Debug, Clone, Event)]
+pub struct Dropped {
+    /// The dragged node.
+    pub source: Entity,
+
+    /// The drop target the node was dropped onto.
+    pub target: Entity,
+
+    /// The payload of the dragged node.
+    pub payload: DragPayload,
+}
+
+/// An event that is sent when a drag ends without being dropped onto a drop
+/// target, either because it was released elsewhere or escape was pressed.
+#[derive(Debug, Clone, Event)]
+pub struct DragCancelled {
+    /// The dragged node.
+    pub source: Entity,
+
+    /// The payload of the dragged node.
+    pub payload: DragPayload,
+}
+
+/// A resource that stores the node that is currently being dragged.
+#[derive(Debug, Default, Resource)]
+pub struct DragState {
+    /// The current drag, if a draggable node is pressed.
+    drag: Option<ActiveDrag>,
+}
+
+impl DragState {
+    /// Returns true if a node is currently being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.active().is_some()
+    }
+
+    /// Gets the node that is currently being dragged.
+    pub fn source(&self) -> Option<Entity> {
+        self.active().map(|drag| drag.source)
+    }
+
+    /// Gets the payload of the node that is currently being dragged.
+    pub fn payload(&self) -> Option<&DragPayload> {
+        self.active().map(|drag| &drag.payload)
+    }
+
+    /// Gets the drop target the dragged node is currently over, if it accepts
+    /// the payload.
+    pub fn target(&self) -> Option<Entity> {
+        self.active().and_then(|drag| drag.target)
+    }
+
+    /// Gets the current drag, if it has started.
+    fn active(&self) -> Option<&ActiveDrag> {
+        self.drag.as_ref().filter(|drag| drag.ghost.is_some())
+    }
+}
+
+/// The state of a pressed draggable node.
+#[derive(Debug)]
+struct ActiveDrag {
+    /// The dragged node.
+    source: Entity,
+
+    /// The payload of the dragged node.
+    payload: DragPayload,
+
+    /// The position of the cursor when the node was pressed.
+    start: Vec2,
+
+    /// The position of the cursor relative to the top-left corner of the
+    /// node when it was pressed.
+    grab_offset: Vec2,
+
+    /// The ghost node following the cursor, once the drag has started.
+    ghost: Option<Entity>,
+
+    /// The accepting drop target under the cursor.
+    target: Option<Entity>,
+}
+
+/// A component for the node that follows the cursor while a node is dragged.
+#[derive(Debug, Clone, Copy, Component)]
+struct DragGhost {
+    /// The opacity to apply to the ghost and its descendants.
+    opacity: f32,
+
+    /// Whether the opacity has been applied.
+    faded: bool,
+}
+
+/// This plugin handles dragging nodes and dropping them onto drop targets.
+pub(crate) struct DragPlugin;
+impl Plugin for DragPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DragState>()
+            .add_event::<DragStarted>()
+            .add_event::<Dropped>()
+            .add_event::<DragCancelled>()
+            .add_systems(
+                Update,
+                (
+                    press_draggables,
+                    update_drag,
+                    highlight_drop_targets,
+                    fade_drag_ghosts,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Records the draggable node that is pressed, so that it may be dragged once
+/// the cursor moves far enough.
+fn press_draggables(
+    mouse: Res<ButtonInput<MouseButton>>,
+    ui_scale: Res<UiScale>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut state: ResMut<DragState>,
+    draggables: Query<(Entity, &Interaction, &Node, &GlobalTransform, &Draggable)>,
+) {
+    if state.drag.is_some() || !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Some(cursor) = cursor_position(&windows, &ui_scale) else {
+        return;
+    };
+
+    // Nested draggable nodes are all pressed, so the innermost is dragged.
+    let pressed = draggables
+        .iter()
+        .filter(|(_, interaction, _, _, _)| **interaction == Interaction::Pressed)
+        .max_by_key(|(_, _, node, _, _)| node.stack_index());
+
+    if let Some((source, _, node, transform, draggable)) = pressed {
+        state.drag = Some(ActiveDrag {
+            source,
+            payload: draggable.payload.clone(),
+            start: cursor,
+            grab_offset: cursor - node.logical_rect(transform).min,
+            ghost: None,
+            target: None,
+        });
+    }
+}
+
+/// Starts the pressed drag once the cursor moves far enough, moves the ghost
+/// with the cursor, and drops or cancels the drag when it ends.
+#[allow(clippy::too_many_arguments)]
+fn update_drag(
+    mut cmd: Commands,
+    asset_server: Res<AssetServer>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut back: ResMut<BackInput>,
+    ui_scale: Res<UiScale>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut state: ResMut<DragState>,
+    mut started: EventWriter<DragStarted>,
+    mut dropped: EventWriter<Dropped>,
+    mut cancelled: EventWriter<DragCancelled>,
+    draggables: Query<(&Node, Option<&BackgroundColor>, &Draggable)>,
+    targets: Query<(
+        Entity,
+        &Node,
+        &GlobalTransform,
+        &ViewVisibility,
+        &DropTarget,
+    )>,
+    mut ghosts: Query<&mut Style, With<DragGhost>>,
+) {
+    let Some(drag) = &mut state.drag else {
+        return;
+    };
+
+    let released = !mouse.pressed(MouseButton::Left);
+    let source = draggables.get(drag.source).ok();
+
+    if drag.ghost.is_none() {
+        let cursor = cursor_position(&windows, &ui_scale);
+        let moved = cursor.is_some_and(|cursor| cursor.distance(drag.start) >= DRAG_THRESHOLD);
+
+        let (Some(cursor), Some((node, background, draggable))) =
+            (cursor, source.filter(|_| moved && !released))
+        else {
+            if released || source.is_none() {
+                state.drag = None;
+            }
+            return;
+        };
+
+        let mut ghost = NodeBundleBuilder::default();
+        ghost.insert((
+            DragGhost {
+                opacity: draggable.ghost_opacity,
+                faded: false,
+            },
+            UiLayer::DragGhost,
+        ));
+
+        let style = ghost.get_style_mut();
+        style.position_type = PositionType::Absolute;
+        style.left = Val::Px(cursor.x - drag.grab_offset.x);
+        style.top = Val::Px(cursor.y - drag.grab_offset.y);
+        style.width = Val::Px(node.size().x);
+        style.height = Val::Px(node.size().y);
+
+        match &draggable.ghost {
+            Some(content) => ghost.set_children(vec![content.clone()]),
+            None => ghost.insert(background.copied().unwrap_or_default()),
+        }
+
+        drag.ghost = Some(ghost.build(&mut cmd, &asset_server));
+        started.send(DragStarted {
+            source: drag.source,
+            payload: drag.payload.clone(),
+        });
+    }
+
+    let cursor = cursor_position(&windows, &ui_scale);
+    if let Some(cursor) = cursor {
+        drag.target = targets
+            .iter()
+            .filter(|(entity, _, _, visibility, target)| {
+                *entity != drag.source && visibility.get() && target.accepts(&drag.payload)
+            })
+            .filter(|(_, node, transform, _, _)| node.logical_rect(transform).contains(cursor))
+            .max_by_key(|(_, node, _, _, _)| node.stack_index())
+            .map(|(entity, _, _, _, _)| entity);
+
+        if let Some(mut style) = drag.ghost.and_then(|ghost| ghosts.get_mut(ghost).ok()) {
+            let pos = cursor - drag.grab_offset;
+            style.left = Val::Px(pos.x);
+            style.top = Val::Px(pos.y);
+        }
+    }
+
+    let escaped = keyboard.just_pressed(KeyCode::Escape);
+    let cancel = escaped || source.is_none();
+    if !released && !cancel {
+        return;
+    }
+
+    if escaped {
+        back.consume();
+    }
+
+    let Some(drag) = state.drag.take() else {
+        return;
+    };
+
+    if let Some(ghost) = drag.ghost {
+        cmd.entity(ghost).despawn_recursive();
+    }
+
+    match drag.target.filter(|_| !cancel) {
+        Some(target) => {
+            dropped.send(Dropped {
+                source: drag.source,
+                target,
+                payload: drag.payload,
+            });
+        }
+        None => {
+            cancelled.send(DragCancelled {
+                source: drag.source,
+                payload: drag.payload,
+            });
+        }
+    }
+}
+
+/// Outlines the drop targets that accept the payload of the dragged node,
+/// using a separate color for the target under the cursor.
+fn highlight_drop_targets(
+    mut cmd: Commands,
+    state: Res<DragState>,
+    mut targets: Query<(Entity, &mut DropTarget, Option<&Outline>)>,
+) {
+    for (entity, mut target, outline) in &mut targets {
+        let highlight = match state.payload() {
+            Some(payload) if state.source() == Some(entity) || !target.accepts(payload) => None,
+            Some(_) if state.target() == Some(entity) => Some(target.target.hover_color),
+            Some(_) => Some(target.target.highlight_color),
+            None => None,
+        };
+
+        if target.highlight == highlight {
+            continue;
+        }
+
+        if target.highlight.is_none() {
+            target.restore = outline.copied();
+        }
+
+        target.highlight = highlight;
+        match highlight {
+            Some(color) => {
+                let width = Val::Px(HIGHLIGHT_WIDTH);
+                cmd.entity(entity)
+                    .insert(Outline::new(width, Val::ZERO, color));
+            }
+            None => match target.restore.take() {
+                Some(outline) => {
+                    cmd.entity(entity).insert(outline);
+                }
+                None => {
+                    cmd.entity(entity).remove::<Outline>();
+                }
+            },
+        }
+    }
+}
+
+/// Fades the ghost of the dragged node and its descendants once they have been
+/// spawned, and stops them from blocking interaction with the nodes below.
+fn fade_drag_ghosts(
+    mut ghosts: Query<(Entity, &mut DragGhost)>,
+    children: Query<&Children>,
+    mut colors: Query<(Option<&mut BackgroundColor>, Option<&mut BorderColor>)>,
+    mut texts: Query<&mut Text>,
+    mut focus_policies: Query<&mut FocusPolicy>,
+) {
+    for (ghost_id, mut ghost) in &mut ghosts {
+        if ghost.faded {
+            continue;
+        }
+
+        ghost.faded = true;
+        let opacity = ghost.opacity;
+        let fade = |color: &mut Color| {
+            color.set_a(color.a() * opacity);
+        };
+
+        let descendants = std::iter::once(ghost_id).chain(children.iter_descendants(ghost_id));
+        for entity in descendants {
+            if let Ok((background, border)) = colors.get_mut(entity) {
+                if let Some(mut background) = background {
+                    fade(&mut background.0);
+                }
+
+                if let Some(mut border) = border {
+                    fade(&mut border.0);
+                }
+            }
+
+            if let Ok(mut text) = texts.get_mut(entity) {
+                text.sections
+                    .iter_mut()
+                    .for_each(|section| fade(&mut section.style.color));
+            }
+
+            if let Ok(mut focus) = focus_policies.get_mut(entity) {
+                *focus = FocusPolicy::Pass;
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Contains the components and systems used to track which node currently
+//! has keyboard and gamepad focus.
+
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+/// A resource that stores the node that currently receives keyboard and
+/// gamepad input.
+///
+/// Game code may freely read or write this resource to move focus between
+/// nodes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct UiFocus {
+    /// The focused node, if any.
+    pub entity: Option<Entity>,
+}
+
+impl UiFocus {
+    /// Returns true if the given entity currently has focus.
+    pub fn is_focused(&self, entity: Entity) -> bool {
+        self.entity == Some(entity)
+    }
+}
+
+/// A resource that records whether the escape key or the gamepad back button
+/// has been used by a widget during the current frame, such as to cancel text
+/// entry or a drag.
+///
+/// Dismissible modals are only closed by these inputs when they have not been
+/// used by a widget.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct BackInput {
+    /// Whether the back input has been used this frame.
+    consumed: bool,
+}
+
+impl BackInput {
+    /// Marks the back input of the current frame as used.
+    pub fn consume(&mut self) {
+        self.consumed = true;
+    }
+
+    /// Returns true if the back input of the current frame has been used.
+    pub fn is_consumed(&self) -> bool {
+        self.consumed
+    }
+}
+
+/// A marker component for nodes that receive focus when clicked.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct Focusable;
+
+/// A marker component for nodes that keep the current focus when clicked,
+/// instead of clearing it, such as the keys of an on-screen keyboard.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct KeepsFocus;
+
+/// This plugin tracks which node currently has focus.
+pub(crate) struct FocusPlugin;
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiFocus>()
+            .init_resource::<BackInput>()
+            .register_type::<UiFocus>()
+            .register_type::<Focusable>()
+            .register_type::<KeepsFocus>()
+            .add_systems(First, reset_back_input)
+            .add_systems(PreUpdate, focus_on_click.after(UiSystem::Focus));
+    }
+}
+
+/// Moves focus to the top-most focusable node that was clicked this frame.
+///
+/// Clicking on a node that keeps focus leaves the focus unchanged, while
+/// clicking on anything else clears the current focus.
+fn focus_on_click(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut focus: ResMut<UiFocus>,
+    focusables: Query<(Entity, &Node, &Interaction), With<Focusable>>,
+    keeps_focus: Query<&Interaction, With<KeepsFocus>>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let clicked = focusables
+        .iter()
+        .filter(|(_, _, interaction)| **interaction == Interaction::Pressed)
+        .max_by_key(|(_, node, _)| node.stack_index())
+        .map(|(entity, _, _)| entity);
+
+    let kept = keeps_focus
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed);
+    if clicked.is_none() && kept {
+        return;
+    }
+
+    focus.set_if_neq(UiFocus { entity: clicked });
+}
+
+/// Clears the used back input at the start of each frame.
+fn reset_back_input(mut back: ResMut<BackInput>) {
+    back.set_if_neq(BackInput::default());
+}
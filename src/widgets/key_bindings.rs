@@ -0,0 +1,513 @@
+//! Contains the components, events, and systems used to remap the inputs bound
+//! to actions.
+
+use std::any::TypeId;
+use std::fmt;
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::prelude::{
+    BackInput,
+    DataBlock,
+    Focusable,
+    NodeBundleBuilder,
+    NodeBundleType,
+    NodeKeyBindings,
+};
+
+/// An input that may be bound to an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputBinding {
+    /// A keyboard key.
+    Key(KeyCode),
+
+    /// A mouse button.
+    Mouse(MouseButton),
+
+    /// A button on any gamepad.
+    Gamepad(GamepadButtonType),
+}
+
+impl fmt::Display for InputBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputBinding::Key(key) => {
+                let name = format!("{key:?}");
+                let name = name
+                    .strip_prefix("Key")
+                    .or_else(|| name.strip_prefix("Digit"))
+                    .unwrap_or(&name);
+                write!(f, "{name}")
+            }
+            InputBinding::Mouse(MouseButton::Other(button)) => write!(f, "Mouse {button}"),
+            InputBinding::Mouse(button) => write!(f, "Mouse {button:?}"),
+            InputBinding::Gamepad(button) => write!(f, "Gamepad {button:?}"),
+        }
+    }
+}
+
+/// A trait for game resources that store the inputs bound to each action.
+///
+/// The type must be registered with a [`BindingSourcePlugin`], after which key
+/// binding lists built for this type display and edit its bindings.
+pub trait BindingSource: Resource {
+    /// Gets the input bound to the given slot of an action, if any.
+    fn binding(&self, action: &str, slot: usize) -> Option<InputBinding>;
+
+    /// Binds an input to the given slot of an action, or removes the binding
+    /// if `None`.
+    fn set_binding(&mut self, action: &str, slot: usize, binding: Option<InputBinding>);
+}
+
+/// A component that stores the state of a key binding list.
+#[derive(Debug, Clone, Component)]
+pub struct KeyBindingList {
+    /// The type of the bindings resource this list edits.
+    source: Option<TypeId>,
+
+    /// The identifiers of the listed actions.
+    actions: Vec<String>,
+
+    /// The number of bindings each action may have.
+    slots: usize,
+
+    /// The binding button that is waiting for an input, if any.
+    capturing: Option<BindingCapture>,
+
+    /// The text nodes of the binding buttons, in order of action, then slot.
+    labels: Vec<Entity>,
+
+    /// The text displayed while waiting for an input.
+    capture_text: String,
+
+    /// The text displayed for slots with no binding.
+    unbound_text: String,
+
+    /// The text color of the binding buttons.
+    text_color: Color,
+
+    /// The text color of bindings used by more than one action.
+    conflict_color: Color,
+
+    /// The key that cancels capturing an input.
+    cancel_key: KeyCode,
+}
+
+impl KeyBindingList {
+    /// Returns true if the list is waiting for an input to bind.
+    pub fn is_capturing(&self) -> bool {
+        self.capturing.is_some()
+    }
+
+    /// Stops waiting for an input to bind, if the list is waiting.
+    pub fn cancel_capture(&mut self) {
+        if self.capturing.is_some() {
+            self.capturing = None;
+        }
+    }
+
+    /// Gets every action slot, other than the given one, that the binding is
+    /// also bound to.
+    fn conflicts<T: BindingSource>(
+        &self,
+        store: &T,
+        binding: InputBinding,
+        except: (usize, usize),
+    ) -> Vec<(String, usize)> {
+        let mut conflicts = Vec::new();
+        for (action, id) in self.actions.iter().enumerate() {
+            for slot in 0 .. self.slots {
+                if (action, slot) != except && store.binding(id, slot) == Some(binding) {
+                    conflicts.push((id.clone(), slot));
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+/// The binding button of a key binding list that is waiting for an input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BindingCapture {
+    /// The index of the action.
+    action: usize,
+
+    /// The binding slot of the action.
+    slot: usize,
+
+    /// Whether inputs are accepted. This is set one frame after the capture
+    /// starts, so that the click that started it is not bound.
+    armed: bool,
+}
+
+/// A component for a button that rebinds a slot of an action when clicked.
+#[derive(Debug, Clone, Copy, Component)]
+struct BindingButton {
+    /// The key binding list this button belongs to.
+    list: Entity,
+
+    /// The index of the action.
+    action: usize,
+
+    /// The binding slot of the action.
+    slot: usize,
+}
+
+/// An event that is sent when a key binding list changes a binding.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct BindingChanged {
+    /// The key binding list entity.
+    pub list: Entity,
+
+    /// The identifier of the action.
+    pub action: String,
+
+    /// The binding slot of the action.
+    pub slot: usize,
+
+    /// The new binding, or `None` if the binding was removed.
+    pub binding: Option<InputBinding>,
+
+    /// The other action slots the new binding is also bound to.
+    pub conflicts: Vec<(String, usize)>,
+}
+
+/// This plugin handles starting input capture on key binding lists.
+pub(crate) struct KeyBindingsPlugin;
+impl Plugin for KeyBindingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<BindingChanged>()
+            .add_systems(Update, start_binding_capture);
+    }
+}
+
+/// This plugin displays and edits the bindings stored in the given
+/// [`BindingSource`] resource, for key binding lists built for this type.
+pub struct BindingSourcePlugin<T: BindingSource>(PhantomData<T>);
+
+impl<T: BindingSource> Default for BindingSourcePlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: BindingSource> Plugin for BindingSourcePlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                clear_bindings_on_right_click::<T>,
+                capture_bindings::<T>,
+                update_binding_labels::<T>,
+            )
+                .chain()
+                .after(start_binding_capture)
+                .run_if(resource_exists::<T>),
+        );
+    }
+}
+
+/// Consumes the given container node builder and creates a key binding list,
+/// along with a row for each action.
+///
+/// Returns the entity of the key binding list.
+pub(crate) fn build_key_bindings(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut container: NodeBundleBuilder,
+    bindings: NodeKeyBindings,
+) -> Entity {
+    let spacing = Val::Px(bindings.spacing);
+
+    let style = container.get_style_mut();
+    style.flex_direction = FlexDirection::Column;
+    style.row_gap = spacing;
+    let list_id = container.build(cmd, asset_server);
+
+    let mut text = bindings.text.clone();
+    text.sections.truncate(1);
+
+    let text_with = |value: &str| {
+        let mut text = text.clone();
+        if let Some(section) = text.sections.first_mut() {
+            section.text = value.to_string();
+        }
+        text
+    };
+
+    let mut labels = Vec::new();
+    for (action, def) in bindings.actions.iter().enumerate() {
+        let mut row = NodeBundleBuilder::default();
+        row.set_parent(Some(list_id));
+
+        let style = row.get_style_mut();
+        style.align_items = AlignItems::Center;
+        style.column_gap = spacing;
+        style.height = Val::Px(bindings.row_height);
+        let row_id = row.build(cmd, asset_server);
+
+        let mut label = NodeBundleBuilder::default();
+        label.set_parent(Some(row_id));
+
+        let style = label.get_style_mut();
+        style.flex_shrink = 0.0;
+        style.width = bindings.label_width;
+        let label_id = label.build(cmd, asset_server);
+
+        let mut label_text = NodeBundleBuilder::default();
+        label_text.set_parent(Some(label_id));
+        text_with(&def.label).apply_to_node(&mut label_text, asset_server);
+        label_text.build(cmd, asset_server);
+
+        for slot in 0 .. bindings.slots {
+            let mut button = NodeBundleBuilder::default();
+            button.set_parent(Some(row_id));
+            button.bundle_type(NodeBundleType::Button);
+            bindings
+                .button_background
+                .clone()
+                .apply_to_node(&mut button, asset_server);
+            button.insert((
+                BindingButton {
+                    list: list_id,
+                    action,
+                    slot,
+                },
+                Focusable,
+            ));
+
+            let style = button.get_style_mut();
+            style.flex_shrink = 0.0;
+            style.width = Val::Px(bindings.button_width);
+            style.height = Val::Percent(100.0);
+            style.justify_content = JustifyContent::Center;
+            style.align_items = AlignItems::Center;
+            let button_id = button.build(cmd, asset_server);
+
+            let mut button_text = NodeBundleBuilder::default();
+            button_text.set_parent(Some(button_id));
+            text_with(&bindings.unbound_text).apply_to_node(&mut button_text, asset_server);
+            labels.push(button_text.build(cmd, asset_server));
+        }
+    }
+
+    cmd.entity(list_id).insert(KeyBindingList {
+        source: bindings.source,
+        actions: bindings
+            .actions
+            .into_iter()
+            .map(|action| action.id)
+            .collect(),
+        slots: bindings.slots,
+        capturing: None,
+        labels,
+        capture_text: bindings.capture_text,
+        unbound_text: bindings.unbound_text,
+        text_color: text.sections.first().map_or(Color::WHITE, |s| s.color),
+        conflict_color: bindings.conflict_color,
+        cancel_key: bindings.cancel_key,
+    });
+
+    list_id
+}
+
+/// Starts waiting for an input when a binding button is clicked.
+///
+/// A list that is already waiting ignores the click, so that the left mouse
+/// button may be bound.
+fn start_binding_capture(
+    buttons: Query<(&Interaction, &BindingButton), Changed<Interaction>>,
+    mut lists: Query<&mut KeyBindingList>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut list) = lists.get_mut(button.list) else {
+            continue;
+        };
+
+        if list.capturing.is_some_and(|capture| capture.armed) {
+            continue;
+        }
+
+        list.capturing = Some(BindingCapture {
+            action: button.action,
+            slot: button.slot,
+            armed: false,
+        });
+    }
+}
+
+/// Removes the binding of a binding button when it is right-clicked.
+fn clear_bindings_on_right_click<T: BindingSource>(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut store: ResMut<T>,
+    mut changed: EventWriter<BindingChanged>,
+    buttons: Query<(&Interaction, &BindingButton)>,
+    lists: Query<&KeyBindingList>,
+) {
+    if !mouse.just_pressed(MouseButton::Right) {
+        return;
+    }
+
+    for (interaction, button) in &buttons {
+        if *interaction == Interaction::None {
+            continue;
+        }
+
+        let Ok(list) = lists.get(button.list) else {
+            continue;
+        };
+
+        if list.source != Some(TypeId::of::<T>()) || list.is_capturing() {
+            continue;
+        }
+
+        let action = &list.actions[button.action];
+        if store.binding(action, button.slot).is_none() {
+            continue;
+        }
+
+        store.set_binding(action, button.slot, None);
+        changed.send(BindingChanged {
+            list: button.list,
+            action: action.clone(),
+            slot: button.slot,
+            binding: None,
+            conflicts: Vec::new(),
+        });
+    }
+}
+
+/// Binds the first input pressed while a key binding list is waiting for an
+/// input, or stops waiting if the cancel key is pressed.
+fn capture_bindings<T: BindingSource>(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepad: Res<ButtonInput<GamepadButton>>,
+    mut back: ResMut<BackInput>,
+    mut store: ResMut<T>,
+    mut changed: EventWriter<BindingChanged>,
+    mut lists: Query<(Entity, &mut KeyBindingList)>,
+) {
+    for (list_id, mut list) in &mut lists {
+        let Some(capture) = list.capturing else {
+            continue;
+        };
+
+        if list.source != Some(TypeId::of::<T>()) {
+            continue;
+        }
+
+        if !capture.armed {
+            list.capturing = Some(BindingCapture {
+                armed: true,
+                ..capture
+            });
+            continue;
+        }
+
+        // Any input is captured, so escape and the gamepad back button must
+        // not also close a modal.
+        if keyboard.just_pressed(list.cancel_key) {
+            back.consume();
+            list.capturing = None;
+            continue;
+        }
+
+        let binding = keyboard
+            .get_just_pressed()
+            .next()
+            .map(|key| InputBinding::Key(*key))
+            .or_else(|| {
+                mouse
+                    .get_just_pressed()
+                    .next()
+                    .map(|b| InputBinding::Mouse(*b))
+            })
+            .or_else(|| {
+                gamepad
+                    .get_just_pressed()
+                    .next()
+                    .map(|b| InputBinding::Gamepad(b.button_type))
+            });
+
+        let Some(binding) = binding else {
+            continue;
+        };
+
+        back.consume();
+        let action = list.actions[capture.action].clone();
+        let conflicts = list.conflicts(&*store, binding, (capture.action, capture.slot));
+
+        list.capturing = None;
+        store.set_binding(&action, capture.slot, Some(binding));
+        changed.send(BindingChanged {
+            list: list_id,
+            action,
+            slot: capture.slot,
+            binding: Some(binding),
+            conflicts,
+        });
+    }
+}
+
+/// Updates the text of the binding buttons of each key binding list when the
+/// bindings or the list have changed, highlighting conflicting bindings.
+fn update_binding_labels<T: BindingSource>(
+    store: Res<T>,
+    lists: Query<Ref<KeyBindingList>>,
+    mut texts: Query<&mut Text>,
+) {
+    for list in &lists {
+        if list.source != Some(TypeId::of::<T>()) {
+            continue;
+        }
+
+        if !store.is_changed() && !list.is_changed() {
+            continue;
+        }
+
+        for (action, id) in list.actions.iter().enumerate() {
+            for slot in 0 .. list.slots {
+                let Some(mut text) = list
+                    .labels
+                    .get(action * list.slots + slot)
+                    .and_then(|label| texts.get_mut(*label).ok())
+                else {
+                    continue;
+                };
+
+                let binding = store.binding(id, slot);
+                let capturing = list
+                    .capturing
+                    .is_some_and(|c| c.action == action && c.slot == slot);
+
+                let (value, color) = match binding {
+                    _ if capturing => (list.capture_text.clone(), list.text_color),
+                    Some(binding) => {
+                        let conflict = !list.conflicts(&*store, binding, (action, slot)).is_empty();
+                        let color = match conflict {
+                            true => list.conflict_color,
+                            false => list.text_color,
+                        };
+                        (binding.to_string(), color)
+                    }
+                    None => (list.unbound_text.clone(), list.text_color),
+                };
+
+                if let Some(section) = text.sections.first_mut() {
+                    if section.value != value {
+                        section.value = value;
+                    }
+                    if section.style.color != color {
+                        section.style.color = color;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,335 @@
+//! Contains the components, events, and systems that allow sections of text to
+//! be hovered and clicked as links.
+
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+use bevy::transform::TransformSystem;
+use bevy::ui::UiSystem;
+use bevy::ui::widget::text_system;
+use bevy::window::PrimaryWindow;
+
+use super::cursor_position;
+
+/// The space, in logical pixels, added around the glyphs of a link when hit
+/// testing, so that the gaps between letters and lines are also hovered.
+const LINK_HIT_PADDING: f32 = 2.0;
+
+/// A single link section of a text node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextLink {
+    /// The index of the section within the [`Text`] component.
+    pub section: usize,
+
+    /// The identifier of the link, sent with the click event.
+    pub id: String,
+
+    /// The color of the link while not hovered.
+    pub color: Color,
+
+    /// The color of the link while hovered.
+    pub hover_color: Color,
+
+    /// Whether the link is underlined while hovered.
+    pub underline: bool,
+}
+
+/// A component that stores the links of a text node, and which of them is
+/// hovered.
+///
+/// Links are only hovered while the [`Interaction`] of the text node is not
+/// [`Interaction::None`], so nodes above the text block its links.
+#[derive(Debug, Clone, Component)]
+pub struct TextLinks {
+    /// The links of the text node.
+    links: Vec<TextLink>,
+
+    /// The index of the hovered link, if any.
+    hovered: Option<usize>,
+
+    /// The nodes that underline the hovered link.
+    underlines: Vec<Entity>,
+}
+
+impl TextLinks {
+    /// Creates a new link component with the given links, none of which are
+    /// hovered.
+    pub fn new(links: Vec<TextLink>) -> Self {
+        Self {
+            links,
+            hovered: None,
+            underlines: Vec::new(),
+        }
+    }
+
+    /// Gets the links of the text node.
+    pub fn links(&self) -> &[TextLink] {
+        &self.links
+    }
+
+    /// Gets the currently hovered link, if any.
+    pub fn hovered(&self) -> Option<&TextLink> {
+        self.hovered.and_then(|index| self.links.get(index))
+    }
+}
+
+/// A component for a node that underlines the hovered link of a text node.
+#[derive(Debug, Clone, Copy, Component)]
+struct LinkUnderline {
+    /// The text node this underline belongs to.
+    text: Entity,
+}
+
+/// An event that is sent when a link within a text node is clicked.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct LinkClicked {
+    /// The text node that contains the link.
+    pub text: Entity,
+
+    /// The identifier of the link.
+    pub id: String,
+}
+
+/// This plugin handles hovering and clicking the links of text nodes.
+pub(crate) struct LinkPlugin;
+impl Plugin for LinkPlugin {
+    fn build(&self, app: &mut App) {
+        // The links are hit tested against the glyphs of the current frame,
+        // which are only known once the text has been laid out.
+        app.add_event::<LinkClicked>().add_systems(
+            PostUpdate,
+            (hover_text_links, click_text_links, style_text_links)
+                .chain()
+                .after(UiSystem::Layout)
+                .after(text_system)
+                .after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// Gets the hit rectangles of each line of the given section of a text node,
+/// in logical pixels relative to the top-left corner of the node.
+///
+/// Glyph positions are stored in physical pixels, so they are divided by the
+/// given scale factor.
+fn section_lines(layout: &TextLayoutInfo, section: usize, scale_factor: f32) -> Vec<Rect> {
+    let mut lines: Vec<Rect> = Vec::new();
+    let mut last_x = f32::NEG_INFINITY;
+
+    for glyph in layout
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.section_index == section)
+    {
+        let rect = Rect::from_center_size(glyph.position, glyph.size);
+        let rect = Rect::from_corners(rect.min / scale_factor, rect.max / scale_factor);
+
+        // Glyphs are laid out from left to right, so moving left starts a new
+        // line.
+        match lines.last_mut() {
+            Some(line) if rect.min.x >= last_x => *line = line.union(rect),
+            _ => lines.push(rect),
+        }
+        last_x = rect.min.x;
+    }
+
+    lines
+}
+
+/// Finds the link under the cursor for each text node with links, using the
+/// glyph positions from the most recent text layout.
+fn hover_text_links(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    mut texts: Query<(
+        &mut TextLinks,
+        &TextLayoutInfo,
+        &Node,
+        &GlobalTransform,
+        &ViewVisibility,
+        &Interaction,
+    )>,
+) {
+    let cursor = cursor_position(&windows, &ui_scale);
+    let scale_factor = windows
+        .get_single()
+        .map_or(1.0, |window| window.scale_factor())
+        * ui_scale.0;
+
+    for (mut links, layout, node, transform, visibility, interaction) in &mut texts {
+        let top_left = transform.translation().truncate() - node.size() / 2.0;
+        let local = cursor
+            .filter(|_| visibility.get() && *interaction != Interaction::None)
+            .map(|cursor| cursor - top_left);
+
+        let hovered = local.and_then(|local| {
+            links.links.iter().position(|link| {
+                section_lines(layout, link.section, scale_factor)
+                    .into_iter()
+                    .any(|line| line.inset(LINK_HIT_PADDING).contains(local))
+            })
+        });
+
+        if links.hovered != hovered {
+            links.hovered = hovered;
+        }
+    }
+}
+
+/// Sends an event when the left mouse button is pressed over a link.
+fn click_text_links(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut clicked: EventWriter<LinkClicked>,
+    texts: Query<(Entity, &TextLinks)>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    for (text, links) in &texts {
+        if let Some(link) = links.hovered() {
+            clicked.send(LinkClicked {
+                text,
+                id: link.id.clone(),
+            });
+        }
+    }
+}
+
+/// The components of a text node whose links are styled.
+type StyledLinkText = (
+    Entity,
+    &'static mut TextLinks,
+    &'static mut Text,
+    &'static TextLayoutInfo,
+    &'static Node,
+    &'static GlobalTransform,
+    Option<&'static Parent>,
+);
+
+/// Colors the hovered link of each text node, and underlines it by placing
+/// nodes beneath each of its lines.
+///
+/// Text nodes cannot contain children, so the underlines are absolutely
+/// positioned children of the node that contains the text.
+fn style_text_links(
+    mut cmd: Commands,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    mut texts: Query<StyledLinkText>,
+    containers: Query<(&Node, &GlobalTransform)>,
+    mut underlines: Query<(Entity, &LinkUnderline, &mut Style)>,
+) {
+    // Underlines are removed when their text node is despawned, or when its
+    // links are replaced.
+    for (underline, owner, _) in &underlines {
+        let owned = texts
+            .get(owner.text)
+            .is_ok_and(|(_, links, ..)| links.underlines.contains(&underline));
+        if !owned {
+            cmd.entity(underline).despawn_recursive();
+        }
+    }
+
+    let scale_factor = windows
+        .get_single()
+        .map_or(1.0, |window| window.scale_factor())
+        * ui_scale.0;
+
+    for (text_id, mut links, mut text, layout, node, transform, parent) in &mut texts {
+        if links.is_changed() {
+            // The underlines are respawned, as the hovered link may have a
+            // different color.
+            for underline in links.bypass_change_detection().underlines.drain(..) {
+                cmd.entity(underline).despawn_recursive();
+            }
+
+            for (index, link) in links.links.iter().enumerate() {
+                let color = match links.hovered == Some(index) {
+                    true => link.hover_color,
+                    false => link.color,
+                };
+
+                if let Some(section) = text.sections.get_mut(link.section) {
+                    if section.style.color != color {
+                        section.style.color = color;
+                    }
+                }
+            }
+        }
+
+        let container = parent.and_then(|parent| {
+            containers
+                .get(parent.get())
+                .ok()
+                .map(|container| (parent.get(), container))
+        });
+
+        let hovered = links.hovered().filter(|link| link.underline).cloned();
+        let (Some(link), Some((container_id, (container, container_transform)))) =
+            (hovered, container)
+        else {
+            if !links.underlines.is_empty() {
+                for underline in links.bypass_change_detection().underlines.drain(..) {
+                    cmd.entity(underline).despawn_recursive();
+                }
+            }
+            continue;
+        };
+
+        let thickness = text
+            .sections
+            .get(link.section)
+            .map_or(1.0, |section| (section.style.font_size / 14.0).max(1.0));
+
+        let container_top_left =
+            container_transform.translation().truncate() - container.size() / 2.0;
+        let offset = transform.translation().truncate() - node.size() / 2.0 - container_top_left;
+        let lines = section_lines(layout, link.section, scale_factor);
+
+        let links = links.bypass_change_detection();
+        while links.underlines.len() > lines.len() {
+            if let Some(underline) = links.underlines.pop() {
+                cmd.entity(underline).despawn_recursive();
+            }
+        }
+
+        for (index, line) in lines.into_iter().enumerate() {
+            let style = Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(offset.x + line.min.x),
+                top: Val::Px(offset.y + line.max.y),
+                width: Val::Px(line.width()),
+                height: Val::Px(thickness),
+                ..default()
+            };
+
+            match links.underlines.get(index) {
+                Some(underline) => {
+                    if let Ok((_, _, mut current)) = underlines.get_mut(*underline) {
+                        if current.left != style.left
+                            || current.top != style.top
+                            || current.width != style.width
+                            || current.height != style.height
+                        {
+                            *current = style;
+                        }
+                    }
+                }
+                None => {
+                    let underline = cmd
+                        .spawn((
+                            NodeBundle {
+                                style,
+                                background_color: link.hover_color.into(),
+                                ..default()
+                            },
+                            LinkUnderline { text: text_id },
+                        ))
+                        .set_parent(container_id)
+                        .id();
+                    links.underlines.push(underline);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,336 @@
+//! Contains the components and systems used to append lines to log views.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use super::{ScrollContent, ScrollPosition, ScrollView, update_scroll_bounds};
+use crate::nodes::build_scroll_view;
+use crate::prelude::{
+    DataBlock,
+    NodeBundleBuilder,
+    NodeLogView,
+    NodeText,
+    NodeTextSection,
+    TextLinks,
+};
+
+/// The distance, in logical pixels, from the bottom of a log view within which
+/// it is treated as scrolled to the bottom.
+const BOTTOM_TOLERANCE: f32 = 1.0;
+
+/// A component that stores the lines and settings of a log view.
+///
+/// Game code appends lines with [`LogView::push`] and the related methods,
+/// and may write to the public fields to change how lines are displayed.
+#[derive(Debug, Clone, Component)]
+pub struct LogView {
+    /// The largest number of lines kept in the history. Once reached, the
+    /// oldest line is replaced by each new line.
+    pub max_lines: usize,
+
+    /// The style of plain lines appended to the log.
+    pub line_text: NodeText,
+
+    /// The space below each line, in logical pixels.
+    pub line_spacing: f32,
+
+    /// Whether each line is prefixed with the time it was appended.
+    pub show_timestamps: bool,
+
+    /// The color of the timestamps.
+    pub timestamp_color: Color,
+
+    /// If set, lines fade out once they are older than the given number of
+    /// seconds, unless the log view is hovered.
+    pub fade_after: Option<f32>,
+
+    /// The time, in seconds, old lines take to fade out.
+    pub fade_time: f32,
+
+    /// Whether the log view scrolls to the bottom as lines are appended. This
+    /// is cleared when the view is scrolled up, and set again once it is
+    /// scrolled back to the bottom.
+    pub follow: bool,
+
+    /// The lines that have been appended but not yet displayed.
+    pending: Vec<NodeText>,
+
+    /// Whether the displayed lines should be removed.
+    clear: bool,
+
+    /// The displayed line nodes, from oldest to newest.
+    lines: VecDeque<Entity>,
+
+    /// The vertical scroll offset of the view at the end of the last frame.
+    last_offset: f32,
+}
+
+impl LogView {
+    /// Appends a plain line using the style of the log view.
+    pub fn push<S: Into<String>>(&mut self, message: S) {
+        let mut text = self.line_text.clone();
+        text.sections.truncate(1);
+        match text.sections.first_mut() {
+            Some(section) => section.text = message.into(),
+            None => text.sections.push(NodeTextSection {
+                text: message.into(),
+                ..default()
+            }),
+        }
+
+        self.pending.push(text);
+    }
+
+    /// Appends a plain line with the given color, using the style of the log
+    /// view.
+    pub fn push_colored<S: Into<String>>(&mut self, message: S, color: Color) {
+        self.push(message);
+        if let Some(section) = self
+            .pending
+            .last_mut()
+            .and_then(|text| text.sections.first_mut())
+        {
+            section.color = color;
+        }
+    }
+
+    /// Appends a line of rich text, keeping the style of each section.
+    pub fn push_text<T: Into<NodeText>>(&mut self, text: T) {
+        self.pending.push(text.into());
+    }
+
+    /// Removes all lines from the log view.
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.clear = true;
+    }
+
+    /// Gets the number of lines that are displayed.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+}
+
+/// A component for a single line of a log view.
+#[derive(Debug, Clone, Component)]
+struct LogEntry {
+    /// The time the line was appended, in seconds since startup.
+    time: f32,
+
+    /// The original color of each section of the line.
+    colors: Vec<Color>,
+
+    /// The opacity currently applied to the line.
+    alpha: f32,
+}
+
+/// This plugin handles appending lines to log views.
+pub(crate) struct LogViewPlugin;
+impl Plugin for LogViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (append_log_lines, follow_log_views, fade_log_lines)
+                .chain()
+                .after(update_scroll_bounds),
+        );
+    }
+}
+
+/// Consumes the given viewport node builder and creates a log view, along with
+/// its scrolled contents and scrollbars.
+///
+/// Returns the entity of the log view.
+pub(crate) fn build_log_view(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    view_node: NodeBundleBuilder,
+    log: NodeLogView,
+) -> Entity {
+    let view_id = build_scroll_view(cmd, asset_server, view_node, log.scroll, default());
+
+    let mut line_text = log.line_text;
+    line_text.sections.truncate(1);
+
+    cmd.entity(view_id).insert(LogView {
+        max_lines: log.max_lines,
+        line_text,
+        line_spacing: log.line_spacing,
+        show_timestamps: log.show_timestamps,
+        timestamp_color: log.timestamp_color,
+        fade_after: log.fade_after,
+        fade_time: log.fade_time,
+        follow: true,
+        pending: Vec::new(),
+        clear: false,
+        lines: VecDeque::new(),
+        last_offset: 0.0,
+    });
+
+    view_id
+}
+
+/// Formats the given number of seconds as a timestamp.
+fn format_timestamp(seconds: f32) -> String {
+    let seconds = seconds as u32;
+    format!("[{:02}:{:02}] ", seconds / 60, seconds % 60)
+}
+
+/// Displays the appended lines of each log view, reusing the nodes of the
+/// oldest lines once the history is full.
+fn append_log_lines(
+    mut cmd: Commands,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    mut views: Query<(&mut LogView, &Children)>,
+    contents: Query<(), With<ScrollContent>>,
+    mut entries: Query<(&mut LogEntry, &mut Text, &mut Style)>,
+) {
+    for (mut view, children) in &mut views {
+        if !view.clear && view.pending.is_empty() {
+            continue;
+        }
+
+        let Some(content) = children.iter().copied().find(|c| contents.contains(*c)) else {
+            continue;
+        };
+
+        let view = &mut *view;
+        if view.clear {
+            view.clear = false;
+            for line in view.lines.drain(..) {
+                cmd.entity(line).despawn_recursive();
+            }
+        }
+
+        for mut line in view.pending.drain(..) {
+            if view.show_timestamps {
+                let mut timestamp = line
+                    .sections
+                    .first()
+                    .or(view.line_text.sections.first())
+                    .cloned()
+                    .unwrap_or_default();
+                timestamp.text = format_timestamp(time.elapsed_seconds());
+                timestamp.color = view.timestamp_color;
+                line.sections.insert(0, timestamp);
+            }
+
+            let entry = LogEntry {
+                time: time.elapsed_seconds(),
+                colors: line.sections.iter().map(|section| section.color).collect(),
+                alpha: 1.0,
+            };
+            let margin = UiRect::bottom(Val::Px(view.line_spacing));
+
+            let recycled = match view.lines.len() >= view.max_lines.max(1) {
+                true => view.lines.pop_front(),
+                false => None,
+            };
+
+            let line_id = match recycled.filter(|line| entries.contains(*line)) {
+                Some(line_id) => {
+                    // The links of the old line are replaced, so that they
+                    // match the sections of the new line.
+                    match line.text_links() {
+                        Some(links) => {
+                            cmd.entity(line_id).insert((links, Interaction::default()));
+                        }
+                        None => {
+                            cmd.entity(line_id).remove::<(TextLinks, Interaction)>();
+                        }
+                    }
+
+                    if let Ok((mut old_entry, mut text, mut style)) = entries.get_mut(line_id) {
+                        *old_entry = entry;
+                        *text = line.into_text(&asset_server);
+                        style.margin = margin;
+                    }
+
+                    // Moving the node to the end of the content keeps the
+                    // lines in order.
+                    cmd.entity(content).push_children(&[line_id]);
+                    line_id
+                }
+                None => {
+                    if let Some(old) = recycled {
+                        cmd.entity(old).despawn_recursive();
+                    }
+
+                    let mut node = NodeBundleBuilder::default();
+                    node.set_parent(Some(content));
+                    node.insert(entry);
+                    node.get_style_mut().margin = margin;
+                    line.apply_to_node(&mut node, &asset_server);
+                    node.build(&mut cmd, &asset_server)
+                }
+            };
+
+            view.lines.push_back(line_id);
+        }
+    }
+}
+
+/// Scrolls each log view that follows its newest lines to the bottom, and
+/// stops following when the view is scrolled up.
+fn follow_log_views(mut views: Query<(&mut LogView, &ScrollView, &mut ScrollPosition)>) {
+    for (mut view, scroll, mut position) in &mut views {
+        let max = scroll.max_offset().y;
+        let offset = position.offset.y;
+
+        let follow = match view.follow {
+            true => offset >= view.last_offset - BOTTOM_TOLERANCE,
+            false => offset >= max - BOTTOM_TOLERANCE,
+        };
+
+        if follow && offset != max {
+            position.offset.y = max;
+        }
+
+        let last_offset = position.offset.y;
+        if view.follow != follow || view.last_offset != last_offset {
+            view.follow = follow;
+            view.last_offset = last_offset;
+        }
+    }
+}
+
+/// Fades out the old lines of each log view that fades lines while it is not
+/// hovered.
+fn fade_log_lines(
+    time: Res<Time>,
+    views: Query<(&LogView, &Interaction)>,
+    mut entries: Query<(&mut LogEntry, &mut Text)>,
+) {
+    for (view, interaction) in &views {
+        let hovered = *interaction != Interaction::None;
+
+        for line in &view.lines {
+            let Ok((mut entry, mut text)) = entries.get_mut(*line) else {
+                continue;
+            };
+
+            let alpha = match view.fade_after {
+                Some(after) if !hovered => {
+                    let age = time.elapsed_seconds() - entry.time - after;
+                    match view.fade_time > 0.0 {
+                        true => 1.0 - (age / view.fade_time).clamp(0.0, 1.0),
+                        false if age > 0.0 => 0.0,
+                        false => 1.0,
+                    }
+                }
+                _ => 1.0,
+            };
+
+            if entry.alpha == alpha {
+                continue;
+            }
+
+            entry.alpha = alpha;
+            for (section, color) in text.sections.iter_mut().zip(&entry.colors) {
+                section.style.color = color.with_a(color.a() * alpha);
+            }
+        }
+    }
+}
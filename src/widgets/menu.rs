@@ -0,0 +1,740 @@
+//! Contains the components, events, and systems that open context menus and
+//! the menus of a menu bar.
+
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::window::PrimaryWindow;
+
+use super::{BackInput, UiLayer, cursor_position, hovered_nodes};
+use crate::prelude::{
+    DataBlock,
+    MenuEntry,
+    MenuStyle,
+    NodeBundleBuilder,
+    NodeMenu,
+    NodeMenuBar,
+    NodeText,
+};
+
+/// The space, in logical pixels, between the edges of a menu popup and its
+/// items.
+const POPUP_PADDING: f32 = 4.0;
+
+/// A component that stores the entries of a context menu, or of a menu within
+/// a menu bar.
+///
+/// The popups of a menu are created from this component each time the menu is
+/// opened, so game code may modify the entries at any time.
+#[derive(Debug, Default, Clone, Component)]
+pub struct Menu {
+    /// The entries of the menu.
+    pub entries: Vec<MenuEntry>,
+
+    /// How the popups of the menu are displayed.
+    pub style: MenuStyle,
+}
+
+impl From<NodeMenu> for Menu {
+    fn from(menu: NodeMenu) -> Self {
+        Self {
+            entries: menu.entries,
+            style: menu.style,
+        }
+    }
+}
+
+/// An event that is sent when an action item of a menu is clicked.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct MenuAction {
+    /// The node that owns the menu.
+    pub source: Entity,
+
+    /// The user-defined id of the clicked item.
+    pub id: String,
+
+    /// If the clicked item is checkable, whether it is now checked.
+    pub checked: Option<bool>,
+}
+
+/// A marker component for a menu bar.
+#[derive(Debug, Default, Clone, Copy, Component)]
+pub struct MenuBar;
+
+/// A marker component for a node that opens its menu when right-clicked.
+#[derive(Debug, Default, Clone, Copy, Component)]
+pub(crate) struct ContextMenuTarget;
+
+/// A component for a button within a menu bar that opens its menu.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct MenuBarButton {
+    /// The menu bar this button belongs to.
+    bar: Entity,
+
+    /// The background color of the button while hovered or open.
+    hover_color: Color,
+}
+
+/// A resource that tracks the currently open menu.
+#[derive(Debug, Default, Resource)]
+struct MenuState {
+    /// The node that owns the open menu, if any.
+    source: Option<Entity>,
+
+    /// The open popups, starting from the top-level popup and followed by each
+    /// nested submenu.
+    popups: Vec<Entity>,
+}
+
+/// A component for a displayed menu popup.
+#[derive(Debug, Clone, Component)]
+struct MenuPopup {
+    /// The node that owns the menu.
+    source: Entity,
+
+    /// The indices of the submenu items leading to this popup.
+    path: Vec<usize>,
+
+    /// The area the popup is positioned next to.
+    target: Rect,
+
+    /// Whether the popup is placed beside the target instead of below it.
+    beside: bool,
+}
+
+/// A component for an item within a menu popup.
+#[derive(Debug, Clone, Copy, Component)]
+struct MenuRow {
+    /// The popup this item belongs to.
+    popup: Entity,
+
+    /// The index of the item within the popup.
+    index: usize,
+}
+
+/// This plugin handles opening, navigating, and closing menus.
+pub(crate) struct MenuPlugin;
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MenuState>()
+            .add_event::<MenuAction>()
+            .add_systems(
+                Update,
+                (
+                    close_menus_on_outside_press,
+                    open_context_menu,
+                    open_menu_bar_menu,
+                    hover_menu_rows,
+                    click_menu_rows,
+                    highlight_menu_bar_buttons,
+                    position_menu_popups,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Consumes the given container node builder and creates a menu bar, along
+/// with a button for each of its menus.
+///
+/// Returns the entity of the menu bar.
+pub(crate) fn build_menu_bar(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut container: NodeBundleBuilder,
+    bar: NodeMenuBar,
+) -> Entity {
+    container.insert(MenuBar);
+    container.get_style_mut().flex_direction = FlexDirection::Row;
+    let bar_id = container.build(cmd, asset_server);
+
+    for item in bar.items {
+        let mut button = NodeBundleBuilder::default();
+        button.set_parent(Some(bar_id));
+        button.insert((
+            Menu::from(item.menu),
+            MenuBarButton {
+                bar: bar_id,
+                hover_color: bar.hover_color,
+            },
+            Interaction::default(),
+        ));
+
+        let style = button.get_style_mut();
+        style.align_items = AlignItems::Center;
+        style.padding = UiRect::horizontal(bar.button_padding);
+        let button_id = button.build(cmd, asset_server);
+
+        let mut label = menu_text(&bar.text, item.label, None, asset_server);
+        label.set_parent(Some(button_id));
+        label.build(cmd, asset_server);
+    }
+
+    bar_id
+}
+
+/// Creates a text node builder with the style of the first section of the
+/// given text, and an optional color override.
+fn menu_text(
+    text: &NodeText,
+    value: String,
+    color: Option<Color>,
+    asset_server: &AssetServer,
+) -> NodeBundleBuilder {
+    let mut text = text.clone();
+    text.sections.truncate(1);
+    if let Some(section) = text.sections.first_mut() {
+        section.text = value;
+        if let Some(color) = color {
+            section.color = color;
+        }
+    }
+
+    let mut text_node = NodeBundleBuilder::default();
+    text.apply_to_node(&mut text_node, asset_server);
+    text_node
+}
+
+/// Gets the entries displayed by the popup at the given path of submenu
+/// indices.
+fn entries_at<'a>(entries: &'a [MenuEntry], path: &[usize]) -> Option<&'a [MenuEntry]> {
+    if path.is_empty() {
+        return Some(entries);
+    }
+
+    match MenuEntry::find(entries, path)? {
+        MenuEntry::Submenu { entries, .. } => Some(entries),
+        _ => None,
+    }
+}
+
+/// Closes every open popup at or below the given depth.
+///
+/// Closing the top-level popup closes the menu.
+fn close_popups(cmd: &mut Commands, state: &mut MenuState, depth: usize) {
+    if depth >= state.popups.len() {
+        return;
+    }
+
+    for popup in state.popups.drain(depth ..) {
+        cmd.entity(popup).despawn_recursive();
+    }
+
+    if depth == 0 {
+        state.source = None;
+    }
+}
+
+/// Spawns a hidden popup that displays the entries of the given menu at the
+/// path of the popup, and adds it to the open popups.
+///
+/// The popup is shown once it has been positioned next to its target.
+fn open_popup(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    state: &mut MenuState,
+    menu: &Menu,
+    popup_data: MenuPopup,
+) {
+    let Some(entries) = entries_at(&menu.entries, &popup_data.path) else {
+        return;
+    };
+    let source = popup_data.source;
+
+    let style = &menu.style;
+    let mut popup = NodeBundleBuilder::default();
+    style
+        .background
+        .clone()
+        .apply_to_node(&mut popup, asset_server);
+    popup.insert((
+        popup_data,
+        UiLayer::Popup,
+        Visibility::Hidden,
+        Interaction::default(),
+        FocusPolicy::Block,
+    ));
+
+    let popup_style = popup.get_style_mut();
+    popup_style.position_type = PositionType::Absolute;
+    popup_style.flex_direction = FlexDirection::Column;
+    popup_style.min_width = Val::Px(style.min_width);
+    popup_style.padding = UiRect::vertical(Val::Px(POPUP_PADDING));
+    let popup_id = popup.build(cmd, asset_server);
+
+    for (index, entry) in entries.iter().enumerate() {
+        let (label, right, enabled, checked) = match entry {
+            MenuEntry::Action {
+                label,
+                accelerator,
+                enabled,
+                checked,
+                ..
+            } => (label, accelerator.clone(), *enabled, *checked == Some(true)),
+
+            MenuEntry::Submenu { label, enabled, .. } => {
+                (label, Some(style.submenu_icon.clone()), *enabled, false)
+            }
+
+            MenuEntry::Separator => {
+                let mut separator = NodeBundleBuilder::default();
+                separator.set_parent(Some(popup_id));
+                separator.insert(BackgroundColor(style.separator_color));
+
+                let separator_style = separator.get_style_mut();
+                separator_style.height = Val::Px(1.0);
+                separator_style.margin = UiRect::vertical(Val::Px(POPUP_PADDING));
+                separator.build(cmd, asset_server);
+                continue;
+            }
+        };
+
+        let mut row = NodeBundleBuilder::default();
+        row.set_parent(Some(popup_id));
+        row.insert((
+            MenuRow {
+                popup: popup_id,
+                index,
+            },
+            Interaction::default(),
+        ));
+
+        let row_style = row.get_style_mut();
+        row_style.height = Val::Px(style.item_height);
+        row_style.align_items = AlignItems::Center;
+        row_style.padding = UiRect::horizontal(Val::Px(POPUP_PADDING * 2.0));
+        let row_id = row.build(cmd, asset_server);
+
+        let color = (!enabled).then_some(style.disabled_color);
+        let mut check = NodeBundleBuilder::default();
+        check.set_parent(Some(row_id));
+
+        let check_style = check.get_style_mut();
+        check_style.width = Val::Px(style.item_height * 0.75);
+        check_style.flex_shrink = 0.0;
+        let check_id = check.build(cmd, asset_server);
+
+        if checked {
+            let mut icon = menu_text(&style.text, style.check_icon.clone(), color, asset_server);
+            icon.set_parent(Some(check_id));
+            icon.build(cmd, asset_server);
+        }
+
+        let mut label = menu_text(&style.text, label.clone(), color, asset_server);
+        label.set_parent(Some(row_id));
+        label.get_style_mut().flex_grow = 1.0;
+        label.build(cmd, asset_server);
+
+        // Accelerators and submenu icons are pushed to the right edge by the
+        // growing label.
+        if let Some(right) = right {
+            let mut right = menu_text(&style.text, right, color, asset_server);
+            right.set_parent(Some(row_id));
+            right.get_style_mut().margin.left = Val::Px(POPUP_PADDING * 6.0);
+            right.build(cmd, asset_server);
+        }
+    }
+
+    state.source = Some(source);
+    state.popups.push(popup_id);
+}
+
+/// Closes the open menu when escape is pressed, when the mouse is pressed
+/// outside of its popups, or when the node that owns it is despawned.
+#[allow(clippy::too_many_arguments)]
+fn close_menus_on_outside_press(
+    mut cmd: Commands,
+    mut state: ResMut<MenuState>,
+    mut back: ResMut<BackInput>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    menus: Query<(), With<Menu>>,
+    popups: Query<&Interaction, With<MenuPopup>>,
+    bar_buttons: Query<&Interaction, With<MenuBarButton>>,
+) {
+    let Some(source) = state.source else {
+        return;
+    };
+
+    if keyboard.just_pressed(KeyCode::Escape) {
+        back.consume();
+        close_popups(&mut cmd, &mut state, 0);
+        return;
+    }
+
+    if menus.get(source).is_err() {
+        close_popups(&mut cmd, &mut state, 0);
+        return;
+    }
+
+    if !mouse.any_just_pressed([MouseButton::Left, MouseButton::Right]) {
+        return;
+    }
+
+    let over_popup = popups
+        .iter()
+        .any(|interaction| *interaction != Interaction::None);
+
+    // Menu bar buttons toggle their own menu when pressed.
+    let over_bar = mouse.just_pressed(MouseButton::Left)
+        && bar_buttons
+            .iter()
+            .any(|interaction| *interaction == Interaction::Pressed);
+
+    if !over_popup && !over_bar {
+        close_popups(&mut cmd, &mut state, 0);
+    }
+}
+
+/// Opens the context menu of the top-most hovered node at the cursor when the
+/// right mouse button is pressed.
+#[allow(clippy::too_many_arguments)]
+fn open_context_menu(
+    mut cmd: Commands,
+    asset_server: Res<AssetServer>,
+    mut state: ResMut<MenuState>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    popups: Query<&Interaction, With<MenuPopup>>,
+    targets: Query<(Entity, &Node, &Menu), With<ContextMenuTarget>>,
+    interactions: Query<(Entity, &Interaction)>,
+    parents: Query<&Parent>,
+) {
+    if !mouse.just_pressed(MouseButton::Right) {
+        return;
+    }
+
+    if popups
+        .iter()
+        .any(|interaction| *interaction != Interaction::None)
+    {
+        return;
+    }
+
+    let Some(cursor) = cursor_position(&windows, &ui_scale) else {
+        return;
+    };
+
+    // Targets covered by a blocking node, such as a modal scrim, are not
+    // hovered.
+    let hovered = hovered_nodes(&interactions, &parents);
+    let Some((target, _, menu)) = targets
+        .iter()
+        .filter(|(entity, _, _)| hovered.contains(entity))
+        .max_by_key(|(_, node, _)| node.stack_index())
+    else {
+        return;
+    };
+
+    close_popups(&mut cmd, &mut state, 0);
+    open_popup(
+        &mut cmd,
+        &asset_server,
+        &mut state,
+        menu,
+        MenuPopup {
+            source: target,
+            path: Vec::new(),
+            target: Rect::from_center_size(cursor, Vec2::ZERO),
+            beside: false,
+        },
+    );
+}
+
+/// Toggles the menu of a menu bar button when it is pressed, and switches to
+/// the menu of a hovered button while another menu of the same bar is open.
+fn open_menu_bar_menu(
+    mut cmd: Commands,
+    asset_server: Res<AssetServer>,
+    mut state: ResMut<MenuState>,
+    buttons: Query<(Entity, &Interaction, &MenuBarButton), Changed<Interaction>>,
+    all_buttons: Query<&MenuBarButton>,
+    menus: Query<(&Node, &GlobalTransform, &Menu)>,
+) {
+    for (entity, interaction, button) in &buttons {
+        let open_bar = state
+            .source
+            .and_then(|source| all_buttons.get(source).ok())
+            .map(|open| open.bar);
+
+        let open = match interaction {
+            Interaction::Pressed => state.source != Some(entity),
+            Interaction::Hovered => open_bar == Some(button.bar) && state.source != Some(entity),
+            Interaction::None => continue,
+        };
+
+        if *interaction == Interaction::Pressed || open {
+            close_popups(&mut cmd, &mut state, 0);
+        }
+
+        let Ok((node, transform, menu)) = menus.get(entity) else {
+            continue;
+        };
+
+        if open {
+            open_popup(
+                &mut cmd,
+                &asset_server,
+                &mut state,
+                menu,
+                MenuPopup {
+                    source: entity,
+                    path: Vec::new(),
+                    target: node.logical_rect(transform),
+                    beside: false,
+                },
+            );
+        }
+    }
+}
+
+/// Highlights hovered menu items, and opens the submenu of a hovered submenu
+/// item while closing any other submenus at the same depth.
+fn hover_menu_rows(
+    mut cmd: Commands,
+    asset_server: Res<AssetServer>,
+    mut state: ResMut<MenuState>,
+    mut rows: Query<
+        (
+            &Interaction,
+            &Node,
+            &GlobalTransform,
+            &MenuRow,
+            &mut BackgroundColor,
+        ),
+        Changed<Interaction>,
+    >,
+    popups: Query<&MenuPopup>,
+    menus: Query<&Menu>,
+) {
+    for (interaction, node, transform, row, mut background) in &mut rows {
+        let Ok(popup) = popups.get(row.popup) else {
+            continue;
+        };
+
+        let Ok(menu) = menus.get(popup.source) else {
+            continue;
+        };
+
+        let mut path = popup.path.clone();
+        path.push(row.index);
+
+        let Some(entry) = MenuEntry::find(&menu.entries, &path) else {
+            continue;
+        };
+
+        let hovered = *interaction != Interaction::None && entry.is_enabled();
+        background.0 = match hovered {
+            true => menu.style.hover_color,
+            false => Color::NONE,
+        };
+
+        if !hovered {
+            continue;
+        }
+
+        let Some(depth) = state.popups.iter().position(|p| *p == row.popup) else {
+            continue;
+        };
+
+        let is_open = state
+            .popups
+            .get(depth + 1)
+            .and_then(|child| popups.get(*child).ok())
+            .is_some_and(|child| child.path == path);
+
+        if is_open {
+            continue;
+        }
+
+        close_popups(&mut cmd, &mut state, depth + 1);
+        if matches!(entry, MenuEntry::Submenu { .. }) {
+            open_popup(
+                &mut cmd,
+                &asset_server,
+                &mut state,
+                menu,
+                MenuPopup {
+                    source: popup.source,
+                    path,
+                    target: node.logical_rect(transform),
+                    beside: true,
+                },
+            );
+        }
+    }
+}
+
+/// Sends a menu action event and closes the menu when an enabled action item
+/// is clicked, toggling the item first if it is checkable.
+fn click_menu_rows(
+    mut cmd: Commands,
+    mut state: ResMut<MenuState>,
+    mut actions: EventWriter<MenuAction>,
+    rows: Query<(&Interaction, &MenuRow), Changed<Interaction>>,
+    popups: Query<&MenuPopup>,
+    mut menus: Query<&mut Menu>,
+) {
+    for (interaction, row) in &rows {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(popup) = popups.get(row.popup) else {
+            continue;
+        };
+
+        let Ok(mut menu) = menus.get_mut(popup.source) else {
+            continue;
+        };
+
+        let mut path = popup.path.clone();
+        path.push(row.index);
+
+        let Some(MenuEntry::Action {
+            id,
+            enabled: true,
+            checked,
+            ..
+        }) = MenuEntry::find_mut(&mut menu.entries, &path)
+        else {
+            continue;
+        };
+
+        if let Some(checked) = checked {
+            *checked = !*checked;
+        }
+
+        actions.send(MenuAction {
+            source: popup.source,
+            id: id.clone(),
+            checked: *checked,
+        });
+
+        close_popups(&mut cmd, &mut state, 0);
+        return;
+    }
+}
+
+/// Highlights each menu bar button that is hovered or whose menu is open.
+fn highlight_menu_bar_buttons(
+    state: Res<MenuState>,
+    mut buttons: Query<(Entity, &Interaction, &MenuBarButton, &mut BackgroundColor)>,
+) {
+    for (entity, interaction, button, mut background) in &mut buttons {
+        let color = match *interaction != Interaction::None || state.source == Some(entity) {
+            true => button.hover_color,
+            false => Color::NONE,
+        };
+
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+/// Positions each newly opened popup next to its target, and shows it once its
+/// size is known.
+fn position_menu_popups(
+    ui_scale: Res<UiScale>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut popups: Query<(&MenuPopup, &Node, &mut Style, &mut Visibility)>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+
+    let bounds = Vec2::new(window.width(), window.height()) / ui_scale.0;
+    for (popup, node, mut style, mut visibility) in &mut popups {
+        let size = node.size();
+        if *visibility != Visibility::Hidden || size == Vec2::ZERO {
+            continue;
+        }
+
+        let pos = place_menu(popup.target, size, bounds, popup.beside);
+        style.left = Val::Px(pos.x);
+        style.top = Val::Px(pos.y);
+        *visibility = Visibility::Inherited;
+    }
+}
+
+/// Finds the top-left position of a menu popup with the given size.
+///
+/// Popups are placed below the target and aligned to its left edge, or beside
+/// the target and aligned to its top edge. If the popup would leave the bounds,
+/// it is flipped to the opposite side. The result is always clamped to within
+/// the bounds.
+fn place_menu(target: Rect, size: Vec2, bounds: Vec2, beside: bool) -> Vec2 {
+    let axis = |min: f32, max: f32, size: f32, bound: f32, outside: bool| {
+        let (start, flipped) = match outside {
+            true => (max, min - size),
+            false => (min, max - size),
+        };
+
+        let pos = match start + size > bound && flipped >= 0.0 {
+            true => flipped,
+            false => start,
+        };
+
+        pos.clamp(0.0, (bound - size).max(0.0))
+    };
+
+    Vec2::new(
+        axis(target.min.x, target.max.x, size.x, bounds.x, beside),
+        axis(target.min.y, target.max.y, size.y, bounds.y, !beside),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Vec2 = Vec2::new(800.0, 600.0);
+
+    #[test]
+    fn menu_opens_below_target() {
+        let target = Rect::new(100.0, 100.0, 200.0, 120.0);
+        let pos = place_menu(target, Vec2::new(150.0, 200.0), BOUNDS, false);
+        assert_eq!(pos, Vec2::new(100.0, 120.0));
+    }
+
+    #[test]
+    fn submenu_opens_beside_target() {
+        let target = Rect::new(100.0, 100.0, 200.0, 120.0);
+        let pos = place_menu(target, Vec2::new(150.0, 200.0), BOUNDS, true);
+        assert_eq!(pos, Vec2::new(200.0, 100.0));
+    }
+
+    #[test]
+    fn menu_flips_at_window_edges() {
+        let size = Vec2::new(150.0, 200.0);
+
+        // Below the target, the menu flips above it and aligns to the right
+        // edge of the target.
+        let target = Rect::new(700.0, 500.0, 780.0, 520.0);
+        assert_eq!(
+            place_menu(target, size, BOUNDS, false),
+            Vec2::new(630.0, 300.0)
+        );
+
+        // Beside the target, the menu flips to the left and aligns to the
+        // bottom edge of the target.
+        assert_eq!(
+            place_menu(target, size, BOUNDS, true),
+            Vec2::new(550.0, 320.0)
+        );
+    }
+
+    #[test]
+    fn menu_is_clamped_when_it_cannot_flip() {
+        // Flipping above the target would leave the window, so the menu stays
+        // below and is pushed up to fit.
+        let target = Rect::new(0.0, 50.0, 100.0, 60.0);
+        let pos = place_menu(target, Vec2::new(150.0, 580.0), BOUNDS, false);
+        assert_eq!(pos, Vec2::new(0.0, 20.0));
+
+        // Menus larger than the window are placed at the top-left corner.
+        let pos = place_menu(target, Vec2::new(1000.0, 700.0), BOUNDS, false);
+        assert_eq!(pos, Vec2::ZERO);
+    }
+}
@@ -0,0 +1,160 @@
+//! This module contains the runtime behavior of interactive nodes.
+//!
+//! While blocks define how a node is displayed when it is built, widgets
+//! define the components, events, and systems that allow a node to respond to
+//! user input after it has been spawned.
+
+use std::hash::Hash;
+
+use bevy::prelude::*;
+use bevy::render::RenderApp;
+use bevy::utils::HashSet;
+use bevy::window::PrimaryWindow;
+
+mod collapsible;
+mod color_picker;
+mod dialogue;
+mod drag;
+mod focus;
+mod key_bindings;
+mod link;
+mod log;
+mod menu;
+mod modal;
+mod overlay;
+mod progress;
+mod radio;
+mod scroll;
+mod slot_grid;
+mod spinner;
+mod split;
+mod table;
+mod tabs;
+mod toast;
+mod tooltip;
+mod tree;
+mod virtual_keyboard;
+mod virtual_list;
+mod window;
+
+pub use collapsible::*;
+pub use color_picker::*;
+pub use dialogue::*;
+pub use drag::*;
+pub use focus::*;
+pub use key_bindings::*;
+pub use link::*;
+pub use log::*;
+pub use menu::*;
+pub use modal::*;
+pub use overlay::*;
+pub use progress::*;
+pub use radio::*;
+pub use scroll::*;
+pub use slot_grid::*;
+pub use spinner::*;
+pub use split::*;
+pub use table::*;
+pub use tabs::*;
+pub use toast::*;
+pub use tooltip::*;
+pub use tree::*;
+pub use virtual_keyboard::*;
+pub use virtual_list::*;
+pub use window::*;
+
+/// Gets the position of the cursor within the primary window in logical UI
+/// coordinates, taking the [`UiScale`] into account.
+///
+/// Returns `None` if there is no primary window or the cursor is not within
+/// it.
+pub(crate) fn cursor_position(
+    windows: &Query<&Window, With<PrimaryWindow>>,
+    ui_scale: &UiScale,
+) -> Option<Vec2> {
+    windows
+        .get_single()
+        .ok()
+        .and_then(Window::cursor_position)
+        .map(|pos| pos / ui_scale.0)
+}
+
+/// Finds the nodes that are under the cursor without being covered by a node
+/// with a blocking [`FocusPolicy`](bevy::ui::FocusPolicy), such as a modal
+/// scrim or an open popup.
+///
+/// These are the hovered or pressed nodes along with all of their ancestors,
+/// so that a node is still found while the cursor is over a button within it.
+pub(crate) fn hovered_nodes(
+    interactions: &Query<(Entity, &Interaction)>,
+    parents: &Query<&Parent>,
+) -> HashSet<Entity> {
+    let mut hovered = HashSet::new();
+    for (entity, interaction) in interactions {
+        if *interaction == Interaction::None || !hovered.insert(entity) {
+            continue;
+        }
+
+        for ancestor in parents.iter_ancestors(entity) {
+            if !hovered.insert(ancestor) {
+                break;
+            }
+        }
+    }
+    hovered
+}
+
+/// Registers the given UI material, adding the material plugin when the render
+/// app is present so that the material can be drawn.
+///
+/// Headless apps have no render app, but still need the material asset to
+/// build the nodes that use it.
+///
+/// This must be called from [`Plugin::finish`], so that the render plugins have
+/// been built regardless of the order in which the plugins were added.
+pub(crate) fn register_ui_material<M: UiMaterial>(app: &mut App)
+where
+    M::Data: PartialEq + Eq + Hash + Clone,
+{
+    if app.get_sub_app(RenderApp).is_err() {
+        app.init_asset::<M>();
+        return;
+    }
+
+    // Plugins added while finishing are not finished by the app, so the
+    // material plugin is built and finished directly.
+    let plugin = UiMaterialPlugin::<M>::default();
+    plugin.build(app);
+    plugin.finish(app);
+}
+
+/// Creates a headless app with the plugins needed to build and update UI
+/// nodes without a window or renderer.
+///
+/// The UI plugin is left out, as its layout panics without a camera, so nodes
+/// are not laid out and interactions are not updated.
+#[cfg(test)]
+pub(crate) fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        bevy::input::InputPlugin,
+        WindowPlugin {
+            primary_window: None,
+            ..default()
+        },
+        HierarchyPlugin,
+        TransformPlugin,
+    ))
+    .init_asset::<Image>()
+    .init_resource::<UiScale>()
+    .configure_sets(
+        PreUpdate,
+        bevy::ui::UiSystem::Focus.after(bevy::input::InputSystem),
+    )
+    .add_plugins(crate::StreamlineUIPlugin);
+    app.finish();
+    app.cleanup();
+    app
+}
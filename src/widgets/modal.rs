@@ -0,0 +1,229 @@
+//! Contains the components, events, and systems used to display and close
+//! modal dialogs.
+
+use bevy::prelude::*;
+
+use super::overlay::move_to_overlay;
+use super::{BackInput, UiLayer};
+
+/// A component for the full-screen scrim of a modal dialog.
+///
+/// The dialog itself is a child of the scrim. Despawning the scrim closes the
+/// modal without sending a [`ModalClosed`] event.
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Modal {
+    /// An identifier for the modal, which is included in the event sent when
+    /// the modal is closed.
+    pub id: String,
+
+    /// If true, the modal may be cancelled by clicking on the scrim or by
+    /// pressing escape.
+    pub dismissible: bool,
+}
+
+/// The result chosen when closing a modal dialog.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum ModalResult {
+    /// The dialog was confirmed.
+    Confirm,
+
+    /// The dialog was cancelled or dismissed.
+    #[default]
+    Cancel,
+
+    /// A custom, user-defined result.
+    Custom(u32),
+}
+
+/// An event that is sent when a modal dialog is closed by the user.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct ModalClosed {
+    /// The scrim entity of the modal that was closed.
+    pub modal: Entity,
+
+    /// The identifier of the modal that was closed.
+    pub id: String,
+
+    /// The result chosen by the user.
+    pub result: ModalResult,
+}
+
+/// A resource that stores the currently open modals, from the bottom-most to
+/// the top-most.
+///
+/// Only the top-most modal receives input, and modals are closed in the
+/// reverse order they were opened.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct ModalStack {
+    /// The scrim entities of the open modals.
+    stack: Vec<Entity>,
+}
+
+impl ModalStack {
+    /// Gets the top-most open modal, if any.
+    pub fn top(&self) -> Option<Entity> {
+        self.stack.last().copied()
+    }
+
+    /// Returns true if no modals are open.
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Gets the number of open modals.
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+}
+
+/// A component for a button that closes the modal it is within when clicked.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct ModalButton {
+    /// The result to close the modal with.
+    pub(crate) result: ModalResult,
+}
+
+/// This plugin handles opening and closing modal dialogs.
+pub(crate) struct ModalPlugin;
+impl Plugin for ModalPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Modal>()
+            .register_type::<ModalResult>()
+            .init_resource::<ModalStack>()
+            .add_event::<ModalClosed>()
+            .add_systems(
+                Update,
+                (
+                    remove_despawned_modals,
+                    push_opened_modals.after(move_to_overlay),
+                    close_modal_on_button,
+                )
+                    .chain(),
+            )
+            // Widgets use the back input during the update, so the modal is
+            // only dismissed afterwards if none of them used it.
+            .add_systems(PostUpdate, close_modal_on_dismiss);
+    }
+}
+
+/// Despawns the given modal and sends an event with the chosen result.
+fn close_modal(
+    cmd: &mut Commands,
+    stack: &mut ModalStack,
+    modal_closed: &mut EventWriter<ModalClosed>,
+    entity: Entity,
+    modal: &Modal,
+    result: ModalResult,
+) {
+    cmd.entity(entity).despawn_recursive();
+    stack.stack.retain(|e| *e != entity);
+    modal_closed.send(ModalClosed {
+        modal: entity,
+        id: modal.id.clone(),
+        result,
+    });
+}
+
+/// Removes modals that were despawned by game code from the stack.
+fn remove_despawned_modals(mut stack: ResMut<ModalStack>, mut removed: RemovedComponents<Modal>) {
+    for entity in removed.read() {
+        stack.stack.retain(|e| *e != entity);
+    }
+}
+
+/// Adds newly opened modals to the top of the stack.
+fn push_opened_modals(
+    mut cmd: Commands,
+    mut stack: ResMut<ModalStack>,
+    opened: Query<Entity, Added<Modal>>,
+) {
+    for entity in &opened {
+        stack.stack.push(entity);
+        let z_index = UiLayer::Modal.z_index() + stack.stack.len() as i32;
+        cmd.entity(entity).insert(ZIndex::Local(z_index));
+    }
+}
+
+/// Closes the modal containing a modal button when the button is clicked.
+fn close_modal_on_button(
+    mut cmd: Commands,
+    mut stack: ResMut<ModalStack>,
+    mut modal_closed: EventWriter<ModalClosed>,
+    buttons: Query<(Entity, &Interaction, &ModalButton), Changed<Interaction>>,
+    parents: Query<&Parent>,
+    modals: Query<&Modal>,
+) {
+    for (entity, interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Some((modal_id, modal)) = parents
+            .iter_ancestors(entity)
+            .find_map(|e| modals.get(e).ok().map(|m| (e, m)))
+        else {
+            continue;
+        };
+
+        if stack.top() != Some(modal_id) {
+            continue;
+        }
+
+        close_modal(
+            &mut cmd,
+            &mut stack,
+            &mut modal_closed,
+            modal_id,
+            modal,
+            button.result,
+        );
+    }
+}
+
+/// Cancels the top-most modal when its scrim is clicked, or when escape or
+/// the gamepad back button is pressed, if the modal is dismissible.
+///
+/// The back input is ignored if a widget has already used it this frame, as
+/// recorded by [`BackInput`].
+#[allow(clippy::too_many_arguments)]
+fn close_modal_on_dismiss(
+    mut cmd: Commands,
+    mut stack: ResMut<ModalStack>,
+    mut modal_closed: EventWriter<ModalClosed>,
+    back: Res<BackInput>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    modals: Query<(&Modal, Ref<Interaction>)>,
+) {
+    let Some(top) = stack.top() else {
+        return;
+    };
+
+    let Ok((modal, interaction)) = modals.get(top) else {
+        return;
+    };
+
+    if !modal.dismissible {
+        return;
+    }
+
+    let scrim_clicked = interaction.is_changed() && *interaction == Interaction::Pressed;
+    let back_pressed = !back.is_consumed()
+        && (keyboard.just_pressed(KeyCode::Escape)
+            || gamepads.iter().any(|gamepad| {
+                gamepad_buttons.just_pressed(GamepadButton::new(gamepad, GamepadButtonType::East))
+            }));
+
+    if scrim_clicked || back_pressed {
+        close_modal(
+            &mut cmd,
+            &mut stack,
+            &mut modal_closed,
+            top,
+            modal,
+            ModalResult::Cancel,
+        );
+    }
+}
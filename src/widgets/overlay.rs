@@ -0,0 +1,115 @@
+//! Contains the overlay root, which displays popups and other layered nodes
+//! above all other UI.
+
+use bevy::prelude::*;
+
+/// The global z-index of the overlay root.
+const OVERLAY_Z_INDEX: i32 = 1_000_000;
+
+/// A resource that stores the root node of the overlay.
+///
+/// The overlay root is a full-screen node that is spawned by the plugin and
+/// rendered above all other UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct UiOverlay {
+    /// The overlay root entity.
+    pub root: Entity,
+}
+
+/// A component that moves a node into the overlay when it is spawned.
+///
+/// Nodes are placed into the overlay root and sorted by their layer, with
+/// later layers displayed above earlier layers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component, Reflect)]
+#[reflect(Component)]
+pub enum UiLayer {
+    /// Popups, such as dropdowns and context menus.
+    #[default]
+    Popup,
+
+    /// Modal dialogs, which block all input to the UI below them.
+    Modal,
+
+    /// Toast notifications, which are displayed above modal dialogs.
+    Toast,
+
+    /// The virtual keyboard, which is displayed above modal dialogs and toasts
+    /// so that text inputs within them may be edited.
+    Keyboard,
+
+    /// Tooltips, which are displayed above all other layers except dragged
+    /// nodes.
+    Tooltip,
+
+    /// The node that follows the cursor while a node is dragged, which is
+    /// displayed above everything else.
+    DragGhost,
+}
+
+impl UiLayer {
+    /// Gets the z-index of this layer within the overlay root.
+    pub fn z_index(self) -> i32 {
+        match self {
+            UiLayer::Popup => 100,
+            UiLayer::Modal => 200,
+            UiLayer::Toast => 250,
+            UiLayer::Keyboard => 275,
+            UiLayer::Tooltip => 300,
+            UiLayer::DragGhost => 400,
+        }
+    }
+}
+
+/// This plugin spawns the overlay root and moves layered nodes into it.
+pub(crate) struct OverlayPlugin;
+impl Plugin for OverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<UiLayer>()
+            .add_systems(Startup, spawn_overlay_root)
+            .add_systems(Update, move_to_overlay.run_if(resource_exists::<UiOverlay>));
+    }
+}
+
+/// Spawns the overlay root node.
+fn spawn_overlay_root(mut cmd: Commands) {
+    let root = cmd
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    top: Val::Px(0.0),
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                z_index: ZIndex::Global(OVERLAY_Z_INDEX),
+                ..default()
+            },
+            Name::new("UI Overlay"),
+        ))
+        .id();
+
+    cmd.insert_resource(UiOverlay { root });
+}
+
+/// Moves newly spawned layered nodes into the overlay root.
+///
+/// Nodes may be despawned by other systems in the same frame, such as a popup
+/// that is closed right after it opens, so they are only moved if they still
+/// exist once the command is applied.
+pub(crate) fn move_to_overlay(
+    mut cmd: Commands,
+    overlay: Res<UiOverlay>,
+    layered: Query<(Entity, &UiLayer), Added<UiLayer>>,
+) {
+    for (entity, layer) in &layered {
+        let root = overlay.root;
+        let z_index = ZIndex::Local(layer.z_index());
+        cmd.add(move |world: &mut World| {
+            if let Some(mut node) = world.get_entity_mut(entity) {
+                node.set_parent(root).insert(z_index);
+            }
+        });
+    }
+}
@@ -0,0 +1,221 @@
+//! Contains the components and systems that keep progress bars in sync with
+//! their values.
+
+use bevy::asset::embedded_asset;
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef};
+
+use super::register_ui_material;
+use crate::prelude::{
+    DataBlock,
+    NodeBundleBuilder,
+    NodeProgress,
+    NodeText,
+    ProgressDirection,
+    ValueBinding,
+};
+
+/// The path of the shader used to draw radial progress bars.
+const RADIAL_PROGRESS_SHADER: &str = "embedded://bevy_streamline_ui/widgets/radial_progress.wgsl";
+
+/// A component that stores the value of a progress bar.
+///
+/// Game code may write to [`ProgressBar::value`] to update the progress bar,
+/// unless the progress bar is bound to a value.
+#[derive(Debug, Default, Clone, Copy, Component, Reflect)]
+#[reflect(Component)]
+pub struct ProgressBar {
+    /// The value of the progress bar, between `0.0` and `1.0`.
+    pub value: f32,
+
+    /// The direction in which the fill grows.
+    pub direction: ProgressDirection,
+
+    /// Whether the label displays the value as a percentage.
+    pub percent_label: bool,
+}
+
+/// A component that updates the value of a progress bar from a binding each
+/// frame.
+#[derive(Debug, Clone, Component)]
+pub struct ProgressBinding(pub ValueBinding);
+
+/// A marker component for the node that displays the fill of a progress bar.
+#[derive(Debug, Default, Clone, Copy, Component)]
+pub struct ProgressIndicator;
+
+/// A component for the text that labels a progress bar.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct ProgressLabel {
+    /// The progress bar this label belongs to.
+    bar: Entity,
+}
+
+/// The UI material used to draw the ring of a radial progress bar.
+#[derive(Debug, Clone, Asset, TypePath, AsBindGroup)]
+pub struct RadialProgressMaterial {
+    /// The color of the filled part of the ring.
+    #[uniform(0)]
+    pub fill: Color,
+
+    /// The color of the unfilled part of the ring.
+    #[uniform(1)]
+    pub track: Color,
+
+    /// The value, ring thickness, and whether the ring fills clockwise,
+    /// packed into the `x`, `y`, and `z` components.
+    #[uniform(2)]
+    pub params: Vec4,
+}
+
+impl UiMaterial for RadialProgressMaterial {
+    fn fragment_shader() -> ShaderRef {
+        RADIAL_PROGRESS_SHADER.into()
+    }
+}
+
+/// This plugin handles updating progress bars and their labels.
+pub(crate) struct ProgressPlugin;
+impl Plugin for ProgressPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ProgressBar>().add_systems(
+            Update,
+            (update_bound_progress, update_progress_bars).chain(),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        embedded_asset!(app, "radial_progress.wgsl");
+        register_ui_material::<RadialProgressMaterial>(app);
+    }
+}
+
+/// Consumes the given container node builder and creates a progress bar,
+/// along with its fill and label.
+///
+/// Returns the entity of the progress bar.
+pub(crate) fn build_progress_bar(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut container: NodeBundleBuilder,
+    mut progress: NodeProgress,
+) -> Entity {
+    progress.apply_to_parent(&mut container, asset_server);
+    let bar_id = container.build(cmd, asset_server);
+
+    let label = progress.label.take();
+    let percent_label = progress.percent_label;
+    let value = progress.value.clamp(0.0, 1.0);
+
+    let mut indicator = NodeBundleBuilder::default();
+    indicator.set_parent(Some(bar_id));
+    progress.apply_to_node(&mut indicator, asset_server);
+    indicator.build(cmd, asset_server);
+
+    let label = match label {
+        Some(label) => label,
+        None if percent_label => NodeText::styled(16.0, Color::WHITE),
+        None => return bar_id,
+    };
+
+    let mut label_container = NodeBundleBuilder::default();
+    label_container.set_parent(Some(bar_id));
+
+    let style = label_container.get_style_mut();
+    style.position_type = PositionType::Absolute;
+    style.width = Val::Percent(100.0);
+    style.height = Val::Percent(100.0);
+
+    label.apply_to_parent(&mut label_container, asset_server);
+    let label_container_id = label_container.build(cmd, asset_server);
+
+    let mut label_node = NodeBundleBuilder::default();
+    label_node.set_parent(Some(label_container_id));
+    label_node.insert(ProgressLabel { bar: bar_id });
+
+    let mut label = label;
+    if percent_label {
+        if let Some(section) = label.sections.first_mut() {
+            section.text = percent_text(value);
+        }
+    }
+
+    label.apply_to_node(&mut label_node, asset_server);
+    label_node.build(cmd, asset_server);
+
+    bar_id
+}
+
+/// Formats the given progress value as a percentage.
+fn percent_text(value: f32) -> String {
+    format!("{:.0}%", value * 100.0)
+}
+
+/// Reads the value of each bound progress bar from its binding.
+fn update_bound_progress(world: &mut World, bars: &mut QueryState<(Entity, &ProgressBinding)>) {
+    let values: Vec<(Entity, f32)> = bars
+        .iter(world)
+        .filter_map(|(entity, binding)| binding.0.get(world).map(|value| (entity, value)))
+        .collect();
+
+    for (entity, value) in values {
+        let Some(mut bar) = world.get_mut::<ProgressBar>(entity) else {
+            continue;
+        };
+
+        let value = value.clamp(0.0, 1.0);
+        if bar.value != value {
+            bar.value = value;
+        }
+    }
+}
+
+/// Resizes the fill and updates the label of each progress bar whose value has
+/// changed.
+fn update_progress_bars(
+    bars: Query<(Entity, &ProgressBar, &Children), Changed<ProgressBar>>,
+    mut indicators: Query<
+        (&mut Style, Option<&Handle<RadialProgressMaterial>>),
+        With<ProgressIndicator>,
+    >,
+    mut labels: Query<(&ProgressLabel, &mut Text)>,
+    mut materials: ResMut<Assets<RadialProgressMaterial>>,
+) {
+    for (bar_id, bar, children) in &bars {
+        let value = bar.value.clamp(0.0, 1.0);
+
+        for &child in children {
+            let Ok((mut style, material)) = indicators.get_mut(child) else {
+                continue;
+            };
+
+            if let Some(material) = material {
+                if let Some(material) = materials.get_mut(material) {
+                    material.params.x = value;
+                }
+                continue;
+            }
+
+            let size = Val::Percent(value * 100.0);
+            if bar.direction.horizontal() {
+                style.width = size;
+            } else {
+                style.height = size;
+            }
+        }
+
+        if !bar.percent_label {
+            continue;
+        }
+
+        for (label, mut text) in &mut labels {
+            if label.bar != bar_id {
+                continue;
+            }
+
+            if let Some(section) = text.sections.first_mut() {
+                section.value = percent_text(value);
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+// Draws the ring of a radial progress bar.
+
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+@group(1) @binding(0) var<uniform> fill: vec4<f32>;
+@group(1) @binding(1) var<uniform> track: vec4<f32>;
+
+// x: value, y: ring thickness, z: 1.0 if clockwise
+@group(1) @binding(2) var<uniform> params: vec4<f32>;
+
+const TAU: f32 = 6.28318530718;
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    let value = clamp(params.x, 0.0, 1.0);
+    let thickness = params.y;
+
+    // Position within the largest circle that fits the node, in the range -1..1.
+    let extent = min(in.size.x, in.size.y);
+    let p = (in.uv * 2.0 - 1.0) * in.size / extent;
+    let dist = length(p);
+
+    let aa = fwidth(dist);
+    let outer = 1.0 - smoothstep(1.0 - aa, 1.0, dist);
+    let inner = smoothstep(1.0 - thickness - aa, 1.0 - thickness, dist);
+    let ring = outer * inner;
+
+    // The angle around the ring, starting at the top and increasing clockwise.
+    var angle = atan2(p.x, -p.y) / TAU;
+    if params.z < 0.5 {
+        angle = -angle;
+    }
+    angle = fract(angle);
+
+    let color = select(track, fill, angle < value);
+    return vec4<f32>(color.rgb, color.a * ring);
+}
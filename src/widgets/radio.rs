@@ -0,0 +1,295 @@
+//! Contains the components, events, and systems that select options within a
+//! radio group.
+
+use bevy::prelude::*;
+
+use crate::prelude::{
+    DataBlock,
+    NodeBackground,
+    NodeBundleBuilder,
+    NodePosition,
+    NodeRadioGroup,
+    UiFocus,
+    UiNode,
+};
+
+/// A component that stores the index of the selected option of a radio group.
+///
+/// Game code may write to this component to change the selected option.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct SelectedIndex(pub Option<usize>);
+
+/// A component that stores the state of a radio group.
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct RadioGroup {
+    /// The number of options within the group.
+    pub(crate) count: usize,
+
+    /// The index of the option that is currently displayed as selected.
+    pub(crate) shown: Option<usize>,
+}
+
+impl RadioGroup {
+    /// Gets the number of options within the group.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// An event that is sent when the selected option of a radio group changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct RadioChanged {
+    /// The radio group entity.
+    pub group: Entity,
+
+    /// The index of the previously selected option, if any.
+    pub previous: Option<usize>,
+
+    /// The index of the newly selected option, if any.
+    pub selected: Option<usize>,
+}
+
+/// A component for the node of a single option within a radio group.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct RadioOption {
+    /// The radio group this option belongs to.
+    group: Entity,
+
+    /// The index of this option within the group.
+    index: usize,
+}
+
+/// A component for an indicator node, which is only displayed while the
+/// selected state of its option matches.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct RadioIndicator {
+    /// The radio group this indicator belongs to.
+    group: Entity,
+
+    /// The index of the option this indicator belongs to.
+    index: usize,
+
+    /// Whether this indicator is displayed while its option is selected.
+    selected: bool,
+}
+
+/// This plugin handles selecting options within radio groups.
+pub(crate) struct RadioPlugin;
+impl Plugin for RadioPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SelectedIndex>()
+            .register_type::<RadioGroup>()
+            .add_event::<RadioChanged>()
+            .add_systems(
+                Update,
+                (
+                    select_radio_on_click,
+                    select_radio_with_keys,
+                    show_selected_radio,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Consumes the given group node builder and creates a radio group, along
+/// with a node for each of its options.
+///
+/// Returns the entity of the group.
+pub(crate) fn build_radio_group(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut group: NodeBundleBuilder,
+    mut radio: NodeRadioGroup,
+) -> Entity {
+    let options = std::mem::take(&mut radio.options);
+    let selected = radio.selected;
+    let gap = radio.gap;
+    let indicator_size = radio.indicator_size;
+    let indicator = radio.indicator.clone();
+    let selected_indicator = radio.selected_indicator.clone();
+
+    radio.apply_to_node(&mut group, asset_server);
+    let group_id = group.build(cmd, asset_server);
+
+    for (index, label) in options.into_iter().enumerate() {
+        let mut option = NodeBundleBuilder::default();
+        option.set_parent(Some(group_id));
+        option.insert((
+            RadioOption {
+                group: group_id,
+                index,
+            },
+            Interaction::default(),
+        ));
+
+        let style = option.get_style_mut();
+        style.flex_direction = FlexDirection::Row;
+        style.align_items = AlignItems::Center;
+        style.column_gap = gap;
+        let option_id = option.build(cmd, asset_server);
+
+        let mut slot = NodeBundleBuilder::default();
+        slot.set_parent(Some(option_id));
+
+        let style = slot.get_style_mut();
+        style.flex_shrink = 0.0;
+        style.width = indicator_size;
+        style.height = indicator_size;
+        let slot_id = slot.build(cmd, asset_server);
+
+        for (background, is_selected) in [
+            (indicator.clone(), false),
+            (selected_indicator.clone(), true),
+        ] {
+            build_indicator(
+                cmd,
+                asset_server,
+                slot_id,
+                background,
+                RadioIndicator {
+                    group: group_id,
+                    index,
+                    selected: is_selected,
+                },
+                (selected == Some(index)) == is_selected,
+            );
+        }
+
+        UiNode::Text {
+            background: default(),
+            position: NodePosition::default(),
+            text: label,
+        }
+        .build_node(cmd, asset_server, Some(option_id));
+    }
+
+    group_id
+}
+
+/// Creates a single indicator node within the given indicator slot.
+fn build_indicator(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    slot: Entity,
+    background: NodeBackground,
+    indicator: RadioIndicator,
+    visible: bool,
+) {
+    let mut node = NodeBundleBuilder::default();
+    node.set_parent(Some(slot));
+    node.insert(indicator);
+    background.apply_to_node(&mut node, asset_server);
+
+    let style = node.get_style_mut();
+    style.position_type = PositionType::Absolute;
+    style.width = Val::Percent(100.0);
+    style.height = Val::Percent(100.0);
+    if !visible {
+        style.display = Display::None;
+    }
+
+    node.build(cmd, asset_server);
+}
+
+/// Selects an option when it is clicked.
+fn select_radio_on_click(
+    options: Query<(&Interaction, &RadioOption), Changed<Interaction>>,
+    mut groups: Query<&mut SelectedIndex>,
+) {
+    for (interaction, option) in &options {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut selected) = groups.get_mut(option.group) else {
+            continue;
+        };
+
+        selected.set_if_neq(SelectedIndex(Some(option.index)));
+    }
+}
+
+/// Moves the selection of the focused radio group using the arrow keys.
+///
+/// The selection wraps around when moving past the first or last option.
+fn select_radio_with_keys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    focus: Res<UiFocus>,
+    mut groups: Query<(&RadioGroup, &mut SelectedIndex)>,
+) {
+    let Some(entity) = focus.entity else {
+        return;
+    };
+
+    let Ok((group, mut selected)) = groups.get_mut(entity) else {
+        return;
+    };
+
+    if group.count == 0 {
+        return;
+    }
+
+    let mut step = 0;
+    for key in keyboard.get_just_pressed() {
+        match key {
+            KeyCode::ArrowUp | KeyCode::ArrowLeft => step -= 1,
+            KeyCode::ArrowDown | KeyCode::ArrowRight => step += 1,
+            _ => {}
+        }
+    }
+
+    if step == 0 {
+        return;
+    }
+
+    let count = group.count as isize;
+    let index = match selected.0 {
+        Some(index) => (index as isize + step).rem_euclid(count),
+        None if step > 0 => step - 1,
+        None => count + step,
+    };
+
+    selected.set_if_neq(SelectedIndex(Some(index.rem_euclid(count) as usize)));
+}
+
+/// Displays the indicators of the selected option of each radio group whose
+/// selection has changed.
+fn show_selected_radio(
+    mut changed: EventWriter<RadioChanged>,
+    mut groups: Query<(Entity, &mut RadioGroup, &mut SelectedIndex), Changed<SelectedIndex>>,
+    mut indicators: Query<(&RadioIndicator, &mut Style)>,
+) {
+    for (group_id, mut group, mut selected) in &mut groups {
+        if selected.0.is_some_and(|index| index >= group.count) {
+            selected.0 = None;
+        }
+
+        if group.shown == selected.0 {
+            continue;
+        }
+
+        for (indicator, mut style) in &mut indicators {
+            if indicator.group != group_id {
+                continue;
+            }
+
+            let visible = (selected.0 == Some(indicator.index)) == indicator.selected;
+            style.display = if visible {
+                Display::Flex
+            } else {
+                Display::None
+            };
+        }
+
+        changed.send(RadioChanged {
+            group: group_id,
+            previous: group.shown,
+            selected: selected.0,
+        });
+
+        group.shown = selected.0;
+    }
+}
@@ -0,0 +1,633 @@
+//! Contains the components and systems that allow the contents of a scroll
+//! view to be scrolled.
+
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::window::PrimaryWindow;
+
+use super::{UiFocus, cursor_position, hovered_nodes};
+use crate::prelude::{DataBlock, NodeBundleBuilder, NodeScrollbar, ScrollDirection};
+
+/// The speed, in logical pixels per second, at which a fully tilted gamepad
+/// stick scrolls a scroll view, relative to its scroll speed.
+const GAMEPAD_SCROLL_FACTOR: f32 = 20.0;
+
+/// The smallest size of a scrollbar thumb, relative to the scrollbar track.
+const MIN_THUMB_SIZE: f32 = 0.05;
+
+/// The current scroll offset of a scroll view, in logical pixels.
+///
+/// Game code may read this component to find out how far a scroll view has
+/// been scrolled, or write to it to scroll the view. The offset is clamped to
+/// the scrollable area automatically.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct ScrollPosition {
+    /// The offset of the contents from the top-left corner of the view.
+    pub offset: Vec2,
+}
+
+/// A component that stores the settings and runtime state of a scroll view.
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct ScrollView {
+    /// The axes along which the contents may be scrolled.
+    pub direction: ScrollDirection,
+
+    /// The number of logical pixels to scroll per mouse wheel line or key
+    /// press.
+    pub scroll_speed: f32,
+
+    /// If set, scrolling carries momentum that decays at the given rate per
+    /// second.
+    pub momentum: Option<f32>,
+
+    /// The current scrolling velocity, in logical pixels per second.
+    pub(crate) velocity: Vec2,
+
+    /// The size of the visible area of the scroll view.
+    pub(crate) viewport_size: Vec2,
+
+    /// The size of the scrolled contents.
+    pub(crate) content_size: Vec2,
+}
+
+impl ScrollView {
+    /// Gets the largest offset the contents may be scrolled to.
+    pub fn max_offset(&self) -> Vec2 {
+        let mut max = (self.content_size - self.viewport_size).max(Vec2::ZERO);
+
+        if !self.direction.horizontal() {
+            max.x = 0.0;
+        }
+
+        if !self.direction.vertical() {
+            max.y = 0.0;
+        }
+
+        max
+    }
+
+    /// Scrolls by the given amount, either immediately or by adding momentum,
+    /// depending on the settings of this scroll view.
+    fn scroll_by(&mut self, position: &mut ScrollPosition, delta: Vec2) {
+        match self.momentum {
+            Some(decay) => self.velocity += delta * decay,
+            None => position.offset += delta,
+        }
+    }
+}
+
+/// A marker component for the node that contains the scrolled children of a
+/// scroll view.
+#[derive(Debug, Default, Clone, Copy, Component, Reflect)]
+#[reflect(Component)]
+pub struct ScrollContent;
+
+/// A component for the track of a scrollbar.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct ScrollbarTrack {
+    /// The scroll view this scrollbar belongs to.
+    view: Entity,
+
+    /// Whether this scrollbar scrolls vertically or horizontally.
+    vertical: bool,
+}
+
+/// A component for the draggable thumb of a scrollbar.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct ScrollbarThumb {
+    /// The scroll view this scrollbar belongs to.
+    view: Entity,
+
+    /// Whether this scrollbar scrolls vertically or horizontally.
+    vertical: bool,
+
+    /// The cursor position and scroll offset when the thumb started being
+    /// dragged, if it is being dragged.
+    drag_start: Option<(Vec2, Vec2)>,
+}
+
+/// This plugin handles scrolling of scroll views.
+pub(crate) struct ScrollViewPlugin;
+impl Plugin for ScrollViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ScrollPosition>()
+            .register_type::<ScrollView>()
+            .register_type::<ScrollContent>()
+            .add_systems(
+                Update,
+                (
+                    mouse_wheel_scroll,
+                    keyboard_scroll,
+                    gamepad_scroll,
+                    drag_scrollbar_thumb,
+                    apply_scroll_momentum,
+                    update_scroll_bounds,
+                    update_scrollbars,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Spawns the scrollbars for the given scroll view.
+pub(crate) fn build_scrollbars(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    view: Entity,
+    direction: ScrollDirection,
+    scrollbar: NodeScrollbar,
+) {
+    for vertical in [true, false] {
+        if (vertical && !direction.vertical()) || (!vertical && !direction.horizontal()) {
+            continue;
+        }
+
+        let mut track = NodeBundleBuilder::default();
+        track.set_parent(Some(view));
+        scrollbar
+            .track
+            .clone()
+            .apply_to_node(&mut track, asset_server);
+        track.insert((ScrollbarTrack { view, vertical }, FocusPolicy::Block));
+
+        let style = track.get_style_mut();
+        style.position_type = PositionType::Absolute;
+        style.right = Val::Px(0.0);
+        style.bottom = Val::Px(0.0);
+        if vertical {
+            style.top = Val::Px(0.0);
+            style.width = Val::Px(scrollbar.thickness);
+        } else {
+            style.left = Val::Px(0.0);
+            style.height = Val::Px(scrollbar.thickness);
+        }
+
+        let track_id = track.build(cmd, asset_server);
+
+        let mut thumb = NodeBundleBuilder::default();
+        thumb.set_parent(Some(track_id));
+        scrollbar
+            .thumb
+            .clone()
+            .apply_to_node(&mut thumb, asset_server);
+        thumb.insert((
+            ScrollbarThumb {
+                view,
+                vertical,
+                drag_start: None,
+            },
+            Interaction::default(),
+            FocusPolicy::Block,
+        ));
+
+        let style = thumb.get_style_mut();
+        style.position_type = PositionType::Absolute;
+        if vertical {
+            style.width = Val::Percent(100.0);
+        } else {
+            style.height = Val::Percent(100.0);
+        }
+
+        thumb.build(cmd, asset_server);
+    }
+}
+
+/// Finds the top-most scroll view that is currently under the cursor.
+///
+/// Scroll views covered by a blocking node, such as a modal scrim, are not
+/// hovered.
+fn hovered_scroll_view(
+    views: &Query<(Entity, &Node), With<ScrollView>>,
+    interactions: &Query<(Entity, &Interaction)>,
+    parents: &Query<&Parent>,
+) -> Option<Entity> {
+    let hovered = hovered_nodes(interactions, parents);
+    views
+        .iter()
+        .filter(|(entity, _)| hovered.contains(entity))
+        .max_by_key(|(_, node)| node.stack_index())
+        .map(|(entity, _)| entity)
+}
+
+/// Scrolls the hovered scroll view when the mouse wheel is used.
+fn mouse_wheel_scroll(
+    mut wheel_events: EventReader<MouseWheel>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    hovered: Query<(Entity, &Node), With<ScrollView>>,
+    interactions: Query<(Entity, &Interaction)>,
+    parents: Query<&Parent>,
+    mut views: Query<(&mut ScrollView, &mut ScrollPosition)>,
+) {
+    let mut lines = Vec2::ZERO;
+    let mut pixels = Vec2::ZERO;
+    for event in wheel_events.read() {
+        match event.unit {
+            MouseScrollUnit::Line => lines += Vec2::new(event.x, event.y),
+            MouseScrollUnit::Pixel => pixels += Vec2::new(event.x, event.y),
+        }
+    }
+
+    if lines == Vec2::ZERO && pixels == Vec2::ZERO {
+        return;
+    }
+
+    let Some(entity) = hovered_scroll_view(&hovered, &interactions, &parents) else {
+        return;
+    };
+
+    let Ok((mut view, mut position)) = views.get_mut(entity) else {
+        return;
+    };
+
+    let mut delta = lines * view.scroll_speed + pixels;
+
+    let shift = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if shift || view.direction == ScrollDirection::Horizontal {
+        delta = Vec2::new(delta.y, delta.x);
+    }
+
+    view.scroll_by(&mut position, -delta);
+}
+
+/// Finds the scroll view that should receive keyboard and gamepad input.
+///
+/// This is the focused scroll view, or the hovered scroll view if nothing is
+/// focused.
+fn input_scroll_view(
+    focus: &UiFocus,
+    views: &Query<(Entity, &Node), With<ScrollView>>,
+    interactions: &Query<(Entity, &Interaction)>,
+    parents: &Query<&Parent>,
+) -> Option<Entity> {
+    match focus.entity {
+        Some(entity) => views.contains(entity).then_some(entity),
+        None => hovered_scroll_view(views, interactions, parents),
+    }
+}
+
+/// Scrolls the focused scroll view using the arrow keys, page up, page down,
+/// home, and end.
+fn keyboard_scroll(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    focus: Res<UiFocus>,
+    hovered: Query<(Entity, &Node), With<ScrollView>>,
+    interactions: Query<(Entity, &Interaction)>,
+    parents: Query<&Parent>,
+    mut views: Query<(&mut ScrollView, &mut ScrollPosition)>,
+) {
+    let keys = [
+        KeyCode::ArrowUp,
+        KeyCode::ArrowDown,
+        KeyCode::ArrowLeft,
+        KeyCode::ArrowRight,
+        KeyCode::PageUp,
+        KeyCode::PageDown,
+        KeyCode::Home,
+        KeyCode::End,
+    ];
+
+    if !keyboard.any_just_pressed(keys) {
+        return;
+    }
+
+    let Some(entity) = input_scroll_view(&focus, &hovered, &interactions, &parents) else {
+        return;
+    };
+
+    let Ok((mut view, mut position)) = views.get_mut(entity) else {
+        return;
+    };
+
+    let line = view.scroll_speed;
+    let page = view.viewport_size;
+    let max = view.max_offset();
+
+    let mut delta = Vec2::ZERO;
+    for key in keyboard.get_just_pressed() {
+        match key {
+            KeyCode::ArrowUp => delta.y -= line,
+            KeyCode::ArrowDown => delta.y += line,
+            KeyCode::ArrowLeft => delta.x -= line,
+            KeyCode::ArrowRight => delta.x += line,
+            KeyCode::PageUp => delta.y -= page.y,
+            KeyCode::PageDown => delta.y += page.y,
+            KeyCode::Home => {
+                view.velocity = Vec2::ZERO;
+                position.offset = Vec2::ZERO;
+            }
+            KeyCode::End => {
+                view.velocity = Vec2::ZERO;
+                position.offset = max;
+            }
+            _ => {}
+        }
+    }
+
+    if view.direction == ScrollDirection::Horizontal && delta.x == 0.0 {
+        delta = Vec2::new(delta.y, delta.x);
+    }
+
+    if delta != Vec2::ZERO {
+        view.scroll_by(&mut position, delta);
+    }
+}
+
+/// Scrolls the focused scroll view using the gamepad right stick and
+/// directional pad.
+#[allow(clippy::too_many_arguments)]
+fn gamepad_scroll(
+    time: Res<Time>,
+    gamepads: Res<Gamepads>,
+    buttons: Res<ButtonInput<GamepadButton>>,
+    axes: Res<Axis<GamepadAxis>>,
+    focus: Res<UiFocus>,
+    hovered: Query<(Entity, &Node), With<ScrollView>>,
+    interactions: Query<(Entity, &Interaction)>,
+    parents: Query<&Parent>,
+    mut views: Query<(&mut ScrollView, &mut ScrollPosition)>,
+) {
+    let mut stick = Vec2::ZERO;
+    let mut steps = Vec2::ZERO;
+
+    for gamepad in gamepads.iter() {
+        let axis = |axis_type| {
+            axes.get(GamepadAxis::new(gamepad, axis_type))
+                .unwrap_or_default()
+        };
+        stick.x += axis(GamepadAxisType::RightStickX);
+        stick.y -= axis(GamepadAxisType::RightStickY);
+
+        let pressed = |button_type| buttons.just_pressed(GamepadButton::new(gamepad, button_type));
+        if pressed(GamepadButtonType::DPadUp) {
+            steps.y -= 1.0;
+        }
+        if pressed(GamepadButtonType::DPadDown) {
+            steps.y += 1.0;
+        }
+        if pressed(GamepadButtonType::DPadLeft) {
+            steps.x -= 1.0;
+        }
+        if pressed(GamepadButtonType::DPadRight) {
+            steps.x += 1.0;
+        }
+    }
+
+    if stick == Vec2::ZERO && steps == Vec2::ZERO {
+        return;
+    }
+
+    let Some(entity) = input_scroll_view(&focus, &hovered, &interactions, &parents) else {
+        return;
+    };
+
+    let Ok((mut view, mut position)) = views.get_mut(entity) else {
+        return;
+    };
+
+    let speed = view.scroll_speed;
+    position.offset += stick * speed * GAMEPAD_SCROLL_FACTOR * time.delta_seconds();
+
+    if steps != Vec2::ZERO {
+        view.scroll_by(&mut position, steps * speed);
+    }
+}
+
+/// Scrolls a scroll view when the thumb of one of its scrollbars is dragged.
+fn drag_scrollbar_thumb(
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    mut thumbs: Query<(&mut ScrollbarThumb, &Interaction, &Node, &Parent)>,
+    tracks: Query<&Node, With<ScrollbarTrack>>,
+    mut views: Query<(&mut ScrollView, &mut ScrollPosition)>,
+) {
+    let cursor = cursor_position(&windows, &ui_scale);
+
+    for (mut thumb, interaction, thumb_node, track) in &mut thumbs {
+        let (Some(cursor), true) = (cursor, mouse.pressed(MouseButton::Left)) else {
+            thumb.drag_start = None;
+            continue;
+        };
+
+        let Ok((mut view, mut position)) = views.get_mut(thumb.view) else {
+            continue;
+        };
+
+        if mouse.just_pressed(MouseButton::Left) && *interaction == Interaction::Pressed {
+            view.velocity = Vec2::ZERO;
+            thumb.drag_start = Some((cursor, position.offset));
+        }
+
+        let Some((start_cursor, start_offset)) = thumb.drag_start else {
+            continue;
+        };
+
+        let Ok(track_node) = tracks.get(track.get()) else {
+            continue;
+        };
+
+        let axis = if thumb.vertical { Vec2::Y } else { Vec2::X };
+        let free_space = (track_node.size() - thumb_node.size()).dot(axis);
+        if free_space <= 0.0 {
+            continue;
+        }
+
+        let cursor_delta = (cursor - start_cursor).dot(axis);
+        let max = view.max_offset().dot(axis);
+        let offset = start_offset.dot(axis) + cursor_delta / free_space * max;
+
+        if thumb.vertical {
+            position.offset.y = offset;
+        } else {
+            position.offset.x = offset;
+        }
+    }
+}
+
+/// Moves scroll views according to their current momentum.
+fn apply_scroll_momentum(
+    time: Res<Time>,
+    mut views: Query<(&mut ScrollView, &mut ScrollPosition)>,
+) {
+    let delta = time.delta_seconds();
+
+    for (mut view, mut position) in &mut views {
+        let Some(decay) = view.momentum else {
+            continue;
+        };
+
+        if view.velocity == Vec2::ZERO {
+            continue;
+        }
+
+        position.offset += view.velocity * delta;
+        view.velocity *= (-decay * delta).exp();
+
+        if view.velocity.length_squared() < 1.0 {
+            view.velocity = Vec2::ZERO;
+        }
+    }
+}
+
+/// Clamps the scroll position of each scroll view to its scrollable area and
+/// moves its contents to match.
+pub(crate) fn update_scroll_bounds(
+    mut views: Query<(&mut ScrollView, &mut ScrollPosition, &Node, &Children)>,
+    mut contents: Query<(&mut Style, &Node), With<ScrollContent>>,
+) {
+    for (mut view, mut position, view_node, children) in &mut views {
+        let Some(content) = children.iter().copied().find(|c| contents.contains(*c)) else {
+            continue;
+        };
+
+        let Ok((mut style, content_node)) = contents.get_mut(content) else {
+            continue;
+        };
+
+        let viewport_size = view_node.size();
+        let content_size = content_node.size();
+        if view.viewport_size != viewport_size || view.content_size != content_size {
+            view.viewport_size = viewport_size;
+            view.content_size = content_size;
+        }
+
+        // Scroll views that have not been laid out yet keep their offset, so
+        // that a scroll position assigned when spawning is not lost.
+        let max = view.max_offset();
+        let clamped = if viewport_size == Vec2::ZERO {
+            position.offset
+        } else {
+            position.offset.clamp(Vec2::ZERO, max)
+        };
+
+        if clamped != position.offset {
+            position.offset = clamped;
+            if clamped.x == 0.0 || clamped.x == max.x {
+                view.velocity.x = 0.0;
+            }
+            if clamped.y == 0.0 || clamped.y == max.y {
+                view.velocity.y = 0.0;
+            }
+        }
+
+        let left = Val::Px(-clamped.x);
+        let top = Val::Px(-clamped.y);
+        if style.left != left || style.top != top {
+            style.left = left;
+            style.top = top;
+        }
+    }
+}
+
+/// Resizes and moves the scrollbar thumbs to match the scroll position of
+/// their scroll views, and hides scrollbars when there is nothing to scroll.
+fn update_scrollbars(
+    views: Query<(&ScrollView, &ScrollPosition)>,
+    mut tracks: Query<(&ScrollbarTrack, &mut Style), Without<ScrollbarThumb>>,
+    mut thumbs: Query<(&ScrollbarThumb, &mut Style), Without<ScrollbarTrack>>,
+) {
+    for (track, mut style) in &mut tracks {
+        let Ok((view, _)) = views.get(track.view) else {
+            continue;
+        };
+
+        let max = view.max_offset();
+        let scrollable = if track.vertical { max.y } else { max.x } > 0.0;
+        let display = if scrollable {
+            Display::Flex
+        } else {
+            Display::None
+        };
+
+        if style.display != display {
+            style.display = display;
+        }
+    }
+
+    for (thumb, mut style) in &mut thumbs {
+        let Ok((view, position)) = views.get(thumb.view) else {
+            continue;
+        };
+
+        let axis = if thumb.vertical { Vec2::Y } else { Vec2::X };
+        let viewport = view.viewport_size.dot(axis);
+        let content = view.content_size.dot(axis);
+        let max = view.max_offset().dot(axis);
+
+        if content <= 0.0 || max <= 0.0 {
+            continue;
+        }
+
+        let size = (viewport / content).clamp(MIN_THUMB_SIZE, 1.0);
+        let progress = (position.offset.dot(axis) / max).clamp(0.0, 1.0);
+        let start = Val::Percent(progress * (1.0 - size) * 100.0);
+        let length = Val::Percent(size * 100.0);
+
+        if thumb.vertical {
+            if style.top != start || style.height != length {
+                style.top = start;
+                style.height = length;
+            }
+        } else if style.left != start || style.width != length {
+            style.left = start;
+            style.width = length;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    /// Spawns a scroll view with the given interaction, containing a button
+    /// with the given interaction, and returns the scroll view.
+    fn spawn_view(world: &mut World, view: Interaction, button: Interaction) -> Entity {
+        world
+            .spawn((ScrollView::default(), Node::default(), view))
+            .with_children(|parent| {
+                parent.spawn((Node::default(), button));
+            })
+            .id()
+    }
+
+    /// Finds the hovered scroll view within the given world.
+    fn hovered(world: &mut World) -> Option<Entity> {
+        world.run_system_once(
+            |views: Query<(Entity, &Node), With<ScrollView>>,
+             interactions: Query<(Entity, &Interaction)>,
+             parents: Query<&Parent>| {
+                hovered_scroll_view(&views, &interactions, &parents)
+            },
+        )
+    }
+
+    #[test]
+    fn hovered_view_is_found() {
+        let mut world = World::new();
+        let view = spawn_view(&mut world, Interaction::Hovered, Interaction::None);
+        assert_eq!(hovered(&mut world), Some(view));
+    }
+
+    #[test]
+    fn view_is_hovered_through_blocking_children() {
+        let mut world = World::new();
+        let view = spawn_view(&mut world, Interaction::None, Interaction::Hovered);
+        assert_eq!(hovered(&mut world), Some(view));
+    }
+
+    #[test]
+    fn view_under_blocking_node_is_not_hovered() {
+        let mut world = World::new();
+        spawn_view(&mut world, Interaction::None, Interaction::None);
+
+        // A modal scrim above the view blocks it from being hovered, even
+        // though the cursor is within the bounds of the view.
+        world.spawn((Node::default(), Interaction::Hovered));
+        assert_eq!(hovered(&mut world), None);
+    }
+}
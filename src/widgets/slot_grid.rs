@@ -0,0 +1,422 @@
+//! Contains the components, events, and systems used to display and select
+//! the slots of a slot grid.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::prelude::{
+    AnchorPoint,
+    DataBlock,
+    NodeBundleBuilder,
+    NodeBundleType,
+    NodePosition,
+    NodeSlotGrid,
+    NodeText,
+    UiFocus,
+};
+
+/// The number keys used to select the first ten slots of a grid with hotkeys.
+const HOTKEYS: [KeyCode; 10] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+    KeyCode::Digit0,
+];
+
+/// The contents of a single slot.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SlotContent {
+    /// The icon of the item in the slot.
+    pub icon: Handle<Image>,
+
+    /// The number of items stacked in the slot. The count is only displayed
+    /// when greater than one.
+    pub count: u32,
+}
+
+/// A trait for game components that provide the contents of a slot grid.
+///
+/// The type must be registered with a [`SlotSourcePlugin`], after which any
+/// slot grid whose [`SlotGrid::source`] is an entity with this component is
+/// updated whenever the component changes.
+pub trait SlotSource: Component {
+    /// Gets the contents of the slot at the given index, or `None` if the slot
+    /// is empty.
+    fn slot(&self, index: usize) -> Option<SlotContent>;
+}
+
+/// A component that stores the state of a slot grid.
+///
+/// Game code may write to [`SlotGrid::selected`] to change the selected slot,
+/// and to [`SlotGrid::source`] to bind the grid to other inventory data.
+#[derive(Debug, Clone, Component)]
+pub struct SlotGrid {
+    /// The entity with the [`SlotSource`] component that provides the
+    /// contents of the slots.
+    pub source: Option<Entity>,
+
+    /// The index of the selected slot, if any.
+    pub selected: Option<usize>,
+
+    /// Whether the first ten slots may be selected by pressing the matching
+    /// number key.
+    pub hotkeys: bool,
+
+    /// The selected slot that is currently displayed, if it has been
+    /// displayed.
+    pub(crate) shown: Option<Option<usize>>,
+
+    /// The nodes of each slot.
+    slots: Vec<SlotParts>,
+}
+
+impl SlotGrid {
+    /// Gets the number of slots in the grid.
+    pub fn slot_count(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Gets the node of the slot at the given index.
+    ///
+    /// This may be used to attach other components to a slot, such as a
+    /// [`Draggable`](crate::prelude::Draggable) or
+    /// [`DropTarget`](crate::prelude::DropTarget).
+    pub fn slot(&self, index: usize) -> Option<Entity> {
+        self.slots.get(index).map(|parts| parts.slot)
+    }
+}
+
+/// The nodes of a single slot that are updated when its contents change.
+#[derive(Debug, Clone, Copy)]
+struct SlotParts {
+    /// The slot node.
+    slot: Entity,
+
+    /// The item icon node.
+    icon: Entity,
+
+    /// The stack count text node.
+    count: Entity,
+
+    /// The node that outlines the slot while it is selected.
+    highlight: Entity,
+}
+
+/// A component for a single slot of a slot grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub struct GridSlot {
+    /// The slot grid this slot belongs to.
+    pub grid: Entity,
+
+    /// The index of the slot within the grid.
+    pub index: usize,
+}
+
+/// An event that is sent when a slot is clicked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct SlotClicked {
+    /// The slot grid entity.
+    pub grid: Entity,
+
+    /// The index of the clicked slot.
+    pub index: usize,
+}
+
+/// An event that is sent when the selected slot of a grid changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct SlotSelected {
+    /// The slot grid entity.
+    pub grid: Entity,
+
+    /// The index of the selected slot, if any.
+    pub index: Option<usize>,
+}
+
+/// This plugin handles selecting the slots of slot grids.
+pub(crate) struct SlotGridPlugin;
+impl Plugin for SlotGridPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SlotClicked>()
+            .add_event::<SlotSelected>()
+            .add_systems(
+                Update,
+                (
+                    click_slots,
+                    select_slots_with_hotkeys,
+                    update_slot_selection,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// This plugin updates the slot grids bound to entities with the given
+/// [`SlotSource`] component whenever the component changes.
+pub struct SlotSourcePlugin<T: SlotSource>(PhantomData<T>);
+
+impl<T: SlotSource> Default for SlotSourcePlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: SlotSource> Plugin for SlotSourcePlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, sync_slot_grids::<T>);
+    }
+}
+
+/// Consumes the given container node builder and creates a slot grid, along
+/// with its slots.
+///
+/// Returns the entity of the slot grid.
+pub(crate) fn build_slot_grid(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut container: NodeBundleBuilder,
+    grid: NodeSlotGrid,
+) -> Entity {
+    let style = container.get_style_mut();
+    style.display = Display::Grid;
+    style.grid_template_columns = RepeatedGridTrack::px(grid.columns, grid.slot_size);
+    style.grid_template_rows = RepeatedGridTrack::px(grid.rows, grid.slot_size);
+    style.row_gap = Val::Px(grid.spacing);
+    style.column_gap = Val::Px(grid.spacing);
+    let grid_id = container.build(cmd, asset_server);
+
+    let label = |cmd: &mut Commands, parent: Entity, text: &NodeText, anchor: AnchorPoint| {
+        let mut text = text.clone();
+        text.sections.truncate(1);
+
+        let mut node = NodeBundleBuilder::default();
+        node.set_parent(Some(parent));
+        text.apply_to_node(&mut node, asset_server);
+        NodePosition::Anchored {
+            anchor,
+            width: Val::Auto,
+            height: Val::Auto,
+            margin: Val::Px(2.0),
+        }
+        .apply_to_node(&mut node, asset_server);
+        node.build(cmd, asset_server)
+    };
+
+    let mut count_text = grid.count_text.clone();
+    if let Some(section) = count_text.sections.first_mut() {
+        section.text.clear();
+    }
+
+    let slot_count = grid.columns as usize * grid.rows as usize;
+    let mut slots = Vec::with_capacity(slot_count);
+
+    for index in 0 .. slot_count {
+        let mut slot = NodeBundleBuilder::default();
+        slot.set_parent(Some(grid_id));
+        grid.slot_background
+            .clone()
+            .apply_to_node(&mut slot, asset_server);
+        slot.insert((
+            GridSlot {
+                grid: grid_id,
+                index,
+            },
+            Interaction::default(),
+        ));
+        let slot_id = slot.build(cmd, asset_server);
+
+        let padding = Val::Px(grid.icon_padding);
+        let mut icon = NodeBundleBuilder::default();
+        icon.set_parent(Some(slot_id));
+        icon.bundle_type(NodeBundleType::Image);
+        icon.insert((UiImage::default(), Visibility::Hidden));
+
+        let style = icon.get_style_mut();
+        style.position_type = PositionType::Absolute;
+        style.left = padding;
+        style.right = padding;
+        style.top = padding;
+        style.bottom = padding;
+        let icon_id = icon.build(cmd, asset_server);
+
+        let count_id = label(cmd, slot_id, &count_text, grid.count_anchor);
+
+        if grid.hotkeys && index < HOTKEYS.len() {
+            let mut text = grid.hotkey_text.clone();
+            if let Some(section) = text.sections.first_mut() {
+                section.text = ((index + 1) % HOTKEYS.len()).to_string();
+            }
+            label(cmd, slot_id, &text, AnchorPoint::TopLeft);
+        }
+
+        let highlight_id = cmd
+            .spawn(NodeBundle {
+                style: Style {
+                    display: Display::None,
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    border: UiRect::all(Val::Px(grid.selection_width)),
+                    ..default()
+                },
+                border_color: BorderColor(grid.selection_color),
+                ..default()
+            })
+            .set_parent(slot_id)
+            .id();
+
+        slots.push(SlotParts {
+            slot: slot_id,
+            icon: icon_id,
+            count: count_id,
+            highlight: highlight_id,
+        });
+    }
+
+    cmd.entity(grid_id).insert(SlotGrid {
+        source: grid.source,
+        selected: grid.selected,
+        hotkeys: grid.hotkeys,
+        shown: None,
+        slots,
+    });
+
+    grid_id
+}
+
+/// Selects a slot when it is clicked.
+fn click_slots(
+    mut clicked: EventWriter<SlotClicked>,
+    slots: Query<(&Interaction, &GridSlot), Changed<Interaction>>,
+    mut grids: Query<&mut SlotGrid>,
+) {
+    for (interaction, slot) in &slots {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        clicked.send(SlotClicked {
+            grid: slot.grid,
+            index: slot.index,
+        });
+
+        if let Ok(mut grid) = grids.get_mut(slot.grid) {
+            if grid.selected != Some(slot.index) {
+                grid.selected = Some(slot.index);
+            }
+        }
+    }
+}
+
+/// Selects the slot matching a pressed number key in each grid with hotkeys.
+///
+/// Hotkeys are ignored while another node has keyboard focus.
+fn select_slots_with_hotkeys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    focus: Res<UiFocus>,
+    mut grids: Query<&mut SlotGrid>,
+) {
+    if focus.entity.is_some() {
+        return;
+    }
+
+    let Some(index) = HOTKEYS.iter().position(|key| keyboard.just_pressed(*key)) else {
+        return;
+    };
+
+    for mut grid in &mut grids {
+        if grid.hotkeys && index < grid.slot_count() && grid.selected != Some(index) {
+            grid.selected = Some(index);
+        }
+    }
+}
+
+/// Highlights the selected slot of each grid that has changed, and sends an
+/// event when its selection changes.
+fn update_slot_selection(
+    mut selected: EventWriter<SlotSelected>,
+    mut grids: Query<(Entity, &mut SlotGrid), Changed<SlotGrid>>,
+    mut highlights: Query<&mut Style>,
+) {
+    for (grid_id, mut grid) in &mut grids {
+        if grid.shown == Some(grid.selected) {
+            continue;
+        }
+
+        if grid.shown.is_some() {
+            selected.send(SlotSelected {
+                grid: grid_id,
+                index: grid.selected,
+            });
+        }
+
+        grid.shown = Some(grid.selected);
+
+        for (index, parts) in grid.slots.iter().enumerate() {
+            if let Ok(mut style) = highlights.get_mut(parts.highlight) {
+                style.display = match grid.selected == Some(index) {
+                    true => Display::Flex,
+                    false => Display::None,
+                };
+            }
+        }
+    }
+}
+
+/// Updates the icons and stack counts of each slot grid bound to an entity
+/// with the given source component, when either has changed.
+fn sync_slot_grids<T: SlotSource>(
+    sources: Query<Ref<T>>,
+    grids: Query<Ref<SlotGrid>>,
+    mut icons: Query<(&mut UiImage, &mut Visibility)>,
+    mut texts: Query<&mut Text>,
+) {
+    for grid in &grids {
+        let Some(source) = grid.source.and_then(|source| sources.get(source).ok()) else {
+            continue;
+        };
+
+        if !source.is_changed() && !grid.is_changed() {
+            continue;
+        }
+
+        for (index, parts) in grid.slots.iter().enumerate() {
+            let content = source.slot(index);
+
+            if let Ok((mut image, mut visibility)) = icons.get_mut(parts.icon) {
+                let shown = match &content {
+                    Some(content) => {
+                        if image.texture != content.icon {
+                            image.texture = content.icon.clone();
+                        }
+                        Visibility::Inherited
+                    }
+                    None => Visibility::Hidden,
+                };
+
+                if *visibility != shown {
+                    *visibility = shown;
+                }
+            }
+
+            let count = match &content {
+                Some(content) if content.count > 1 => content.count.to_string(),
+                _ => String::new(),
+            };
+
+            if let Ok(mut text) = texts.get_mut(parts.count) {
+                if let Some(section) = text.sections.first_mut() {
+                    if section.value != count {
+                        section.value = count;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,468 @@
+//! Contains the components, events, and systems that edit the value of a
+//! numeric spinner.
+
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::cursor_position;
+use crate::prelude::{
+    BackInput,
+    DataBlock,
+    NodeBackground,
+    NodeBundleBuilder,
+    NodeBundleType,
+    NodePosition,
+    NodeSpinner,
+    NodeText,
+    NumberFormat,
+    UiFocus,
+    UiNode,
+    VirtualKeyPressed,
+};
+
+/// The distance, in logical pixels, the cursor must move while pressing the
+/// label of a spinner before it is treated as a drag instead of a click.
+const DRAG_THRESHOLD: f32 = 3.0;
+
+/// A component that stores the value and settings of a numeric spinner.
+///
+/// Game code may write to [`Spinner::value`] to change the value.
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Spinner {
+    /// The current value of the spinner.
+    pub value: f32,
+
+    /// The smallest value the spinner may hold.
+    pub min: f32,
+
+    /// The largest value the spinner may hold.
+    pub max: f32,
+
+    /// The amount the value changes per button press, arrow key, or scrub
+    /// increment.
+    pub step: f32,
+
+    /// How the value is formatted and rounded.
+    pub format: NumberFormat,
+
+    /// The distance, in logical pixels, the label must be dragged to change
+    /// the value by one step.
+    pub scrub_distance: f32,
+
+    /// The value that is currently displayed.
+    pub(crate) shown: f32,
+
+    /// The text that has been typed into the spinner, while it is being
+    /// edited.
+    pub(crate) entry: Option<String>,
+}
+
+impl Spinner {
+    /// Clamps the given value to the range of the spinner and rounds it
+    /// according to the number format.
+    pub fn constrain(&self, value: f32) -> f32 {
+        let value = value.max(self.min).min(self.max);
+        match self.format {
+            NumberFormat::Integer => value.round(),
+            NumberFormat::Float { .. } => value,
+        }
+    }
+
+    /// Returns true if a value is currently being typed into the spinner.
+    pub fn is_editing(&self) -> bool {
+        self.entry.is_some()
+    }
+
+    /// Parses the typed text and applies it as the new value, ending the
+    /// current edit. Text that is not a valid number is discarded.
+    fn commit_entry(&mut self) {
+        let Some(entry) = self.entry.take() else {
+            return;
+        };
+
+        if let Ok(value) = entry.trim().parse::<f32>() {
+            self.value = self.constrain(value);
+        }
+    }
+}
+
+/// An event that is sent when the value of a spinner changes.
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct SpinnerChanged {
+    /// The spinner entity.
+    pub spinner: Entity,
+
+    /// The previous value of the spinner.
+    pub previous: f32,
+
+    /// The new value of the spinner.
+    pub value: f32,
+}
+
+/// A component for the buttons that step the value of a spinner.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct SpinnerButton {
+    /// The spinner this button belongs to.
+    spinner: Entity,
+
+    /// The number of steps to add to the value when pressed.
+    steps: f32,
+}
+
+/// A component for the label of a spinner, which may be dragged to scrub the
+/// value or clicked to type a new value.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct SpinnerScrub {
+    /// The spinner this label belongs to.
+    spinner: Entity,
+
+    /// The cursor position and value when the label was pressed, if it is
+    /// currently being pressed.
+    start: Option<(f32, f32)>,
+
+    /// Whether the cursor has moved far enough to be treated as a drag.
+    dragged: bool,
+}
+
+/// A component for the text that displays the value of a spinner.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct SpinnerText {
+    /// The spinner this text belongs to.
+    spinner: Entity,
+}
+
+/// This plugin handles editing the values of spinners.
+pub(crate) struct SpinnerPlugin;
+impl Plugin for SpinnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Spinner>()
+            .add_event::<SpinnerChanged>()
+            .add_systems(
+                Update,
+                (
+                    step_spinner_on_click,
+                    scrub_spinner_label,
+                    type_spinner_entry,
+                    commit_unfocused_spinners,
+                    update_spinner_text,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Consumes the given container node builder and creates a spinner, along with
+/// its buttons and label.
+///
+/// Returns the entity of the spinner.
+pub(crate) fn build_spinner(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut container: NodeBundleBuilder,
+    spinner: NodeSpinner,
+) -> Entity {
+    let mut text = spinner.text.clone();
+    text.sections.truncate(1);
+    let button_background = spinner.button_background.clone();
+    let button_width = spinner.button_width;
+    let value = spinner
+        .format
+        .format(spinner.value.max(spinner.min).min(spinner.max));
+
+    spinner.apply_to_node(&mut container, asset_server);
+    let spinner_id = container.build(cmd, asset_server);
+
+    build_spinner_button(
+        cmd,
+        asset_server,
+        spinner_id,
+        button_background.clone(),
+        button_width,
+        with_text(&text, "-"),
+        -1.0,
+    );
+
+    let mut label = NodeBundleBuilder::default();
+    label.set_parent(Some(spinner_id));
+    label.insert((
+        SpinnerScrub {
+            spinner: spinner_id,
+            start: None,
+            dragged: false,
+        },
+        Interaction::default(),
+    ));
+
+    let style = label.get_style_mut();
+    style.flex_grow = 1.0;
+    style.overflow = Overflow::clip();
+
+    text.apply_to_parent(&mut label, asset_server);
+    let label_id = label.build(cmd, asset_server);
+
+    let mut text_node = NodeBundleBuilder::default();
+    text_node.set_parent(Some(label_id));
+    text_node.insert(SpinnerText {
+        spinner: spinner_id,
+    });
+    with_text(&text, value).apply_to_node(&mut text_node, asset_server);
+    text_node.build(cmd, asset_server);
+
+    build_spinner_button(
+        cmd,
+        asset_server,
+        spinner_id,
+        button_background,
+        button_width,
+        with_text(&text, "+"),
+        1.0,
+    );
+
+    spinner_id
+}
+
+/// Creates a copy of the given text with the contents of its first section
+/// replaced.
+fn with_text<T: Into<String>>(text: &NodeText, value: T) -> NodeText {
+    let mut text = text.clone();
+    if let Some(section) = text.sections.first_mut() {
+        section.text = value.into();
+    }
+    text
+}
+
+/// Creates a button that steps the value of the given spinner when pressed.
+fn build_spinner_button(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    spinner: Entity,
+    background: NodeBackground,
+    width: Val,
+    text: NodeText,
+    steps: f32,
+) {
+    let mut button = NodeBundleBuilder::default();
+    button.set_parent(Some(spinner));
+    background.apply_to_node(&mut button, asset_server);
+    button.bundle_type(NodeBundleType::Button);
+    button.insert(SpinnerButton { spinner, steps });
+
+    let style = button.get_style_mut();
+    style.flex_shrink = 0.0;
+    style.width = width;
+    button.set_children(vec![UiNode::Text {
+        background: default(),
+        position: NodePosition::Relative {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+        },
+        text,
+    }]);
+
+    button.build(cmd, asset_server);
+}
+
+/// Steps the value of a spinner when one of its buttons is clicked.
+fn step_spinner_on_click(
+    mut focus: ResMut<UiFocus>,
+    buttons: Query<(&Interaction, &SpinnerButton), Changed<Interaction>>,
+    mut spinners: Query<&mut Spinner>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut spinner) = spinners.get_mut(button.spinner) else {
+            continue;
+        };
+
+        spinner.commit_entry();
+        spinner.value = spinner.constrain(spinner.value + spinner.step * button.steps);
+        focus.set_if_neq(UiFocus {
+            entity: Some(button.spinner),
+        });
+    }
+}
+
+/// Scrubs the value of a spinner while its label is dragged, and starts typed
+/// entry when the label is clicked without dragging.
+fn scrub_spinner_label(
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    mut labels: Query<(&Interaction, &mut SpinnerScrub)>,
+    mut spinners: Query<&mut Spinner>,
+) {
+    let cursor = cursor_position(&windows, &ui_scale);
+
+    for (interaction, mut scrub) in &mut labels {
+        let Ok(mut spinner) = spinners.get_mut(scrub.spinner) else {
+            continue;
+        };
+
+        if mouse.just_pressed(MouseButton::Left) && *interaction == Interaction::Pressed {
+            if let Some(cursor) = cursor {
+                spinner.commit_entry();
+                scrub.start = Some((cursor.x, spinner.value));
+                scrub.dragged = false;
+            }
+            continue;
+        }
+
+        let Some((start_x, start_value)) = scrub.start else {
+            continue;
+        };
+
+        if !mouse.pressed(MouseButton::Left) {
+            // Clicking starts an empty entry, so that typed digits replace the
+            // value rather than being appended to it.
+            if !scrub.dragged {
+                spinner.entry = Some(String::new());
+            }
+
+            scrub.start = None;
+            continue;
+        }
+
+        let Some(cursor) = cursor else {
+            continue;
+        };
+
+        let delta = cursor.x - start_x;
+        if delta.abs() >= DRAG_THRESHOLD {
+            scrub.dragged = true;
+        }
+
+        if scrub.dragged && spinner.scrub_distance > 0.0 {
+            let steps = (delta / spinner.scrub_distance).round();
+            let value = spinner.constrain(start_value + steps * spinner.step);
+            if spinner.value != value {
+                spinner.value = value;
+            }
+        }
+    }
+}
+
+/// Handles typed entry and the up and down arrow keys for the focused spinner,
+/// from either the keyboard or the virtual keyboard.
+fn type_spinner_entry(
+    mut keyboard: EventReader<KeyboardInput>,
+    mut virtual_keys: EventReader<VirtualKeyPressed>,
+    focus: Res<UiFocus>,
+    mut back: ResMut<BackInput>,
+    mut spinners: Query<&mut Spinner>,
+) {
+    let Some((spinner_id, mut spinner)) = focus
+        .entity
+        .and_then(|entity| spinners.get_mut(entity).ok().map(|s| (entity, s)))
+    else {
+        keyboard.clear();
+        virtual_keys.clear();
+        return;
+    };
+
+    let keys = keyboard
+        .read()
+        .filter(|input| input.state == ButtonState::Pressed)
+        .map(|input| &input.logical_key);
+    let virtual_keys = virtual_keys
+        .read()
+        .filter(|input| input.target == spinner_id)
+        .map(|input| &input.key);
+
+    for key in keys.chain(virtual_keys) {
+        if *key == Key::Escape && spinner.is_editing() {
+            back.consume();
+        }
+
+        type_spinner_key(&mut spinner, key);
+    }
+}
+
+/// Applies a single pressed key to the given spinner.
+///
+/// Typing while the spinner is not being edited starts a new entry. Enter
+/// applies the typed value, while escape discards it.
+fn type_spinner_key(spinner: &mut Spinner, key: &Key) {
+    match key {
+        Key::Character(chars) => {
+            let allow_decimal = matches!(spinner.format, NumberFormat::Float { .. });
+            for c in chars.chars() {
+                if c.is_ascii_digit() || c == '-' || (c == '.' && allow_decimal) {
+                    spinner.entry.get_or_insert_with(String::new).push(c);
+                }
+            }
+        }
+
+        Key::Backspace => {
+            if let Some(entry) = &mut spinner.entry {
+                entry.pop();
+            }
+        }
+
+        Key::Enter => spinner.commit_entry(),
+        Key::Escape => spinner.entry = None,
+
+        Key::ArrowUp if !spinner.is_editing() => {
+            spinner.value = spinner.constrain(spinner.value + spinner.step);
+        }
+
+        Key::ArrowDown if !spinner.is_editing() => {
+            spinner.value = spinner.constrain(spinner.value - spinner.step);
+        }
+
+        _ => {}
+    }
+}
+
+/// Applies the typed value of any spinner that has lost focus.
+fn commit_unfocused_spinners(focus: Res<UiFocus>, mut spinners: Query<(Entity, &mut Spinner)>) {
+    for (entity, mut spinner) in &mut spinners {
+        if spinner.is_editing() && !focus.is_focused(entity) {
+            spinner.commit_entry();
+        }
+    }
+}
+
+/// Updates the text of each spinner that has changed, and sends an event when
+/// its value changes.
+fn update_spinner_text(
+    mut changed: EventWriter<SpinnerChanged>,
+    mut spinners: Query<(Entity, &mut Spinner), Changed<Spinner>>,
+    mut texts: Query<(&SpinnerText, &mut Text)>,
+) {
+    for (spinner_id, mut spinner) in &mut spinners {
+        let value = spinner.constrain(spinner.value);
+        if spinner.value != value {
+            spinner.value = value;
+        }
+
+        if spinner.shown != value {
+            changed.send(SpinnerChanged {
+                spinner: spinner_id,
+                previous: spinner.shown,
+                value,
+            });
+            spinner.shown = value;
+        }
+
+        let display = match &spinner.entry {
+            Some(entry) => format!("{entry}|"),
+            None => spinner.format.format(value),
+        };
+
+        for (text, mut contents) in &mut texts {
+            if text.spinner != spinner_id {
+                continue;
+            }
+
+            if let Some(section) = contents.sections.first_mut() {
+                section.value.clone_from(&display);
+            }
+        }
+    }
+}
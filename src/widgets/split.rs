@@ -0,0 +1,269 @@
+//! Contains the components and systems that resize the panes of a split pane
+//! when its divider is dragged.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::cursor_position;
+use crate::prelude::{NodeBundleBuilder, NodeSplit, SplitDirection, UiNode};
+
+/// The longest time, in seconds, between two presses of a divider for them to
+/// count as a double-click.
+const DOUBLE_CLICK_TIME: f64 = 0.3;
+
+/// A component that stores the fraction of the area of a split pane given to
+/// its first pane, between `0.0` and `1.0`.
+///
+/// Game code may write to this component to move the divider.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct SplitRatio(pub f32);
+
+/// A component that stores the settings of a split pane.
+#[derive(Debug, Clone, Component)]
+pub struct SplitPane {
+    /// The direction in which the area is divided.
+    pub direction: SplitDirection,
+
+    /// The smallest sizes of the first and second panes while dragging the
+    /// divider, in logical pixels.
+    pub min_sizes: [f32; 2],
+
+    /// Whether double-clicking the divider collapses the smaller pane.
+    pub collapsible: bool,
+
+    /// The ratio to restore when the collapsed pane is expanded, if a pane is
+    /// collapsed.
+    collapsed: Option<f32>,
+
+    /// The node that contains the first pane.
+    first: Entity,
+
+    /// The thickness of the divider, in logical pixels.
+    divider_size: f32,
+}
+
+impl SplitPane {
+    /// Returns true if one of the panes is currently collapsed.
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed.is_some()
+    }
+}
+
+/// A component for the divider of a split pane.
+#[derive(Debug, Clone, Copy, Component)]
+struct SplitDivider {
+    /// The split pane this divider belongs to.
+    split: Entity,
+
+    /// The cursor position along the split axis and the ratio when the drag
+    /// started, if the divider is being dragged.
+    drag: Option<(f32, f32)>,
+
+    /// The time, in seconds, the divider was last pressed.
+    last_press: f64,
+
+    /// The colors of the divider while idle and while hovered or dragged.
+    colors: [Color; 2],
+}
+
+/// This plugin handles dragging the dividers of split panes.
+pub(crate) struct SplitPlugin;
+impl Plugin for SplitPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SplitRatio>().add_systems(
+            Update,
+            (
+                drag_split_dividers,
+                highlight_split_dividers,
+                resize_split_panes,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Consumes the given container node builder and creates a split pane, along
+/// with its two panes and the divider between them.
+///
+/// Returns the entity of the split pane.
+pub(crate) fn build_split(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut container: NodeBundleBuilder,
+    split: NodeSplit,
+    first: UiNode,
+    second: UiNode,
+) -> Entity {
+    let horizontal = split.direction == SplitDirection::Horizontal;
+
+    let style = container.get_style_mut();
+    style.flex_direction = match horizontal {
+        true => FlexDirection::Row,
+        false => FlexDirection::Column,
+    };
+    style.overflow = Overflow::clip();
+    let split_id = container.build(cmd, asset_server);
+
+    let pane = |cmd: &mut Commands, node: UiNode, grow: bool| {
+        let mut pane = NodeBundleBuilder::default();
+        pane.set_parent(Some(split_id));
+
+        let style = pane.get_style_mut();
+        style.flex_direction = FlexDirection::Column;
+        style.overflow = Overflow::clip();
+        style.flex_shrink = 1.0;
+        if grow {
+            style.flex_grow = 1.0;
+            style.flex_basis = Val::Px(0.0);
+        }
+
+        pane.set_children(vec![node]);
+        pane.build(cmd, asset_server)
+    };
+
+    let first_id = pane(cmd, first, false);
+
+    let mut divider = NodeBundleBuilder::default();
+    divider.set_parent(Some(split_id));
+    divider.insert((
+        SplitDivider {
+            split: split_id,
+            drag: None,
+            last_press: f64::NEG_INFINITY,
+            colors: [split.divider_color, split.divider_hover_color],
+        },
+        BackgroundColor(split.divider_color),
+        Interaction::default(),
+    ));
+
+    let style = divider.get_style_mut();
+    style.flex_shrink = 0.0;
+    match horizontal {
+        true => style.width = Val::Px(split.divider_size),
+        false => style.height = Val::Px(split.divider_size),
+    }
+    divider.build(cmd, asset_server);
+
+    pane(cmd, second, true);
+
+    cmd.entity(split_id).insert((
+        SplitPane {
+            direction: split.direction,
+            min_sizes: split.min_sizes,
+            collapsible: split.collapsible,
+            collapsed: None,
+            first: first_id,
+            divider_size: split.divider_size,
+        },
+        SplitRatio(split.ratio),
+    ));
+
+    split_id
+}
+
+/// Moves the divider of a split pane while it is dragged, and collapses or
+/// expands the smaller pane when it is double-clicked.
+fn drag_split_dividers(
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    ui_scale: Res<UiScale>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut dividers: Query<(&Interaction, &mut SplitDivider)>,
+    mut splits: Query<(&Node, &mut SplitPane, &mut SplitRatio)>,
+) {
+    let cursor = cursor_position(&windows, &ui_scale);
+
+    for (interaction, mut divider) in &mut dividers {
+        let Ok((node, mut split, mut ratio)) = splits.get_mut(divider.split) else {
+            continue;
+        };
+
+        if mouse.just_pressed(MouseButton::Left) && *interaction == Interaction::Pressed {
+            let now = time.elapsed_seconds_f64();
+            let double_click = now - divider.last_press <= DOUBLE_CLICK_TIME;
+            divider.last_press = now;
+
+            if double_click && split.collapsible {
+                divider.drag = None;
+                divider.last_press = f64::NEG_INFINITY;
+                match split.collapsed.take() {
+                    Some(restore) => ratio.0 = restore,
+                    None => {
+                        split.collapsed = Some(ratio.0);
+                        ratio.0 = if ratio.0 <= 0.5 { 0.0 } else { 1.0 };
+                    }
+                }
+                continue;
+            }
+
+            divider.drag = cursor.map(|cursor| (split.direction.axis(cursor), ratio.0));
+            continue;
+        }
+
+        let Some((start, start_ratio)) = divider.drag else {
+            continue;
+        };
+
+        if !mouse.pressed(MouseButton::Left) {
+            divider.drag = None;
+            continue;
+        }
+
+        let Some(cursor) = cursor else {
+            continue;
+        };
+
+        let total = split.direction.axis(node.size());
+        if total <= 0.0 {
+            continue;
+        }
+
+        let min = split.min_sizes[0] / total;
+        let max = (1.0 - (split.min_sizes[1] + split.divider_size) / total).max(min);
+        let value = (start_ratio + (split.direction.axis(cursor) - start) / total).clamp(min, max);
+
+        if ratio.0 != value {
+            ratio.0 = value;
+            if split.collapsed.is_some() {
+                split.collapsed = None;
+            }
+        }
+    }
+}
+
+/// Highlights each divider that is hovered or being dragged.
+fn highlight_split_dividers(
+    mut dividers: Query<(&Interaction, &SplitDivider, &mut BackgroundColor)>,
+) {
+    for (interaction, divider, mut background) in &mut dividers {
+        let active = *interaction != Interaction::None || divider.drag.is_some();
+        let color = divider.colors[active as usize];
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+/// Resizes the first pane of each split pane whose ratio has changed.
+fn resize_split_panes(
+    mut splits: Query<(&SplitPane, &mut SplitRatio), Changed<SplitRatio>>,
+    mut panes: Query<&mut Style>,
+) {
+    for (split, mut ratio) in &mut splits {
+        let value = ratio.0.clamp(0.0, 1.0);
+        if ratio.0 != value {
+            ratio.0 = value;
+        }
+
+        let Ok(mut style) = panes.get_mut(split.first) else {
+            continue;
+        };
+
+        let size = Val::Percent(value * 100.0);
+        match split.direction {
+            SplitDirection::Horizontal => style.width = size,
+            SplitDirection::Vertical => style.height = size,
+        }
+    }
+}
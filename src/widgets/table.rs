@@ -0,0 +1,1029 @@
+//! Contains the components, events, and systems that sort, resize, select,
+//! and spawn the rows of a table.
+
+use std::ops::Range;
+
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::window::PrimaryWindow;
+
+use super::cursor_position;
+use super::scroll::update_scroll_bounds;
+use crate::nodes::build_scroll_view;
+use crate::prelude::{
+    AnchorPoint,
+    DataBlock,
+    NodeBundleBuilder,
+    NodeScroll,
+    NodeTable,
+    NodeTableRow,
+    NodeText,
+    ScrollContent,
+    ScrollDirection,
+    ScrollPosition,
+    ScrollView,
+    SortOrder,
+    TableCell,
+};
+
+/// The width of the handle used to resize a column, in logical pixels.
+const RESIZE_HANDLE_WIDTH: f32 = 6.0;
+
+/// The runtime state of a single column of a table.
+#[derive(Debug, Clone)]
+struct TableColumn {
+    /// The width of the column, in logical pixels.
+    width: f32,
+
+    /// The smallest width the column may be resized to, in logical pixels.
+    min_width: f32,
+
+    /// How the contents of each cell are aligned within the column.
+    anchor: AnchorPoint,
+
+    /// Whether the rows may be sorted by this column.
+    sortable: bool,
+}
+
+/// A component that stores the rows and state of a table.
+///
+/// Game code may use the methods of this component to replace the rows,
+/// sort them, or change the selection.
+#[derive(Debug, Clone, Component)]
+pub struct Table {
+    /// The rows of the table.
+    rows: Vec<NodeTableRow>,
+
+    /// The columns of the table.
+    columns: Vec<TableColumn>,
+
+    /// The column and order the rows are sorted by, if any.
+    sort: Option<(usize, SortOrder)>,
+
+    /// The indices of the rows, in the order they are displayed.
+    order: Vec<usize>,
+
+    /// The index of the selected row, if any.
+    selected: Option<usize>,
+
+    /// If set, only the visible rows are spawned, along with this number of
+    /// additional rows above and below the visible area.
+    virtualized: Option<usize>,
+
+    /// The height of each row, in logical pixels.
+    row_height: f32,
+
+    /// The background colors of even and odd rows.
+    row_colors: [Color; 2],
+
+    /// The background color of the selected row.
+    selected_color: Color,
+
+    /// The style of the text displayed in text cells.
+    cell_text: NodeText,
+
+    /// The text displayed next to the header of a sorted column.
+    sort_icons: [String; 2],
+
+    /// The row that contains the column headers.
+    header: Entity,
+
+    /// The scroll view that contains the rows.
+    body: Entity,
+
+    /// The range of displayed rows that are currently spawned.
+    shown: Range<usize>,
+
+    /// Whether the contents of all spawned rows must be rebuilt on the next
+    /// update.
+    dirty: bool,
+
+    /// Whether the spawned rows must be reordered and recolored on the next
+    /// update, such as after sorting or selecting a row.
+    changed: bool,
+}
+
+impl Table {
+    /// Gets the rows of the table, in their original order.
+    pub fn rows(&self) -> &[NodeTableRow] {
+        &self.rows
+    }
+
+    /// Replaces the rows of the table.
+    ///
+    /// The new rows are sorted using the current sort order, and the
+    /// selection is cleared.
+    pub fn set_rows(&mut self, rows: Vec<NodeTableRow>) {
+        self.rows = rows;
+        self.selected = None;
+        self.dirty = true;
+        self.sort_rows();
+    }
+
+    /// Gets the column and order the rows are sorted by, if any.
+    pub fn sort(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Sorts the rows by the given column and order.
+    pub fn sort_by(&mut self, column: usize, order: SortOrder) {
+        self.sort = Some((column, order));
+        self.sort_rows();
+    }
+
+    /// Gets the index of the selected row within [`Table::rows`], if any.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects the row with the given index within [`Table::rows`], or clears
+    /// the selection.
+    pub fn select(&mut self, row: Option<usize>) {
+        self.selected = row.filter(|&row| row < self.rows.len());
+        self.changed = true;
+    }
+
+    /// Gets the width of the given column, in logical pixels.
+    pub fn column_width(&self, column: usize) -> Option<f32> {
+        self.columns.get(column).map(|c| c.width)
+    }
+
+    /// Recomputes the display order of the rows and marks them to be updated.
+    fn sort_rows(&mut self) {
+        self.order = (0 .. self.rows.len()).collect();
+
+        if let Some((column, order)) = self.sort {
+            let rows = &self.rows;
+            self.order.sort_by(|&a, &b| {
+                let ordering = match (rows[a].cells.get(column), rows[b].cells.get(column)) {
+                    (Some(a), Some(b)) => a.compare(b),
+                    (a, b) => a.is_some().cmp(&b.is_some()),
+                };
+
+                match order {
+                    SortOrder::Ascending => ordering,
+                    SortOrder::Descending => ordering.reverse(),
+                }
+            });
+        }
+
+        self.changed = true;
+    }
+
+    /// Gets the range of displayed rows that should currently be spawned for
+    /// the given scroll offset and viewport height.
+    fn visible_range(&self, offset: f32, viewport_height: f32) -> Range<usize> {
+        let count = self.order.len();
+        let Some(buffer) = self.virtualized else {
+            return 0 .. count;
+        };
+
+        if self.row_height <= 0.0 {
+            return 0 .. 0;
+        }
+
+        let first = (offset / self.row_height).floor().max(0.0) as usize;
+        let last = ((offset + viewport_height) / self.row_height)
+            .ceil()
+            .max(0.0) as usize;
+
+        first.saturating_sub(buffer).min(count) .. (last + buffer).min(count)
+    }
+
+    /// Gets the total width of all columns.
+    fn total_width(&self) -> f32 {
+        self.columns.iter().map(|c| c.width).sum()
+    }
+
+    /// Gets the background color of the row displayed at the given position.
+    fn row_color(&self, position: usize) -> Color {
+        match self.selected == Some(self.order[position]) {
+            true => self.selected_color,
+            false => self.row_colors[position % 2],
+        }
+    }
+}
+
+/// An event that is sent when the rows of a table are sorted by clicking a
+/// column header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct TableSorted {
+    /// The table entity.
+    pub table: Entity,
+
+    /// The column the rows are sorted by.
+    pub column: usize,
+
+    /// The order the rows are sorted in.
+    pub order: SortOrder,
+}
+
+/// An event that is sent when a row of a table is selected by clicking it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct TableRowSelected {
+    /// The table entity.
+    pub table: Entity,
+
+    /// The index of the selected row within [`Table::rows`].
+    pub row: usize,
+}
+
+/// A component for any node whose width matches the width of a table column.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct TableColumnCell {
+    /// The table this cell belongs to.
+    table: Entity,
+
+    /// The column this cell belongs to.
+    column: usize,
+}
+
+/// A component for a column header, which sorts the table when clicked.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct TableHeader {
+    /// The table this header belongs to.
+    table: Entity,
+
+    /// The column this header belongs to.
+    column: usize,
+}
+
+/// A component for the text that displays the sort order of a column.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct TableSortIcon {
+    /// The table this icon belongs to.
+    table: Entity,
+
+    /// The column this icon belongs to.
+    column: usize,
+}
+
+/// A component for the handle on the edge of a column header that resizes
+/// the column when dragged.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct TableResizeHandle {
+    /// The table this handle belongs to.
+    table: Entity,
+
+    /// The column this handle resizes.
+    column: usize,
+
+    /// The cursor position and column width when the handle was pressed, if
+    /// it is currently being dragged.
+    drag_start: Option<(f32, f32)>,
+}
+
+/// A component for a spawned row of a table.
+///
+/// Rows are recycled as the table is scrolled, so the row they display may
+/// change.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct TableRow {
+    /// The table this row belongs to.
+    table: Entity,
+
+    /// The index of the row within [`Table::rows`].
+    index: usize,
+
+    /// The position the row is displayed at.
+    position: usize,
+}
+
+/// This plugin handles sorting, resizing, selecting, and spawning the rows of
+/// tables.
+pub(crate) struct TablePlugin;
+impl Plugin for TablePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TableSorted>()
+            .add_event::<TableRowSelected>()
+            .add_systems(
+                Update,
+                (
+                    sort_table_on_header_click,
+                    resize_table_columns,
+                    select_table_row_on_click,
+                    update_table_rows,
+                    scroll_table_headers,
+                )
+                    .chain()
+                    .after(update_scroll_bounds),
+            );
+    }
+}
+
+/// Consumes the given container node builder and creates a table, along with
+/// its header and scrollable body.
+///
+/// Returns the entity of the table.
+pub(crate) fn build_table(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut container: NodeBundleBuilder,
+    table: NodeTable,
+) -> Entity {
+    let style = container.get_style_mut();
+    style.flex_direction = FlexDirection::Column;
+    style.overflow = Overflow::clip();
+    let table_id = container.build(cmd, asset_server);
+
+    let mut header = NodeBundleBuilder::default();
+    header.set_parent(Some(table_id));
+    table
+        .header_background
+        .clone()
+        .apply_to_node(&mut header, asset_server);
+
+    let style = header.get_style_mut();
+    style.flex_direction = FlexDirection::Row;
+    style.flex_shrink = 0.0;
+    style.height = Val::Px(table.header_height);
+    let header_id = header.build(cmd, asset_server);
+
+    for (column, def) in table.columns.iter().enumerate() {
+        let mut cell = NodeBundleBuilder::default();
+        cell.set_parent(Some(header_id));
+        cell.insert((
+            TableHeader {
+                table: table_id,
+                column,
+            },
+            TableColumnCell {
+                table: table_id,
+                column,
+            },
+            Interaction::default(),
+        ));
+
+        let mut title = def.header.clone();
+        title.anchor_point = def.anchor;
+        title.apply_to_parent(&mut cell, asset_server);
+
+        let style = cell.get_style_mut();
+        style.flex_direction = FlexDirection::Row;
+        style.flex_shrink = 0.0;
+        style.width = Val::Px(def.width);
+        style.height = Val::Percent(100.0);
+        style.overflow = Overflow::clip();
+        let cell_id = cell.build(cmd, asset_server);
+
+        let mut title_node = NodeBundleBuilder::default();
+        title_node.set_parent(Some(cell_id));
+        title.clone().apply_to_node(&mut title_node, asset_server);
+        title_node.build(cmd, asset_server);
+
+        let mut icon = NodeBundleBuilder::default();
+        icon.set_parent(Some(cell_id));
+        icon.insert(TableSortIcon {
+            table: table_id,
+            column,
+        });
+        replace_text(&title, String::new()).apply_to_node(&mut icon, asset_server);
+        icon.build(cmd, asset_server);
+
+        if table.resizable {
+            let mut handle = NodeBundleBuilder::default();
+            handle.set_parent(Some(cell_id));
+            handle.insert((
+                TableResizeHandle {
+                    table: table_id,
+                    column,
+                    drag_start: None,
+                },
+                Interaction::default(),
+                FocusPolicy::Block,
+            ));
+
+            let style = handle.get_style_mut();
+            style.position_type = PositionType::Absolute;
+            style.right = Val::Px(0.0);
+            style.width = Val::Px(RESIZE_HANDLE_WIDTH);
+            style.height = Val::Percent(100.0);
+            handle.build(cmd, asset_server);
+        }
+    }
+
+    let mut body = NodeBundleBuilder::default();
+    body.set_parent(Some(table_id));
+
+    let style = body.get_style_mut();
+    style.flex_grow = 1.0;
+    style.width = Val::Percent(100.0);
+
+    let scroll = NodeScroll {
+        direction: ScrollDirection::Both,
+        ..default()
+    };
+    let body_id = build_scroll_view(cmd, asset_server, body, scroll, default());
+
+    let mut state = Table {
+        rows: table.rows,
+        columns: table
+            .columns
+            .into_iter()
+            .map(|c| TableColumn {
+                width: c.width,
+                min_width: c.min_width,
+                anchor: c.anchor,
+                sortable: c.sortable,
+            })
+            .collect(),
+        sort: table.sort,
+        order: Vec::new(),
+        selected: None,
+        virtualized: table.virtualized,
+        row_height: table.row_height,
+        row_colors: table.row_colors,
+        selected_color: table.selected_color,
+        cell_text: table.cell_text,
+        sort_icons: table.sort_icons,
+        header: header_id,
+        body: body_id,
+        shown: 0 .. 0,
+        dirty: true,
+        changed: true,
+    };
+    state.cell_text.sections.truncate(1);
+    state.sort_rows();
+
+    cmd.entity(table_id).insert(state);
+    table_id
+}
+
+/// Creates a copy of the given text with the contents of its first section
+/// replaced.
+fn replace_text(text: &NodeText, value: String) -> NodeText {
+    let mut text = text.clone();
+    text.sections.truncate(1);
+    if let Some(section) = text.sections.first_mut() {
+        section.text = value;
+    }
+    text
+}
+
+/// Creates a spawned row of a table within the given content node, displaying
+/// the row at the given position.
+///
+/// Returns the entity of the row.
+fn build_table_row(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    table_id: Entity,
+    table: &Table,
+    content: Entity,
+    position: usize,
+) -> Entity {
+    let mut row = NodeBundleBuilder::default();
+    row.set_parent(Some(content));
+    row.insert((
+        TableRow {
+            table: table_id,
+            index: table.order[position],
+            position,
+        },
+        Interaction::default(),
+        BackgroundColor(table.row_color(position)),
+    ));
+
+    let style = row.get_style_mut();
+    style.position_type = PositionType::Absolute;
+    style.flex_direction = FlexDirection::Row;
+    style.left = Val::Px(0.0);
+    style.top = Val::Px(position as f32 * table.row_height);
+    style.width = Val::Percent(100.0);
+    style.height = Val::Px(table.row_height);
+    let row_id = row.build(cmd, asset_server);
+
+    build_table_cells(cmd, asset_server, table_id, table, row_id, position);
+    row_id
+}
+
+/// Creates the cells of the row displayed at the given position within the
+/// given row node.
+fn build_table_cells(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    table_id: Entity,
+    table: &Table,
+    row_id: Entity,
+    position: usize,
+) {
+    let index = table.order[position];
+    for (column, def) in table.columns.iter().enumerate() {
+        let mut cell = NodeBundleBuilder::default();
+        cell.set_parent(Some(row_id));
+        cell.insert(TableColumnCell {
+            table: table_id,
+            column,
+        });
+
+        let mut text = match table.rows[index].cells.get(column) {
+            Some(TableCell::Text(value)) => replace_text(&table.cell_text, value.clone()),
+            _ => replace_text(&table.cell_text, String::new()),
+        };
+        text.anchor_point = def.anchor;
+        text.apply_to_parent(&mut cell, asset_server);
+
+        let style = cell.get_style_mut();
+        style.flex_shrink = 0.0;
+        style.width = Val::Px(def.width);
+        style.height = Val::Percent(100.0);
+        style.overflow = Overflow::clip();
+        let cell_id = cell.build(cmd, asset_server);
+
+        match table.rows[index].cells.get(column) {
+            Some(TableCell::Node { node, .. }) => {
+                node.as_ref()
+                    .clone()
+                    .build_node(cmd, asset_server, Some(cell_id));
+            }
+
+            Some(TableCell::Text(_)) => {
+                let mut text_node = NodeBundleBuilder::default();
+                text_node.set_parent(Some(cell_id));
+                text.apply_to_node(&mut text_node, asset_server);
+                text_node.build(cmd, asset_server);
+            }
+
+            None => {}
+        }
+    }
+}
+
+/// Sorts a table by a column when its header is clicked, reversing the order
+/// if the table is already sorted by that column.
+fn sort_table_on_header_click(
+    mut sorted: EventWriter<TableSorted>,
+    headers: Query<(&Interaction, &TableHeader), Changed<Interaction>>,
+    mut tables: Query<&mut Table>,
+) {
+    for (interaction, header) in &headers {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut table) = tables.get_mut(header.table) else {
+            continue;
+        };
+
+        if !table.columns.get(header.column).is_some_and(|c| c.sortable) {
+            continue;
+        }
+
+        let order = match table.sort {
+            Some((column, order)) if column == header.column => order.reversed(),
+            _ => SortOrder::Ascending,
+        };
+
+        table.sort_by(header.column, order);
+        sorted.send(TableSorted {
+            table: header.table,
+            column: header.column,
+            order,
+        });
+    }
+}
+
+/// Resizes a column while the handle on the edge of its header is dragged.
+fn resize_table_columns(
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    mut handles: Query<(&Interaction, &mut TableResizeHandle)>,
+    mut tables: Query<&mut Table>,
+    mut cells: Query<(&TableColumnCell, &mut Style)>,
+) {
+    let cursor = cursor_position(&windows, &ui_scale);
+
+    for (interaction, mut handle) in &mut handles {
+        let Ok(mut table) = tables.get_mut(handle.table) else {
+            continue;
+        };
+
+        let Some(column) = table.columns.get(handle.column).cloned() else {
+            continue;
+        };
+
+        if mouse.just_pressed(MouseButton::Left) && *interaction == Interaction::Pressed {
+            handle.drag_start = cursor.map(|cursor| (cursor.x, column.width));
+            continue;
+        }
+
+        let Some((start_x, start_width)) = handle.drag_start else {
+            continue;
+        };
+
+        if !mouse.pressed(MouseButton::Left) {
+            handle.drag_start = None;
+            continue;
+        }
+
+        let Some(cursor) = cursor else {
+            continue;
+        };
+
+        let width = (start_width + cursor.x - start_x).max(column.min_width);
+        if width == column.width {
+            continue;
+        }
+
+        table.columns[handle.column].width = width;
+        for (cell, mut style) in &mut cells {
+            if cell.table == handle.table && cell.column == handle.column {
+                style.width = Val::Px(width);
+            }
+        }
+    }
+}
+
+/// Selects a row of a table when it is clicked.
+fn select_table_row_on_click(
+    mut selected: EventWriter<TableRowSelected>,
+    rows: Query<(&Interaction, &TableRow), Changed<Interaction>>,
+    mut tables: Query<&mut Table>,
+) {
+    for (interaction, row) in &rows {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut table) = tables.get_mut(row.table) else {
+            continue;
+        };
+
+        if table.selected != Some(row.index) {
+            table.select(Some(row.index));
+            selected.send(TableRowSelected {
+                table: row.table,
+                row: row.index,
+            });
+        }
+    }
+}
+
+/// Updates the size of the contents of each table, and spawns the rows that
+/// are currently visible.
+///
+/// Spawned rows are recycled as the table is scrolled or sorted. Rows that
+/// still display the same row at the same position are only recolored, and
+/// only the rows that display a different row have their cells rebuilt.
+fn update_table_rows(
+    mut cmd: Commands,
+    asset_server: Res<AssetServer>,
+    mut tables: Query<(Entity, &mut Table)>,
+    views: Query<(&ScrollView, &ScrollPosition, &Children)>,
+    mut contents: Query<(&mut Style, Option<&Children>), With<ScrollContent>>,
+    mut rows: Query<(&mut TableRow, &mut Style, &mut BackgroundColor), Without<ScrollContent>>,
+    mut icons: Query<(&TableSortIcon, &mut Text)>,
+) {
+    for (table_id, mut table) in &mut tables {
+        let Ok((view, position, children)) = views.get(table.body) else {
+            continue;
+        };
+
+        let Some(content) = children.iter().copied().find(|c| contents.contains(*c)) else {
+            continue;
+        };
+
+        let Ok((mut style, content_children)) = contents.get_mut(content) else {
+            continue;
+        };
+
+        let height = Val::Px(table.order.len() as f32 * table.row_height);
+        if style.height != height {
+            style.height = height;
+        }
+
+        let width = Val::Px(table.total_width());
+        if style.width != width {
+            style.width = width;
+        }
+
+        let visible = table.visible_range(position.offset.y, view.viewport_size.y);
+        if !table.dirty && !table.changed && table.shown == visible {
+            continue;
+        }
+
+        if table.changed || table.dirty {
+            update_sort_icons(table_id, &table, &mut icons);
+        }
+
+        let slots = content_children
+            .map(|c| c.iter().copied().filter(|c| rows.contains(*c)).collect())
+            .unwrap_or_else(Vec::new);
+
+        let mut shown = vec![false; visible.len()];
+        let mut free = Vec::new();
+        for slot in slots {
+            let Ok((row, _, mut color)) = rows.get_mut(slot) else {
+                continue;
+            };
+
+            let unchanged = !table.dirty
+                && visible.contains(&row.position)
+                && table.order.get(row.position) == Some(&row.index);
+
+            if !unchanged {
+                free.push(slot);
+                continue;
+            }
+
+            shown[row.position - visible.start] = true;
+            let row_color = table.row_color(row.position);
+            if color.0 != row_color {
+                color.0 = row_color;
+            }
+        }
+
+        for position in visible.clone().filter(|p| !shown[p - visible.start]) {
+            let Some(slot) = free.pop() else {
+                build_table_row(&mut cmd, &asset_server, table_id, &table, content, position);
+                continue;
+            };
+
+            let Ok((mut row, mut style, mut color)) = rows.get_mut(slot) else {
+                continue;
+            };
+
+            row.index = table.order[position];
+            row.position = position;
+            color.0 = table.row_color(position);
+            style.top = Val::Px(position as f32 * table.row_height);
+            style.height = Val::Px(table.row_height);
+
+            cmd.entity(slot).despawn_descendants();
+            build_table_cells(&mut cmd, &asset_server, table_id, &table, slot, position);
+        }
+
+        for slot in free {
+            cmd.entity(slot).despawn_recursive();
+        }
+
+        table.shown = visible;
+        table.dirty = false;
+        table.changed = false;
+    }
+}
+
+/// Moves the header of each table to match the horizontal scroll offset of its
+/// body, so that the headers stay aligned with their columns.
+fn scroll_table_headers(
+    tables: Query<&Table>,
+    views: Query<&ScrollPosition>,
+    mut headers: Query<&mut Style>,
+) {
+    for table in &tables {
+        let Ok(position) = views.get(table.body) else {
+            continue;
+        };
+
+        let Ok(mut header) = headers.get_mut(table.header) else {
+            continue;
+        };
+
+        let left = Val::Px(-position.offset.x);
+        if header.left != left {
+            header.left = left;
+        }
+    }
+}
+
+/// Displays the sort order of the given table next to the header of the
+/// column it is sorted by.
+fn update_sort_icons(
+    table_id: Entity,
+    table: &Table,
+    icons: &mut Query<(&TableSortIcon, &mut Text)>,
+) {
+    for (icon, mut text) in icons {
+        if icon.table != table_id {
+            continue;
+        }
+
+        let value = match table.sort {
+            Some((column, SortOrder::Ascending)) if column == icon.column => {
+                table.sort_icons[0].as_str()
+            }
+            Some((column, SortOrder::Descending)) if column == icon.column => {
+                table.sort_icons[1].as_str()
+            }
+            _ => "",
+        };
+
+        if let Some(section) = text.sections.first_mut() {
+            if section.value != value {
+                section.value = value.to_string();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::prelude::{TableNodeBuilder, UiNode};
+    use crate::widgets::test_app;
+
+    /// Creates a table state with a single column and the given rows, without
+    /// spawning it.
+    fn table(rows: &[Option<&str>]) -> Table {
+        let mut table = Table {
+            rows: rows
+                .iter()
+                .map(|cell| NodeTableRow {
+                    cells: cell.iter().map(|&cell| cell.into()).collect(),
+                })
+                .collect(),
+            columns: vec![TableColumn {
+                width: 100.0,
+                min_width: 20.0,
+                anchor: AnchorPoint::CenterLeft,
+                sortable: true,
+            }],
+            sort: None,
+            order: Vec::new(),
+            selected: None,
+            virtualized: None,
+            row_height: 20.0,
+            row_colors: [Color::BLACK, Color::GRAY],
+            selected_color: Color::BLUE,
+            cell_text: NodeText::default(),
+            sort_icons: default(),
+            header: Entity::PLACEHOLDER,
+            body: Entity::PLACEHOLDER,
+            shown: 0 .. 0,
+            dirty: false,
+            changed: false,
+        };
+        table.sort_rows();
+        table
+    }
+
+    /// Spawns a table with a single column, a row height of 20, and the given
+    /// rows, and updates it once.
+    fn spawn_table(app: &mut App, rows: &[&str], virtualized: Option<usize>) -> Entity {
+        let mut builder = TableNodeBuilder::default()
+            .column(NodeText::default(), 100.0, AnchorPoint::CenterLeft)
+            .row_height(20.0)
+            .rows(rows.iter().map(|&cell| vec![cell]))
+            .row_colors(Color::BLACK, Color::GRAY)
+            .selected_color(Color::BLUE);
+
+        if let Some(buffer) = virtualized {
+            builder = builder.virtualized(buffer);
+        }
+
+        let table_id =
+            app.world
+                .run_system_once(move |mut cmd: Commands, asset_server: Res<AssetServer>| {
+                    UiNode::from(builder.clone()).build(&mut cmd, &asset_server)
+                });
+        app.update();
+        table_id
+    }
+
+    /// Gets the position, row index, entity, and color of each spawned row,
+    /// ordered by position.
+    fn spawned_rows(app: &mut App) -> Vec<(usize, usize, Entity, Color)> {
+        let mut rows = app
+            .world
+            .query::<(Entity, &TableRow, &BackgroundColor)>()
+            .iter(&app.world)
+            .map(|(entity, row, color)| (row.position, row.index, entity, color.0))
+            .collect::<Vec<_>>();
+        rows.sort_by_key(|row| row.0);
+        rows
+    }
+
+    #[test]
+    fn unsorted_rows_keep_their_order() {
+        let table = table(&[Some("b"), Some("c"), Some("a")]);
+        assert_eq!(table.order, [0, 1, 2]);
+    }
+
+    #[test]
+    fn sort_rows_compares_numbers_numerically() {
+        let mut table = table(&[Some("10"), Some("9"), Some("100"), Some("-1")]);
+
+        table.sort_by(0, SortOrder::Ascending);
+        assert_eq!(table.order, [3, 1, 0, 2]);
+
+        table.sort_by(0, SortOrder::Descending);
+        assert_eq!(table.order, [2, 0, 1, 3]);
+    }
+
+    #[test]
+    fn sort_rows_compares_text_as_strings() {
+        let mut table = table(&[Some("b"), Some("10"), Some("a")]);
+
+        table.sort_by(0, SortOrder::Ascending);
+        assert_eq!(table.order, [1, 2, 0]);
+    }
+
+    #[test]
+    fn sort_rows_places_missing_cells_first() {
+        let mut table = table(&[Some("b"), None, Some("a")]);
+
+        table.sort_by(0, SortOrder::Ascending);
+        assert_eq!(table.order, [1, 2, 0]);
+
+        table.sort_by(0, SortOrder::Descending);
+        assert_eq!(table.order, [0, 2, 1]);
+    }
+
+    #[test]
+    fn sort_rows_is_stable() {
+        let mut table = table(&[Some("a"), Some("b"), Some("a"), Some("a")]);
+
+        table.sort_by(0, SortOrder::Ascending);
+        assert_eq!(table.order, [0, 2, 3, 1]);
+    }
+
+    #[test]
+    fn selecting_recolors_rows_in_place() {
+        let mut app = test_app();
+        let table_id = spawn_table(&mut app, &["a", "b", "c"], None);
+        let before = spawned_rows(&mut app);
+        assert_eq!(before.len(), 3);
+
+        app.world
+            .get_mut::<Table>(table_id)
+            .unwrap()
+            .select(Some(1));
+        app.update();
+
+        let after = spawned_rows(&mut app);
+        let entities = |rows: &[(usize, usize, Entity, Color)]| {
+            rows.iter().map(|row| row.2).collect::<Vec<_>>()
+        };
+        assert_eq!(entities(&after), entities(&before));
+        assert_eq!(after[0].3, Color::BLACK);
+        assert_eq!(after[1].3, Color::BLUE);
+        assert_eq!(after[2].3, Color::BLACK);
+    }
+
+    #[test]
+    fn sorting_reuses_row_entities() {
+        let mut app = test_app();
+        let table_id = spawn_table(&mut app, &["c", "b", "a"], None);
+        let before = spawned_rows(&mut app);
+
+        app.world
+            .get_mut::<Table>(table_id)
+            .unwrap()
+            .sort_by(0, SortOrder::Ascending);
+        app.update();
+
+        let after = spawned_rows(&mut app);
+        assert_eq!(after.iter().map(|row| row.1).collect::<Vec<_>>(), [2, 1, 0]);
+
+        // The middle row displays the same row at the same position, so it is
+        // left untouched.
+        assert_eq!(after[1], before[1]);
+
+        let mut before = before.iter().map(|row| row.2).collect::<Vec<_>>();
+        let mut after = after.iter().map(|row| row.2).collect::<Vec<_>>();
+        before.sort();
+        after.sort();
+        assert_eq!(after, before);
+    }
+
+    #[test]
+    fn scrolling_recycles_rows() {
+        let mut app = test_app();
+        let rows = ["a", "b", "c", "d", "e", "f", "g", "h"];
+        let table_id = spawn_table(&mut app, &rows, Some(2));
+
+        // Headless apps are not laid out, so the viewport has no height and
+        // only the buffer rows are shown.
+        let before = spawned_rows(&mut app);
+        assert_eq!(before.iter().map(|row| row.0).collect::<Vec<_>>(), [0, 1]);
+
+        let body = app.world.get::<Table>(table_id).unwrap().body;
+        app.world.get_mut::<ScrollPosition>(body).unwrap().offset.y = 40.0;
+        app.update();
+
+        let after = spawned_rows(&mut app);
+        assert_eq!(
+            after.iter().map(|row| row.0).collect::<Vec<_>>(),
+            [0, 1, 2, 3]
+        );
+        assert_eq!(after[.. 2], before[..]);
+
+        app.world.get_mut::<ScrollPosition>(body).unwrap().offset.y = 100.0;
+        app.update();
+
+        let moved = spawned_rows(&mut app);
+        assert_eq!(
+            moved.iter().map(|row| row.0).collect::<Vec<_>>(),
+            [3, 4, 5, 6]
+        );
+        assert_eq!(moved[0], after[3]);
+        assert!(
+            moved
+                .iter()
+                .all(|row| after.iter().any(|old| old.2 == row.2))
+        );
+    }
+}
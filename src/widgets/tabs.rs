@@ -0,0 +1,264 @@
+//! Contains the components, events, and systems that switch between the tabs
+//! of a tab container.
+
+use bevy::prelude::*;
+
+use crate::prelude::{
+    DataBlock,
+    NodeBundleBuilder,
+    NodeBundleType,
+    NodePosition,
+    NodeTabs,
+    UiNode,
+};
+
+/// A component that stores the active tab of a tab container.
+///
+/// Game code may write to [`Tabs::active`] to switch tabs.
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Tabs {
+    /// The index of the active tab.
+    pub active: usize,
+
+    /// The number of tabs within the container.
+    pub(crate) count: usize,
+
+    /// The index of the tab that is currently shown, if any.
+    pub(crate) shown: Option<usize>,
+}
+
+impl Tabs {
+    /// Gets the number of tabs within the container.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// An event that is sent when the active tab of a tab container changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct TabChanged {
+    /// The tab container entity.
+    pub tabs: Entity,
+
+    /// The index of the previously active tab.
+    pub previous: usize,
+
+    /// The index of the newly active tab.
+    pub active: usize,
+}
+
+/// A component for the header button that activates a tab.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct TabButton {
+    /// The tab container this button belongs to.
+    tabs: Entity,
+
+    /// The index of the tab this button activates.
+    index: usize,
+
+    /// The tint color of the button while the tab is inactive.
+    color: Color,
+
+    /// The tint color of the button while the tab is active.
+    active_color: Color,
+}
+
+/// A component for the node that holds the contents of a tab.
+#[derive(Debug, Clone, Component)]
+pub(crate) struct TabPage {
+    /// The tab container this page belongs to.
+    tabs: Entity,
+
+    /// The index of the tab this page displays.
+    index: usize,
+
+    /// The contents of the tab, if they have not been built yet.
+    content: Option<UiNode>,
+}
+
+/// This plugin handles switching between the tabs of tab containers.
+pub(crate) struct TabsPlugin;
+impl Plugin for TabsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Tabs>()
+            .add_event::<TabChanged>()
+            .add_systems(Update, (select_tab_on_click, show_active_tab).chain());
+    }
+}
+
+/// Consumes the given container node builder and creates a tab container,
+/// along with its header strip and tab pages.
+///
+/// Returns the entity of the container.
+pub(crate) fn build_tabs(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut container: NodeBundleBuilder,
+    mut tabs: NodeTabs,
+) -> Entity {
+    tabs.apply_to_parent(&mut container, asset_server);
+    let tabs_id = container.build(cmd, asset_server);
+
+    let tab_list = std::mem::take(&mut tabs.tabs);
+    let lazy = tabs.lazy;
+    let tab_background = tabs.tab_background.clone();
+    let tab_width = tabs.tab_width;
+    let color = tabs.tab_color;
+    let active_color = tabs.active_tab_color;
+
+    let mut header = NodeBundleBuilder::default();
+    header.set_parent(Some(tabs_id));
+    tabs.apply_to_node(&mut header, asset_server);
+    let header_id = header.build(cmd, asset_server);
+
+    let mut pages = NodeBundleBuilder::default();
+    pages.set_parent(Some(tabs_id));
+
+    let style = pages.get_style_mut();
+    style.flex_grow = 1.0;
+    style.width = Val::Percent(100.0);
+    let pages_id = pages.build(cmd, asset_server);
+
+    for (index, tab) in tab_list.into_iter().enumerate() {
+        let mut button = NodeBundleBuilder::default();
+        button.set_parent(Some(header_id));
+        tab_background
+            .clone()
+            .apply_to_node(&mut button, asset_server);
+        button.bundle_type(NodeBundleType::Button);
+        button.insert((
+            TabButton {
+                tabs: tabs_id,
+                index,
+                color,
+                active_color,
+            },
+            BackgroundColor(color),
+        ));
+
+        let style = button.get_style_mut();
+        style.width = tab_width;
+        style.height = Val::Percent(100.0);
+        button.set_children(vec![UiNode::Text {
+            background: default(),
+            position: NodePosition::Relative {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+            },
+            text: tab.title,
+        }]);
+        button.build(cmd, asset_server);
+
+        let mut page = NodeBundleBuilder::default();
+        page.set_parent(Some(pages_id));
+
+        let style = page.get_style_mut();
+        style.display = Display::None;
+        style.width = Val::Percent(100.0);
+        style.height = Val::Percent(100.0);
+
+        if lazy {
+            page.insert(TabPage {
+                tabs: tabs_id,
+                index,
+                content: Some(tab.content),
+            });
+        } else {
+            page.insert(TabPage {
+                tabs: tabs_id,
+                index,
+                content: None,
+            });
+            page.set_children(vec![tab.content]);
+        }
+
+        page.build(cmd, asset_server);
+    }
+
+    tabs_id
+}
+
+/// Activates a tab when its header button is clicked.
+fn select_tab_on_click(
+    buttons: Query<(&Interaction, &TabButton), Changed<Interaction>>,
+    mut tabs: Query<&mut Tabs>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut tabs) = tabs.get_mut(button.tabs) else {
+            continue;
+        };
+
+        if tabs.active != button.index {
+            tabs.active = button.index;
+        }
+    }
+}
+
+/// Shows the page of the active tab of each tab container, hides all other
+/// pages, and builds the contents of lazy pages when they are first shown.
+fn show_active_tab(
+    mut cmd: Commands,
+    asset_server: Res<AssetServer>,
+    mut tab_changed: EventWriter<TabChanged>,
+    mut containers: Query<(Entity, &mut Tabs), Changed<Tabs>>,
+    mut pages: Query<(Entity, &mut TabPage, &mut Style)>,
+    mut buttons: Query<(&TabButton, &mut BackgroundColor)>,
+) {
+    for (tabs_id, mut tabs) in &mut containers {
+        if tabs.count == 0 {
+            continue;
+        }
+
+        let active = tabs.active.min(tabs.count - 1);
+        if tabs.active != active {
+            tabs.active = active;
+        }
+
+        if tabs.shown == Some(active) {
+            continue;
+        }
+
+        for (page_id, mut page, mut style) in &mut pages {
+            if page.tabs != tabs_id {
+                continue;
+            }
+
+            if page.index != active {
+                style.display = Display::None;
+                continue;
+            }
+
+            style.display = Display::Flex;
+            if let Some(content) = page.content.take() {
+                content.build_node(&mut cmd, &asset_server, Some(page_id));
+            }
+        }
+
+        for (button, mut background) in &mut buttons {
+            if button.tabs != tabs_id {
+                continue;
+            }
+
+            background.0 = if button.index == active {
+                button.active_color
+            } else {
+                button.color
+            };
+        }
+
+        if let Some(previous) = tabs.shown {
+            tab_changed.send(TabChanged {
+                tabs: tabs_id,
+                previous,
+                active,
+            });
+        }
+
+        tabs.shown = Some(active);
+    }
+}
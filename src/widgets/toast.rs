@@ -0,0 +1,600 @@
+//! Contains the resources, events, and systems that display timed toast
+//! notifications.
+
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use super::UiLayer;
+use crate::prelude::{AnchorPoint, DataBlock, NodeBundleBuilder, NodeText};
+
+/// The severity of a toast, which determines its background color.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum ToastSeverity {
+    /// A general notification.
+    #[default]
+    Info,
+
+    /// A notification that something has completed successfully.
+    Success,
+
+    /// A notification that something may require attention.
+    Warning,
+
+    /// A notification that something has failed.
+    Error,
+}
+
+/// An event that displays a toast notification.
+///
+/// Toasts may also be displayed using the [`Toasts`] resource.
+#[derive(Debug, Clone, PartialEq, Event)]
+pub struct ShowToast {
+    /// The message displayed by the toast.
+    pub message: String,
+
+    /// The severity of the toast.
+    pub severity: ToastSeverity,
+
+    /// The time, in seconds, the toast is displayed before it is dismissed.
+    /// If not set, the duration from the [`ToastSettings`] is used.
+    pub duration: Option<f32>,
+}
+
+impl ShowToast {
+    /// Creates a new toast with the given severity and message.
+    pub fn new<T: Into<String>>(severity: ToastSeverity, message: T) -> Self {
+        Self {
+            message: message.into(),
+            severity,
+            duration: None,
+        }
+    }
+
+    /// Creates a new informational toast with the given message.
+    pub fn info<T: Into<String>>(message: T) -> Self {
+        Self::new(ToastSeverity::Info, message)
+    }
+
+    /// Creates a new success toast with the given message.
+    pub fn success<T: Into<String>>(message: T) -> Self {
+        Self::new(ToastSeverity::Success, message)
+    }
+
+    /// Creates a new warning toast with the given message.
+    pub fn warning<T: Into<String>>(message: T) -> Self {
+        Self::new(ToastSeverity::Warning, message)
+    }
+
+    /// Creates a new error toast with the given message.
+    pub fn error<T: Into<String>>(message: T) -> Self {
+        Self::new(ToastSeverity::Error, message)
+    }
+
+    /// Sets the time, in seconds, the toast is displayed before it is
+    /// dismissed.
+    pub fn duration(mut self, seconds: f32) -> Self {
+        self.duration = Some(seconds);
+        self
+    }
+
+    /// Keeps the toast displayed until it is clicked.
+    pub fn persistent(mut self) -> Self {
+        self.duration = Some(f32::INFINITY);
+        self
+    }
+}
+
+/// A resource that defines where and how toasts are displayed.
+#[derive(Debug, Clone, Resource)]
+pub struct ToastSettings {
+    /// The corner or edge of the window the toasts are stacked against.
+    pub anchor: AnchorPoint,
+
+    /// The time, in seconds, each toast is displayed before it is dismissed.
+    pub duration: f32,
+
+    /// The time, in seconds, each toast takes to animate in or out.
+    pub animation_time: f32,
+
+    /// The largest number of toasts displayed at once. When exceeded, the
+    /// oldest toasts are dismissed.
+    pub max_visible: usize,
+
+    /// The width of each toast, in logical pixels.
+    pub width: f32,
+
+    /// The space, in logical pixels, between the toasts and the edges of the
+    /// window.
+    pub margin: f32,
+
+    /// The space, in logical pixels, between each toast.
+    pub spacing: f32,
+
+    /// The style of the message text.
+    ///
+    /// Only the style of the first section is used.
+    pub text: NodeText,
+
+    /// The background colors of info, success, warning, and error toasts.
+    pub colors: [Color; 4],
+}
+
+impl ToastSettings {
+    /// Gets the background color of a toast with the given severity.
+    pub fn color(&self, severity: ToastSeverity) -> Color {
+        match severity {
+            ToastSeverity::Info => self.colors[0],
+            ToastSeverity::Success => self.colors[1],
+            ToastSeverity::Warning => self.colors[2],
+            ToastSeverity::Error => self.colors[3],
+        }
+    }
+}
+
+impl Default for ToastSettings {
+    fn default() -> Self {
+        Self {
+            anchor: AnchorPoint::BottomRight,
+            duration: 4.0,
+            animation_time: 0.25,
+            max_visible: 5,
+            width: 280.0,
+            margin: 16.0,
+            spacing: 8.0,
+            text: NodeText::styled(16.0, Color::WHITE),
+            colors: [
+                Color::rgba(0.15, 0.15, 0.15, 0.95),
+                Color::rgba(0.15, 0.45, 0.2, 0.95),
+                Color::rgba(0.6, 0.45, 0.1, 0.95),
+                Color::rgba(0.6, 0.15, 0.15, 0.95),
+            ],
+        }
+    }
+}
+
+/// A resource for displaying and dismissing toasts.
+///
+/// Toasts that are shown using this resource or the [`ShowToast`] event are
+/// spawned on the next update.
+#[derive(Debug, Default, Resource)]
+pub struct Toasts {
+    /// The toasts waiting to be spawned.
+    pending: Vec<ShowToast>,
+
+    /// Whether every displayed toast should be dismissed.
+    dismiss_all: bool,
+
+    /// The node that the toasts are stacked within, once spawned.
+    container: Option<Entity>,
+}
+
+impl Toasts {
+    /// Displays the given toast.
+    pub fn show(&mut self, toast: ShowToast) {
+        self.pending.push(toast);
+    }
+
+    /// Displays an informational toast with the given message.
+    pub fn info<T: Into<String>>(&mut self, message: T) {
+        self.show(ShowToast::info(message));
+    }
+
+    /// Displays a success toast with the given message.
+    pub fn success<T: Into<String>>(&mut self, message: T) {
+        self.show(ShowToast::success(message));
+    }
+
+    /// Displays a warning toast with the given message.
+    pub fn warning<T: Into<String>>(&mut self, message: T) {
+        self.show(ShowToast::warning(message));
+    }
+
+    /// Displays an error toast with the given message.
+    pub fn error<T: Into<String>>(&mut self, message: T) {
+        self.show(ShowToast::error(message));
+    }
+
+    /// Dismisses every displayed toast, along with any that are waiting to be
+    /// displayed.
+    pub fn dismiss_all(&mut self) {
+        self.pending.clear();
+        self.dismiss_all = true;
+    }
+}
+
+/// A component for a displayed toast.
+#[derive(Debug, Clone, Component)]
+pub struct Toast {
+    /// The severity of the toast.
+    pub severity: ToastSeverity,
+
+    /// The time, in seconds, the toast remains displayed once it has animated
+    /// in.
+    remaining: f32,
+
+    /// How far the toast has animated in, from `0.0` when hidden to `1.0` when
+    /// fully displayed.
+    progress: f32,
+
+    /// Whether the toast is animating out.
+    leaving: bool,
+}
+
+impl Toast {
+    /// Starts animating the toast out, after which it is despawned.
+    pub fn dismiss(&mut self) {
+        self.leaving = true;
+    }
+
+    /// Returns true if the toast has been dismissed.
+    pub fn is_dismissed(&self) -> bool {
+        self.leaving
+    }
+}
+
+/// A marker component for the node that the toasts are stacked within.
+#[derive(Debug, Default, Clone, Copy, Component)]
+struct ToastContainer;
+
+/// This plugin handles displaying, animating, and dismissing toasts.
+pub(crate) struct ToastPlugin;
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ToastSeverity>()
+            .init_resource::<ToastSettings>()
+            .init_resource::<Toasts>()
+            .add_event::<ShowToast>()
+            .add_systems(
+                Update,
+                (
+                    spawn_pending_toasts,
+                    layout_toast_container,
+                    dismiss_toasts,
+                    animate_toasts,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Spawns each toast that has been shown since the last update, along with
+/// the container node if it does not exist yet.
+fn spawn_pending_toasts(
+    mut cmd: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<ToastSettings>,
+    mut toasts: ResMut<Toasts>,
+    mut events: EventReader<ShowToast>,
+    containers: Query<(), With<ToastContainer>>,
+) {
+    let events = events.read().cloned().collect::<Vec<_>>();
+    if events.is_empty() && toasts.pending.is_empty() {
+        return;
+    }
+
+    let container = match toasts.container {
+        Some(container) if containers.contains(container) => container,
+        _ => {
+            let mut container = NodeBundleBuilder::default();
+            container.insert((ToastContainer, UiLayer::Toast));
+            container_style(container.get_style_mut(), &settings);
+
+            let container = container.build(&mut cmd, &asset_server);
+            toasts.container = Some(container);
+            container
+        }
+    };
+
+    let pending = std::mem::take(&mut toasts.pending);
+    for toast in events.into_iter().chain(pending) {
+        let mut toast_node = NodeBundleBuilder::default();
+        toast_node.set_parent(Some(container));
+        toast_node.insert((
+            Toast {
+                severity: toast.severity,
+                remaining: toast.duration.unwrap_or(settings.duration),
+                progress: 0.0,
+                leaving: false,
+            },
+            BackgroundColor(Color::NONE),
+            Interaction::default(),
+            FocusPolicy::Block,
+        ));
+
+        let style = toast_node.get_style_mut();
+        style.width = Val::Px(settings.width);
+        style.padding = UiRect::all(Val::Px(settings.spacing));
+        let toast_id = toast_node.build(&mut cmd, &asset_server);
+
+        let mut text = settings.text.clone();
+        text.sections.truncate(1);
+        if let Some(section) = text.sections.first_mut() {
+            section.text = toast.message;
+            section.color = Color::NONE;
+        }
+
+        let mut text_node = NodeBundleBuilder::default();
+        text_node.set_parent(Some(toast_id));
+        text.apply_to_node(&mut text_node, &asset_server);
+        text_node.build(&mut cmd, &asset_server);
+    }
+}
+
+/// Sets the style of the toast container to stack its toasts against the
+/// anchor of the given settings.
+fn container_style(style: &mut Style, settings: &ToastSettings) {
+    let align = |direction: i32| match direction {
+        d if d < 0 => AlignItems::FlexStart,
+        0 => AlignItems::Center,
+        _ => AlignItems::FlexEnd,
+    };
+
+    let direction = settings.anchor.direction();
+    style.position_type = PositionType::Absolute;
+    style.width = Val::Percent(100.0);
+    style.height = Val::Percent(100.0);
+    style.flex_direction = FlexDirection::Column;
+    style.align_items = align(direction.x);
+    style.justify_content = match direction.y {
+        d if d < 0 => JustifyContent::FlexStart,
+        0 => JustifyContent::Center,
+        _ => JustifyContent::FlexEnd,
+    };
+    style.padding = UiRect::all(Val::Px(settings.margin));
+    style.row_gap = Val::Px(settings.spacing);
+}
+
+/// Updates the layout of the toast container when the settings change.
+fn layout_toast_container(
+    settings: Res<ToastSettings>,
+    mut containers: Query<&mut Style, With<ToastContainer>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    for mut style in &mut containers {
+        container_style(&mut style, &settings);
+    }
+}
+
+/// Dismisses toasts that have been clicked, that have been displayed for
+/// their full duration, or that exceed the maximum number of visible toasts.
+fn dismiss_toasts(
+    time: Res<Time>,
+    settings: Res<ToastSettings>,
+    mut toasts: ResMut<Toasts>,
+    containers: Query<&Children, With<ToastContainer>>,
+    mut nodes: Query<(&Interaction, &mut Toast)>,
+) {
+    let dismiss_all = std::mem::take(&mut toasts.dismiss_all);
+
+    for (interaction, mut toast) in &mut nodes {
+        if dismiss_all || *interaction == Interaction::Pressed {
+            toast.dismiss();
+        }
+
+        if toast.progress >= 1.0 && !toast.leaving {
+            toast.remaining -= time.delta_seconds();
+            if toast.remaining <= 0.0 {
+                toast.dismiss();
+            }
+        }
+    }
+
+    for children in &containers {
+        let mut visible = children
+            .iter()
+            .filter(|child| nodes.get(**child).is_ok_and(|(_, t)| !t.leaving))
+            .count();
+
+        // Children are ordered from oldest to newest.
+        for child in children.iter() {
+            if visible <= settings.max_visible {
+                break;
+            }
+
+            if let Ok((_, mut toast)) = nodes.get_mut(*child) {
+                if !toast.leaving {
+                    toast.dismiss();
+                    visible -= 1;
+                }
+            }
+        }
+    }
+}
+
+/// Slides and fades toasts in and out, and despawns them once they have
+/// animated out.
+fn animate_toasts(
+    mut cmd: Commands,
+    time: Res<Time>,
+    settings: Res<ToastSettings>,
+    mut nodes: Query<(
+        Entity,
+        &Node,
+        &Children,
+        &mut Toast,
+        &mut Style,
+        &mut BackgroundColor,
+    )>,
+    mut texts: Query<&mut Text>,
+) {
+    let step = match settings.animation_time > 0.0 {
+        true => time.delta_seconds() / settings.animation_time,
+        false => 1.0,
+    };
+
+    let direction = settings.anchor.direction();
+    let text_color = settings
+        .text
+        .sections
+        .first()
+        .map_or(Color::WHITE, |section| section.color);
+
+    for (entity, node, children, mut toast, mut style, mut background) in &mut nodes {
+        let progress = match toast.leaving {
+            true => (toast.progress - step).max(0.0),
+            false => (toast.progress + step).min(1.0),
+        };
+
+        if toast.leaving && progress <= 0.0 {
+            cmd.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        if progress == toast.progress && progress >= 1.0 && style.left == Val::Auto {
+            continue;
+        }
+
+        toast.progress = progress;
+        let eased = progress * progress * (3.0 - 2.0 * progress);
+
+        // Toasts slide in from the side of the window they are anchored to, or
+        // from the top or bottom edge when anchored to the center column.
+        let offset = 1.0 - eased;
+        let (left, top) = match direction.x {
+            0 => {
+                let sign = if direction.y < 0 { -1.0 } else { 1.0 };
+                (0.0, (node.size().y + settings.margin) * offset * sign)
+            }
+            x => ((node.size().x + settings.margin) * offset * x as f32, 0.0),
+        };
+
+        let (left, top) = match progress >= 1.0 {
+            true => (Val::Auto, Val::Auto),
+            false => (Val::Px(left), Val::Px(top)),
+        };
+
+        style.left = left;
+        style.top = top;
+        background.0 = settings
+            .color(toast.severity)
+            .with_a(settings.color(toast.severity).a() * eased);
+
+        for child in children {
+            if let Ok(mut text) = texts.get_mut(*child) {
+                for section in &mut text.sections {
+                    section.style.color = text_color.with_a(text_color.a() * eased);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::widgets::test_app;
+
+    /// Creates an app that shows at most two toasts, advancing time by 0.1
+    /// seconds each update.
+    fn toast_app() -> App {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.world.resource_mut::<ToastSettings>().max_visible = 2;
+        app
+    }
+
+    /// Shows a toast with each of the given messages, and waits for them to
+    /// animate in.
+    fn show(app: &mut App, messages: &[&str]) {
+        let mut toasts = app.world.resource_mut::<Toasts>();
+        for message in messages {
+            toasts.info(*message);
+        }
+
+        for _ in 0 .. 5 {
+            app.update();
+        }
+    }
+
+    /// Gets the displayed toasts, from oldest to newest.
+    fn toasts(app: &App) -> Vec<Entity> {
+        let Some(container) = app.world.resource::<Toasts>().container else {
+            return Vec::new();
+        };
+
+        app.world
+            .get::<Children>(container)
+            .map_or(Vec::new(), |children| children.to_vec())
+    }
+
+    /// Returns true if the given toast has been dismissed.
+    fn is_dismissed(app: &App, toast: Entity) -> bool {
+        app.world.get::<Toast>(toast).unwrap().is_dismissed()
+    }
+
+    #[test]
+    fn oldest_toasts_are_dismissed_past_max_visible() {
+        let mut app = toast_app();
+        show(&mut app, &["a", "b"]);
+        app.world.resource_mut::<Toasts>().info("c");
+        app.update();
+
+        let shown = toasts(&app);
+        assert_eq!(shown.len(), 3);
+        assert!(is_dismissed(&app, shown[0]));
+        assert!(!is_dismissed(&app, shown[1]));
+        assert!(!is_dismissed(&app, shown[2]));
+
+        for _ in 0 .. 5 {
+            app.update();
+        }
+        assert_eq!(toasts(&app), shown[1 ..]);
+    }
+
+    #[test]
+    fn clicked_toast_is_dismissed() {
+        let mut app = toast_app();
+        show(&mut app, &["a", "b"]);
+
+        let toasts = toasts(&app);
+        app.world.entity_mut(toasts[1]).insert(Interaction::Pressed);
+        app.update();
+
+        assert!(!is_dismissed(&app, toasts[0]));
+        assert!(is_dismissed(&app, toasts[1]));
+    }
+
+    #[test]
+    fn dismiss_all_dismisses_displayed_and_pending_toasts() {
+        let mut app = toast_app();
+        show(&mut app, &["a", "b"]);
+
+        let mut resource = app.world.resource_mut::<Toasts>();
+        resource.info("c");
+        resource.dismiss_all();
+        app.update();
+
+        let toasts = toasts(&app);
+        assert_eq!(toasts.len(), 2);
+        assert!(toasts.iter().all(|toast| is_dismissed(&app, *toast)));
+    }
+
+    #[test]
+    fn dismissed_toast_is_despawned_after_animating_out() {
+        let mut app = toast_app();
+        show(&mut app, &["a"]);
+
+        let toast = toasts(&app)[0];
+        assert_eq!(app.world.get::<Toast>(toast).unwrap().progress, 1.0);
+
+        app.world.get_mut::<Toast>(toast).unwrap().dismiss();
+        app.update();
+        assert!(app.world.get_entity(toast).is_some());
+        assert!(app.world.get::<Toast>(toast).unwrap().progress < 1.0);
+
+        for _ in 0 .. 5 {
+            app.update();
+        }
+        assert!(app.world.get_entity(toast).is_none());
+        assert!(toasts(&app).is_empty());
+    }
+}
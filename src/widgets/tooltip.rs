@@ -0,0 +1,273 @@
+//! Contains the components and systems used to display tooltips while nodes
+//! are hovered.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::{UiLayer, cursor_position, hovered_nodes};
+use crate::prelude::{AnchorPoint, NodeBundleBuilder, NodeTooltip, TooltipTarget, UiNode};
+
+/// A component for a node that displays a tooltip while hovered.
+#[derive(Debug, Clone, Component)]
+pub struct Tooltip {
+    /// The contents of the tooltip.
+    pub content: UiNode,
+
+    /// The time, in seconds, the node must be hovered before the tooltip is
+    /// shown. If not set, the delay from the [`TooltipSettings`] is used.
+    pub delay: Option<f32>,
+
+    /// What the tooltip is positioned next to.
+    pub target: TooltipTarget,
+
+    /// The preferred side of the target to display the tooltip on.
+    pub anchor: AnchorPoint,
+}
+
+impl From<NodeTooltip> for Tooltip {
+    fn from(tooltip: NodeTooltip) -> Self {
+        Self {
+            content: *tooltip.content,
+            delay: tooltip.delay,
+            target: tooltip.target,
+            anchor: tooltip.anchor,
+        }
+    }
+}
+
+/// A resource that defines the default behavior of tooltips.
+#[derive(Debug, Clone, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct TooltipSettings {
+    /// The time, in seconds, a node must be hovered before its tooltip is
+    /// shown.
+    pub delay: f32,
+
+    /// The space, in logical pixels, between a tooltip and its target.
+    pub offset: f32,
+}
+
+impl Default for TooltipSettings {
+    fn default() -> Self {
+        Self {
+            delay: 0.5,
+            offset: 12.0,
+        }
+    }
+}
+
+/// A resource that tracks the currently hovered tooltip target.
+#[derive(Debug, Default, Resource)]
+struct TooltipState {
+    /// The node with a tooltip that is currently hovered, if any.
+    target: Option<Entity>,
+
+    /// The time, in seconds, the target has been hovered.
+    hovered_for: f32,
+
+    /// The displayed tooltip node, if any.
+    popup: Option<Entity>,
+}
+
+/// A marker component for a displayed tooltip node.
+#[derive(Debug, Default, Clone, Copy, Component)]
+struct TooltipPopup;
+
+/// This plugin handles showing, positioning, and hiding tooltips.
+pub(crate) struct TooltipPlugin;
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TooltipSettings>()
+            .init_resource::<TooltipSettings>()
+            .init_resource::<TooltipState>()
+            .add_systems(Update, (show_hovered_tooltip, position_tooltip).chain());
+    }
+}
+
+/// Shows the tooltip of the hovered node after the hover delay has passed,
+/// and hides it when the node is no longer hovered.
+///
+/// Nodes covered by a blocking node, such as a modal scrim, are not hovered.
+#[allow(clippy::too_many_arguments)]
+fn show_hovered_tooltip(
+    mut cmd: Commands,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    settings: Res<TooltipSettings>,
+    mut state: ResMut<TooltipState>,
+    targets: Query<(Entity, &Node, &Tooltip)>,
+    interactions: Query<(Entity, &Interaction)>,
+    parents: Query<&Parent>,
+) {
+    let hovered_nodes = hovered_nodes(&interactions, &parents);
+    let hovered = targets
+        .iter()
+        .filter(|(entity, _, _)| hovered_nodes.contains(entity))
+        .max_by_key(|(_, node, _)| node.stack_index());
+
+    let hovered_id = hovered.map(|(entity, _, _)| entity);
+    if state.target != hovered_id {
+        if let Some(popup) = state.popup.take() {
+            cmd.entity(popup).despawn_recursive();
+        }
+
+        state.target = hovered_id;
+        state.hovered_for = 0.0;
+        return;
+    }
+
+    let Some((_, _, tooltip)) = hovered else {
+        return;
+    };
+
+    state.hovered_for += time.delta_seconds();
+    let delay = tooltip.delay.unwrap_or(settings.delay);
+    if state.popup.is_some() || state.hovered_for < delay {
+        return;
+    }
+
+    let mut popup = NodeBundleBuilder::default();
+    popup.insert((TooltipPopup, UiLayer::Tooltip, Visibility::Hidden));
+    popup.get_style_mut().position_type = PositionType::Absolute;
+    popup.set_children(vec![tooltip.content.clone()]);
+    state.popup = Some(popup.build(&mut cmd, &asset_server));
+}
+
+/// Positions the displayed tooltip next to its target, flipping it to the
+/// opposite side if it would leave the window.
+fn position_tooltip(
+    state: Res<TooltipState>,
+    settings: Res<TooltipSettings>,
+    ui_scale: Res<UiScale>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    targets: Query<(&Node, &GlobalTransform, &Tooltip)>,
+    mut popups: Query<(&Node, &mut Style, &mut Visibility), With<TooltipPopup>>,
+) {
+    let (Some(target), Some(popup)) = (state.target, state.popup) else {
+        return;
+    };
+
+    let Ok((target_node, transform, tooltip)) = targets.get(target) else {
+        return;
+    };
+
+    let Ok((popup_node, mut style, mut visibility)) = popups.get_mut(popup) else {
+        return;
+    };
+
+    let size = popup_node.size();
+    if size == Vec2::ZERO {
+        return;
+    }
+
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+
+    let target_rect = match tooltip.target {
+        TooltipTarget::Cursor => match cursor_position(&windows, &ui_scale) {
+            Some(cursor) => Rect::from_center_size(cursor, Vec2::ZERO),
+            None => return,
+        },
+        TooltipTarget::Node => target_node.logical_rect(transform),
+    };
+
+    let bounds = Vec2::new(window.width(), window.height()) / ui_scale.0;
+    let pos = place_popup(tooltip.anchor, target_rect, size, settings.offset, bounds);
+
+    let (left, top) = (Val::Px(pos.x), Val::Px(pos.y));
+    if style.left != left || style.top != top {
+        style.left = left;
+        style.top = top;
+    }
+
+    if *visibility != Visibility::Inherited {
+        *visibility = Visibility::Inherited;
+    }
+}
+
+/// Finds the top-left position of a popup with the given size, placed on the
+/// side of the target rectangle given by the anchor point.
+///
+/// If the popup would leave the bounds on the preferred side, it is flipped to
+/// the opposite side when possible. The result is always clamped to within the
+/// bounds.
+pub(crate) fn place_popup(
+    anchor: AnchorPoint,
+    target: Rect,
+    size: Vec2,
+    offset: f32,
+    bounds: Vec2,
+) -> Vec2 {
+    let direction = anchor.direction();
+
+    let axis = |side: i32, min: f32, max: f32, size: f32, bound: f32| {
+        let place = |side: i32| match side {
+            s if s < 0 => min - offset - size,
+            0 => (min + max - size) / 2.0,
+            _ => max + offset,
+        };
+
+        let mut pos = place(side);
+        if side != 0 && (pos < 0.0 || pos + size > bound) {
+            let flipped = place(-side);
+            if flipped >= 0.0 && flipped + size <= bound {
+                pos = flipped;
+            }
+        }
+
+        pos.clamp(0.0, (bound - size).max(0.0))
+    };
+
+    Vec2::new(
+        axis(direction.x, target.min.x, target.max.x, size.x, bounds.x),
+        axis(direction.y, target.min.y, target.max.y, size.y, bounds.y),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Vec2 = Vec2::new(800.0, 600.0);
+    const TARGET: Rect = Rect {
+        min: Vec2::new(300.0, 300.0),
+        max: Vec2::new(400.0, 320.0),
+    };
+    const SIZE: Vec2 = Vec2::new(100.0, 40.0);
+
+    #[test]
+    fn popup_is_placed_on_anchor_side() {
+        let place = |anchor| place_popup(anchor, TARGET, SIZE, 8.0, BOUNDS);
+
+        assert_eq!(place(AnchorPoint::TopCenter), Vec2::new(300.0, 252.0));
+        assert_eq!(place(AnchorPoint::BottomRight), Vec2::new(408.0, 328.0));
+        assert_eq!(place(AnchorPoint::CenterLeft), Vec2::new(192.0, 290.0));
+        assert_eq!(place(AnchorPoint::Center), Vec2::new(300.0, 290.0));
+    }
+
+    #[test]
+    fn popup_flips_at_window_edges() {
+        let target = Rect::new(300.0, 10.0, 400.0, 30.0);
+        let pos = place_popup(AnchorPoint::TopCenter, target, SIZE, 8.0, BOUNDS);
+        assert_eq!(pos, Vec2::new(300.0, 38.0));
+
+        let target = Rect::new(750.0, 300.0, 790.0, 320.0);
+        let pos = place_popup(AnchorPoint::CenterRight, target, SIZE, 8.0, BOUNDS);
+        assert_eq!(pos, Vec2::new(642.0, 290.0));
+    }
+
+    #[test]
+    fn popup_is_clamped_when_it_cannot_flip() {
+        // Neither side has room, so the popup stays above the target and is
+        // pushed down to fit.
+        let size = Vec2::new(100.0, 590.0);
+        let pos = place_popup(AnchorPoint::TopCenter, TARGET, size, 8.0, BOUNDS);
+        assert_eq!(pos, Vec2::new(300.0, 0.0));
+
+        // Centered popups are never flipped, only clamped.
+        let target = Rect::new(0.0, 300.0, 20.0, 320.0);
+        let pos = place_popup(AnchorPoint::BottomCenter, target, SIZE, 8.0, BOUNDS);
+        assert_eq!(pos, Vec2::new(0.0, 328.0));
+    }
+}
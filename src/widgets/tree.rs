@@ -0,0 +1,607 @@
+//! Contains the components, events, and systems that expand, collapse, and
+//! select the items of a tree view.
+
+use bevy::prelude::*;
+
+use crate::prelude::{
+    ChildrenBuilder,
+    DataBlock,
+    NodeBundleBuilder,
+    NodeChildren,
+    NodeText,
+    NodeTreeItem,
+    UiFocus,
+};
+
+/// A component that stores the settings of a tree view.
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Tree {
+    /// Whether more than one item may be selected at a time, using control
+    /// and shift clicks.
+    pub multi_select: bool,
+
+    /// The indentation of each level of the tree.
+    pub indent: Val,
+
+    /// The height of the row of each item.
+    pub row_height: Val,
+
+    /// The text displayed on the arrow of a collapsed item.
+    pub expand_icon: String,
+
+    /// The text displayed on the arrow of an expanded item.
+    pub collapse_icon: String,
+
+    /// The background color of the row of each selected item.
+    pub selected_color: Color,
+}
+
+/// A component that stores the selected items of a tree view.
+///
+/// Game code may write to [`TreeSelection::selected`] to change the selection.
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct TreeSelection {
+    /// The selected item entities, in the order they were selected.
+    pub selected: Vec<Entity>,
+
+    /// The item that range selections and keyboard navigation start from.
+    pub(crate) anchor: Option<Entity>,
+
+    /// The items that are currently displayed as selected.
+    pub(crate) shown: Vec<Entity>,
+}
+
+impl TreeSelection {
+    /// Returns true if the given item is selected.
+    pub fn is_selected(&self, item: Entity) -> bool {
+        self.selected.contains(&item)
+    }
+
+    /// Selects only the given item.
+    pub fn select(&mut self, item: Entity) {
+        self.selected.clear();
+        self.selected.push(item);
+        self.anchor = Some(item);
+    }
+}
+
+/// A component that stores the state of a single item within a tree view.
+///
+/// Game code may write to [`TreeItem::expanded`] to expand or collapse the
+/// item.
+#[derive(Debug, Clone, Component)]
+pub struct TreeItem {
+    /// Whether the children of the item are displayed.
+    pub expanded: bool,
+
+    /// The tree view this item belongs to, once it has been found.
+    pub(crate) tree: Option<Entity>,
+
+    /// Whether the item has children, or may create them when expanded.
+    pub(crate) expandable: bool,
+
+    /// Whether the children of the item are currently displayed.
+    pub(crate) shown: Option<bool>,
+
+    /// The row node containing the arrow and label of the item.
+    pub(crate) row: Entity,
+
+    /// The text node of the expand and collapse arrow.
+    pub(crate) arrow: Entity,
+
+    /// The node containing the children of the item.
+    pub(crate) container: Entity,
+
+    /// The function used to create the children of the item, if they have not
+    /// been created yet.
+    pub(crate) lazy_children: Option<ChildrenBuilder>,
+}
+
+impl TreeItem {
+    /// Gets the tree view this item belongs to.
+    ///
+    /// This is `None` until the frame after the item is spawned.
+    pub fn tree(&self) -> Option<Entity> {
+        self.tree
+    }
+
+    /// Returns true if the item has children, or may create them when
+    /// expanded.
+    pub fn is_expandable(&self) -> bool {
+        self.expandable
+    }
+}
+
+/// An event that is sent when the selected items of a tree view change.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct TreeSelectionChanged {
+    /// The tree view entity.
+    pub tree: Entity,
+
+    /// The selected item entities.
+    pub selected: Vec<Entity>,
+}
+
+/// An event that is sent when an item of a tree view is expanded or
+/// collapsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct TreeItemToggled {
+    /// The tree view entity.
+    pub tree: Entity,
+
+    /// The item entity.
+    pub item: Entity,
+
+    /// Whether the item is now expanded.
+    pub expanded: bool,
+}
+
+/// A component for the row node of a tree item, which selects the item when
+/// clicked.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct TreeRow {
+    /// The item this row belongs to.
+    item: Entity,
+}
+
+/// A component for the arrow node of a tree item, which expands or collapses
+/// the item when clicked.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct TreeArrow {
+    /// The item this arrow belongs to.
+    item: Entity,
+}
+
+/// This plugin handles expanding, collapsing, and selecting tree items.
+pub(crate) struct TreePlugin;
+impl Plugin for TreePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Tree>()
+            .register_type::<TreeSelection>()
+            .add_event::<TreeSelectionChanged>()
+            .add_event::<TreeItemToggled>()
+            .add_systems(
+                Update,
+                (
+                    attach_tree_items,
+                    toggle_tree_item_on_click,
+                    select_tree_item_on_click,
+                    navigate_tree_with_keys,
+                    update_tree_items,
+                    show_tree_selection,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Consumes the given item node builder and creates a tree item, along with
+/// its row and children.
+///
+/// Returns the entity of the item.
+pub(crate) fn build_tree_item(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut item_node: NodeBundleBuilder,
+    item: NodeTreeItem,
+    children: NodeChildren,
+) -> Entity {
+    item_node.get_style_mut().flex_direction = FlexDirection::Column;
+    let item_id = item_node.build(cmd, asset_server);
+
+    let mut row = NodeBundleBuilder::default();
+    row.set_parent(Some(item_id));
+    row.insert((TreeRow { item: item_id }, Interaction::default()));
+
+    let style = row.get_style_mut();
+    style.flex_direction = FlexDirection::Row;
+    style.align_items = AlignItems::Center;
+    let row_id = row.build(cmd, asset_server);
+
+    let mut arrow = NodeBundleBuilder::default();
+    arrow.set_parent(Some(row_id));
+    arrow.insert((TreeArrow { item: item_id }, Interaction::default()));
+
+    let style = arrow.get_style_mut();
+    style.flex_shrink = 0.0;
+    style.width = Val::Px(16.0);
+    style.justify_content = JustifyContent::Center;
+    let arrow_id = arrow.build(cmd, asset_server);
+
+    let mut arrow_text = NodeBundleBuilder::default();
+    arrow_text.set_parent(Some(arrow_id));
+    icon_text(&item.label).apply_to_node(&mut arrow_text, asset_server);
+    let arrow_text_id = arrow_text.build(cmd, asset_server);
+
+    let mut label = NodeBundleBuilder::default();
+    label.set_parent(Some(row_id));
+    item.label.apply_to_node(&mut label, asset_server);
+    label.build(cmd, asset_server);
+
+    let mut container = NodeBundleBuilder::default();
+    container.set_parent(Some(item_id));
+
+    let style = container.get_style_mut();
+    style.flex_direction = FlexDirection::Column;
+    style.display = Display::None;
+
+    let expandable = item.lazy_children.is_some() || !children.children.is_empty();
+    if item.lazy_children.is_none() {
+        children.apply_to_node(&mut container, asset_server);
+    }
+    let container_id = container.build(cmd, asset_server);
+
+    cmd.entity(item_id).insert(TreeItem {
+        expanded: item.expanded,
+        tree: None,
+        expandable,
+        shown: None,
+        row: row_id,
+        arrow: arrow_text_id,
+        container: container_id,
+        lazy_children: item.lazy_children,
+    });
+
+    item_id
+}
+
+/// Creates the text of an expand and collapse arrow, using the style of the
+/// given label.
+fn icon_text(label: &NodeText) -> NodeText {
+    let mut text = label.clone();
+    text.sections.truncate(1);
+    if let Some(section) = text.sections.first_mut() {
+        section.text.clear();
+    }
+    text
+}
+
+/// Collects the visible items within the given node, in the order they are
+/// displayed.
+///
+/// The given function returns whether an item is expanded and the node
+/// containing its children, or `None` if the entity is not a tree item.
+fn visible_items<F>(node: Entity, children: &Query<&Children>, item: &F, out: &mut Vec<Entity>)
+where
+    F: Fn(Entity) -> Option<(bool, Entity)>,
+{
+    let Ok(node_children) = children.get(node) else {
+        return;
+    };
+
+    for &child in node_children {
+        let Some((expanded, container)) = item(child) else {
+            continue;
+        };
+
+        out.push(child);
+        if expanded {
+            visible_items(container, children, item, out);
+        }
+    }
+}
+
+/// Finds the tree view that each newly spawned tree item belongs to, and
+/// applies the settings of the tree view to the item.
+fn attach_tree_items(
+    mut items: Query<(Entity, &mut TreeItem), Added<TreeItem>>,
+    parents: Query<&Parent>,
+    trees: Query<&Tree>,
+    mut styles: Query<&mut Style>,
+) {
+    for (item_id, mut item) in &mut items {
+        let Some((tree_id, tree)) = parents
+            .iter_ancestors(item_id)
+            .find_map(|entity| trees.get(entity).ok().map(|tree| (entity, tree)))
+        else {
+            continue;
+        };
+
+        item.tree = Some(tree_id);
+
+        if let Ok(mut style) = styles.get_mut(item.row) {
+            style.height = tree.row_height;
+        }
+
+        if let Ok(mut style) = styles.get_mut(item.container) {
+            style.padding.left = tree.indent;
+        }
+    }
+}
+
+/// Expands or collapses a tree item when its arrow is clicked.
+fn toggle_tree_item_on_click(
+    arrows: Query<(&Interaction, &TreeArrow), Changed<Interaction>>,
+    mut items: Query<&mut TreeItem>,
+) {
+    for (interaction, arrow) in &arrows {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut item) = items.get_mut(arrow.item) else {
+            continue;
+        };
+
+        if item.expandable {
+            item.expanded = !item.expanded;
+        }
+    }
+}
+
+/// Selects a tree item when its row is clicked.
+///
+/// If the tree view allows multiple selection, control clicks toggle the
+/// selection of an item, and shift clicks select all visible items between
+/// the anchor and the clicked item.
+fn select_tree_item_on_click(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    rows: Query<(&Interaction, &TreeRow), Changed<Interaction>>,
+    items: Query<&TreeItem>,
+    children: Query<&Children>,
+    mut trees: Query<(&Tree, &mut TreeSelection)>,
+) {
+    let ctrl = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let shift = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    for (interaction, row) in &rows {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Some(tree_id) = items.get(row.item).ok().and_then(|item| item.tree) else {
+            continue;
+        };
+
+        let Ok((tree, mut selection)) = trees.get_mut(tree_id) else {
+            continue;
+        };
+
+        if tree.multi_select && ctrl {
+            if let Some(index) = selection.selected.iter().position(|&e| e == row.item) {
+                selection.selected.remove(index);
+            } else {
+                selection.selected.push(row.item);
+            }
+            selection.anchor = Some(row.item);
+            continue;
+        }
+
+        if let Some(anchor) = selection.anchor.filter(|_| tree.multi_select && shift) {
+            let mut visible = Vec::new();
+            let item_fn = |e| items.get(e).ok().map(|i| (i.expanded, i.container));
+            visible_items(tree_id, &children, &item_fn, &mut visible);
+
+            let start = visible.iter().position(|&e| e == anchor);
+            let end = visible.iter().position(|&e| e == row.item);
+            if let (Some(start), Some(end)) = (start, end) {
+                selection.selected = visible[start.min(end) ..= start.max(end)].to_vec();
+                continue;
+            }
+        }
+
+        selection.select(row.item);
+    }
+}
+
+/// Moves the selection of the focused tree view using the arrow keys.
+///
+/// The up and down arrows move between visible items. The right arrow expands
+/// the selected item, or moves to its first child if it is already expanded,
+/// while the left arrow collapses the item, or moves to its parent. Enter and
+/// space toggle the selected item.
+fn navigate_tree_with_keys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    focus: Res<UiFocus>,
+    mut trees: Query<&mut TreeSelection, With<Tree>>,
+    mut items: Query<&mut TreeItem>,
+    children: Query<&Children>,
+    parents: Query<&Parent>,
+) {
+    let keys = [
+        KeyCode::ArrowUp,
+        KeyCode::ArrowDown,
+        KeyCode::ArrowLeft,
+        KeyCode::ArrowRight,
+        KeyCode::Enter,
+        KeyCode::Space,
+    ];
+
+    if !keyboard.any_just_pressed(keys) {
+        return;
+    }
+
+    let Some(tree_id) = focus.entity else {
+        return;
+    };
+
+    let Ok(mut selection) = trees.get_mut(tree_id) else {
+        return;
+    };
+
+    let mut visible = Vec::new();
+    let item_fn = |e| items.get(e).ok().map(|i| (i.expanded, i.container));
+    visible_items(tree_id, &children, &item_fn, &mut visible);
+
+    if visible.is_empty() {
+        return;
+    }
+
+    let current = selection
+        .anchor
+        .or(selection.selected.last().copied())
+        .and_then(|anchor| visible.iter().position(|&e| e == anchor));
+
+    let mut target = current;
+    for key in keyboard.get_just_pressed() {
+        match (key, target) {
+            (KeyCode::ArrowDown, Some(index)) => target = Some((index + 1).min(visible.len() - 1)),
+            (KeyCode::ArrowUp, Some(index)) => target = Some(index.saturating_sub(1)),
+            (KeyCode::ArrowDown, None) => target = Some(0),
+            (KeyCode::ArrowUp, None) => target = Some(visible.len() - 1),
+
+            (KeyCode::ArrowRight, Some(index)) => {
+                let Ok(mut item) = items.get_mut(visible[index]) else {
+                    continue;
+                };
+
+                if !item.expandable {
+                    continue;
+                }
+
+                if item.expanded {
+                    target = Some((index + 1).min(visible.len() - 1));
+                } else {
+                    item.expanded = true;
+                }
+            }
+
+            (KeyCode::ArrowLeft, Some(index)) => {
+                let Ok(mut item) = items.get_mut(visible[index]) else {
+                    continue;
+                };
+
+                if item.expanded {
+                    item.expanded = false;
+                    continue;
+                }
+
+                // The parent of an item is the container of its parent item.
+                let parent_item = parents
+                    .get(visible[index])
+                    .and_then(|container| parents.get(container.get()))
+                    .map(|parent| parent.get());
+
+                if let Some(position) = parent_item
+                    .ok()
+                    .and_then(|parent| visible.iter().position(|&e| e == parent))
+                {
+                    target = Some(position);
+                }
+            }
+
+            (KeyCode::Enter | KeyCode::Space, Some(index)) => {
+                let Ok(mut item) = items.get_mut(visible[index]) else {
+                    continue;
+                };
+
+                if item.expandable {
+                    item.expanded = !item.expanded;
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    if let Some(index) = target.filter(|&index| Some(index) != current) {
+        selection.select(visible[index]);
+    }
+}
+
+/// Displays or hides the children of each tree item that has changed,
+/// creating lazy children the first time an item is expanded.
+fn update_tree_items(
+    mut cmd: Commands,
+    asset_server: Res<AssetServer>,
+    mut toggled: EventWriter<TreeItemToggled>,
+    trees: Query<&Tree>,
+    mut items: Query<(Entity, &mut TreeItem), Changed<TreeItem>>,
+    mut styles: Query<&mut Style>,
+    mut texts: Query<&mut Text>,
+) {
+    for (item_id, mut item) in &mut items {
+        let Some(tree_id) = item.tree else {
+            continue;
+        };
+
+        let Ok(tree) = trees.get(tree_id) else {
+            continue;
+        };
+
+        let expanded = item.expanded && item.expandable;
+        if item.shown == Some(expanded) {
+            continue;
+        }
+
+        if expanded {
+            if let Some(builder) = item.lazy_children.take() {
+                for child in builder.build() {
+                    child.build_node(&mut cmd, &asset_server, Some(item.container));
+                }
+            }
+        }
+
+        if let Ok(mut style) = styles.get_mut(item.container) {
+            style.display = if expanded {
+                Display::Flex
+            } else {
+                Display::None
+            };
+        }
+
+        if let Ok(mut text) = texts.get_mut(item.arrow) {
+            let icon = match (item.expandable, expanded) {
+                (false, _) => "",
+                (true, false) => tree.expand_icon.as_str(),
+                (true, true) => tree.collapse_icon.as_str(),
+            };
+
+            if let Some(section) = text.sections.first_mut() {
+                section.value = icon.to_string();
+            }
+        }
+
+        if item.shown.is_some() {
+            toggled.send(TreeItemToggled {
+                tree: tree_id,
+                item: item_id,
+                expanded,
+            });
+        }
+
+        item.shown = Some(expanded);
+    }
+}
+
+/// Highlights the rows of the selected items of each tree view whose
+/// selection has changed.
+fn show_tree_selection(
+    mut changed: EventWriter<TreeSelectionChanged>,
+    mut trees: Query<(Entity, &Tree, &mut TreeSelection), Changed<TreeSelection>>,
+    items: Query<(Entity, &TreeItem)>,
+    mut rows: Query<&mut BackgroundColor, With<TreeRow>>,
+) {
+    for (tree_id, tree, mut selection) in &mut trees {
+        if !tree.multi_select && selection.selected.len() > 1 {
+            let last = selection.selected[selection.selected.len() - 1];
+            selection.select(last);
+        }
+
+        for (item_id, item) in &items {
+            if item.tree != Some(tree_id) {
+                continue;
+            }
+
+            let Ok(mut background) = rows.get_mut(item.row) else {
+                continue;
+            };
+
+            background.0 = if selection.is_selected(item_id) {
+                tree.selected_color
+            } else {
+                Color::NONE
+            };
+        }
+
+        if selection.shown != selection.selected {
+            changed.send(TreeSelectionChanged {
+                tree: tree_id,
+                selected: selection.selected.clone(),
+            });
+            selection.shown = selection.selected.clone();
+        }
+    }
+}
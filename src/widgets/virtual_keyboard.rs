@@ -0,0 +1,935 @@
+//! Contains the resources, components, and systems that display an on-screen
+//! keyboard for entering text with a gamepad.
+
+use bevy::input::keyboard::Key;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use super::UiLayer;
+use crate::prelude::{
+    AnchorPoint,
+    BackInput,
+    DataBlock,
+    KeepsFocus,
+    NodeBackground,
+    NodeBundleBuilder,
+    NodeBundleType,
+    NodeText,
+    UiFocus,
+};
+
+/// The set of keys displayed by a virtual keyboard.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum KeyboardLayout {
+    /// A full QWERTY keyboard with letters, digits, and common symbols.
+    #[default]
+    Qwerty,
+
+    /// A number pad with digits, a decimal point, and a minus sign.
+    Numeric,
+}
+
+impl KeyboardLayout {
+    /// Gets the rows of keys of this layout, from top to bottom.
+    pub fn rows(self) -> Vec<Vec<VirtualKey>> {
+        /// Creates a row of character keys from the given normal and shifted
+        /// characters.
+        fn chars(normal: &str, shifted: &str) -> Vec<VirtualKey> {
+            normal
+                .chars()
+                .zip(shifted.chars())
+                .map(|(normal, shifted)| VirtualKey::Char { normal, shifted })
+                .collect()
+        }
+
+        match self {
+            KeyboardLayout::Qwerty => {
+                let mut digits = chars("1234567890", "!@#$%^&*()");
+                digits.push(VirtualKey::Backspace);
+
+                let mut home = vec![VirtualKey::CapsLock];
+                home.extend(chars("asdfghjkl", "ASDFGHJKL"));
+                home.push(VirtualKey::Enter);
+
+                let mut bottom = vec![VirtualKey::Shift];
+                bottom.extend(chars("zxcvbnm,.", "ZXCVBNM<>"));
+
+                vec![
+                    digits,
+                    chars("qwertyuiop", "QWERTYUIOP"),
+                    home,
+                    bottom,
+                    vec![VirtualKey::Space, VirtualKey::Close],
+                ]
+            }
+
+            KeyboardLayout::Numeric => {
+                let mut top = chars("789", "789");
+                top.push(VirtualKey::Backspace);
+
+                let mut middle = chars("456", "456");
+                middle.push(VirtualKey::Char {
+                    normal: '-',
+                    shifted: '-',
+                });
+
+                let mut low = chars("123", "123");
+                low.push(VirtualKey::Enter);
+
+                let mut bottom = chars("0.", "0.");
+                bottom.push(VirtualKey::Close);
+
+                vec![top, middle, low, bottom]
+            }
+        }
+    }
+}
+
+/// A single key of a virtual keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum VirtualKey {
+    /// A key that types a character.
+    Char {
+        /// The character typed when shift is not held.
+        normal: char,
+
+        /// The character typed when shift is held.
+        shifted: char,
+    },
+
+    /// Types the shifted character of the next key.
+    Shift,
+
+    /// Toggles typing upper case letters.
+    CapsLock,
+
+    /// Removes the last typed character.
+    Backspace,
+
+    /// Types a space.
+    Space,
+
+    /// Submits the typed text.
+    Enter,
+
+    /// Closes the keyboard, removing focus from the text input.
+    Close,
+}
+
+impl VirtualKey {
+    /// Gets the number of key widths this key takes up.
+    fn width(self) -> f32 {
+        match self {
+            VirtualKey::Char { .. } => 1.0,
+            VirtualKey::Space => 5.0,
+            _ => 2.0,
+        }
+    }
+
+    /// Gets the label displayed on this key.
+    fn label(self, shift: bool, caps: bool) -> String {
+        match self {
+            VirtualKey::Char { .. } => self.character(shift, caps).to_string(),
+            VirtualKey::Shift => "Shift".to_string(),
+            VirtualKey::CapsLock => "Caps".to_string(),
+            VirtualKey::Backspace => "Back".to_string(),
+            VirtualKey::Space => "Space".to_string(),
+            VirtualKey::Enter => "Enter".to_string(),
+            VirtualKey::Close => "Close".to_string(),
+        }
+    }
+
+    /// Gets the character typed by this key. Caps lock only affects letters.
+    fn character(self, shift: bool, caps: bool) -> char {
+        match self {
+            VirtualKey::Char { normal, shifted } => {
+                let shift = match normal.is_alphabetic() {
+                    true => shift != caps,
+                    false => shift,
+                };
+                match shift {
+                    true => shifted,
+                    false => normal,
+                }
+            }
+            VirtualKey::Space => ' ',
+            _ => '\0',
+        }
+    }
+}
+
+/// A component for text inputs that open a virtual keyboard when focused.
+///
+/// Text inputs receive the keys pressed on the virtual keyboard as
+/// [`VirtualKeyPressed`] events.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct VirtualKeyboardTarget {
+    /// The layout of the keyboard opened for this input.
+    pub layout: KeyboardLayout,
+}
+
+/// An event that is sent when a key of a virtual keyboard is pressed.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct VirtualKeyPressed {
+    /// The text input the keyboard is attached to.
+    pub target: Entity,
+
+    /// The logical key that was pressed, such as [`Key::Character`],
+    /// [`Key::Backspace`], or [`Key::Enter`].
+    pub key: Key,
+}
+
+/// A resource that defines when and how the virtual keyboard is displayed.
+#[derive(Debug, Clone, Resource)]
+pub struct VirtualKeyboardSettings {
+    /// Whether the keyboard is only opened while a gamepad is connected.
+    pub require_gamepad: bool,
+
+    /// The edge of the window the keyboard is displayed against.
+    pub anchor: AnchorPoint,
+
+    /// The space, in logical pixels, between the keyboard and the edges of
+    /// the window.
+    pub margin: f32,
+
+    /// The background of the keyboard panel.
+    pub background: NodeBackground,
+
+    /// The width and height of a single character key, in logical pixels.
+    pub key_size: f32,
+
+    /// The space between each key, in logical pixels.
+    pub spacing: f32,
+
+    /// The background of each key.
+    pub key_background: NodeBackground,
+
+    /// The style of the key labels.
+    ///
+    /// Only the style of the first section is used.
+    pub text: NodeText,
+
+    /// The label color of the shift and caps lock keys while active.
+    pub active_color: Color,
+
+    /// The border color of the selected key.
+    pub selection_color: Color,
+
+    /// The border width of the selected key, in logical pixels.
+    pub selection_width: f32,
+}
+
+impl Default for VirtualKeyboardSettings {
+    fn default() -> Self {
+        Self {
+            require_gamepad: true,
+            anchor: AnchorPoint::BottomCenter,
+            margin: 16.0,
+            background: NodeBackground::Color {
+                color: Color::rgba(0.1, 0.1, 0.12, 0.95),
+            },
+            key_size: 48.0,
+            spacing: 4.0,
+            key_background: NodeBackground::Color {
+                color: Color::rgb(0.25, 0.25, 0.3),
+            },
+            text: NodeText::styled(18.0, Color::WHITE),
+            active_color: Color::rgb(1.0, 0.85, 0.3),
+            selection_color: Color::rgb(1.0, 0.85, 0.3),
+            selection_width: 2.0,
+        }
+    }
+}
+
+/// A component that stores the state of the open virtual keyboard.
+#[derive(Debug, Clone, Component)]
+pub struct VirtualKeyboard {
+    /// The text input the keyboard is attached to.
+    target: Entity,
+
+    /// The layout of the keyboard.
+    layout: KeyboardLayout,
+
+    /// The row and column of the selected key.
+    cursor: (usize, usize),
+
+    /// Whether the next character is shifted.
+    shift: bool,
+
+    /// Whether caps lock is enabled.
+    caps: bool,
+
+    /// The keys of each row, along with their button and label nodes.
+    rows: Vec<Vec<KeyParts>>,
+}
+
+impl VirtualKeyboard {
+    /// Gets the text input the keyboard is attached to.
+    pub fn target(&self) -> Entity {
+        self.target
+    }
+
+    /// Gets the layout of the keyboard.
+    pub fn layout(&self) -> KeyboardLayout {
+        self.layout
+    }
+
+    /// Gets the currently selected key.
+    pub fn selected(&self) -> Option<VirtualKey> {
+        let (row, column) = self.cursor;
+        self.rows
+            .get(row)
+            .and_then(|keys| keys.get(column))
+            .map(|parts| parts.key)
+    }
+
+    /// Moves the selection by the given number of rows and columns, wrapping
+    /// around the edges of the keyboard.
+    fn move_cursor(&mut self, rows: isize, columns: isize) {
+        if self.rows.is_empty() {
+            return;
+        }
+
+        let (mut row, mut column) = self.cursor;
+        if rows != 0 {
+            let count = self.rows.len() as isize;
+            let old_len = self.rows[row].len().max(1) as f32;
+            row = (row as isize + rows).rem_euclid(count) as usize;
+
+            // Keep the selection near the same horizontal position when moving
+            // between rows of different lengths.
+            let new_len = self.rows[row].len().max(1) as f32;
+            column = ((column as f32 + 0.5) / old_len * new_len) as usize;
+        }
+
+        let len = self.rows[row].len().max(1) as isize;
+        column = (column.min(len as usize - 1) as isize + columns).rem_euclid(len) as usize;
+        self.cursor = (row, column);
+    }
+
+    /// Presses the given key, sending the matching logical key to the target
+    /// and updating the shift state.
+    ///
+    /// Returns true if the keyboard should be closed.
+    fn press(&mut self, key: VirtualKey, events: &mut EventWriter<VirtualKeyPressed>) -> bool {
+        let logical = match key {
+            VirtualKey::Char { .. } | VirtualKey::Space => {
+                let c = key.character(self.shift, self.caps);
+                self.shift = false;
+                Key::Character(c.to_string().into())
+            }
+            VirtualKey::Shift => {
+                self.shift = !self.shift;
+                return false;
+            }
+            VirtualKey::CapsLock => {
+                self.caps = !self.caps;
+                return false;
+            }
+            VirtualKey::Backspace => Key::Backspace,
+            VirtualKey::Enter => Key::Enter,
+            VirtualKey::Close => return true,
+        };
+
+        events.send(VirtualKeyPressed {
+            target: self.target,
+            key: logical,
+        });
+        false
+    }
+}
+
+/// The nodes of a single key that are updated when the keyboard changes.
+#[derive(Debug, Clone, Copy)]
+struct KeyParts {
+    /// The key.
+    key: VirtualKey,
+
+    /// The button node of the key.
+    button: Entity,
+
+    /// The label text node of the key.
+    label: Entity,
+}
+
+/// A component for the button of a virtual keyboard key.
+#[derive(Debug, Clone, Copy, Component)]
+struct KeyboardKey {
+    /// The virtual keyboard this key belongs to.
+    keyboard: Entity,
+
+    /// The row of the key.
+    row: usize,
+
+    /// The column of the key.
+    column: usize,
+}
+
+/// This plugin handles opening, navigating, and closing the virtual keyboard.
+pub(crate) struct VirtualKeyboardPlugin;
+impl Plugin for VirtualKeyboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<KeyboardLayout>()
+            .register_type::<VirtualKeyboardTarget>()
+            .init_resource::<VirtualKeyboardSettings>()
+            .add_event::<VirtualKeyPressed>()
+            .add_systems(
+                Update,
+                (
+                    click_virtual_keys,
+                    navigate_virtual_keyboard,
+                    attach_virtual_keyboard,
+                    update_virtual_keys,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Presses the keys of a virtual keyboard that have been clicked.
+///
+/// The keyboard and its keys keep focus on the target when clicked, so that
+/// the entry of the target is not committed.
+fn click_virtual_keys(
+    mut focus: ResMut<UiFocus>,
+    mut events: EventWriter<VirtualKeyPressed>,
+    mut keyboards: Query<&mut VirtualKeyboard>,
+    keys: Query<(&Interaction, &KeyboardKey), Changed<Interaction>>,
+) {
+    for (interaction, key) in &keys {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut keyboard) = keyboards.get_mut(key.keyboard) else {
+            continue;
+        };
+
+        keyboard.cursor = (key.row, key.column);
+        let Some(pressed) = keyboard.selected() else {
+            continue;
+        };
+
+        if keyboard.press(pressed, &mut events) {
+            focus.entity = None;
+        }
+    }
+}
+
+/// Moves the selection and presses keys of the virtual keyboard using any
+/// connected gamepad.
+///
+/// The d-pad moves the selection, south presses the selected key, east erases
+/// a character, west types a space, north toggles shift, start submits, and
+/// select closes the keyboard.
+fn navigate_virtual_keyboard(
+    gamepad: Res<ButtonInput<GamepadButton>>,
+    mut focus: ResMut<UiFocus>,
+    mut back: ResMut<BackInput>,
+    mut events: EventWriter<VirtualKeyPressed>,
+    mut keyboards: Query<&mut VirtualKeyboard>,
+) {
+    for mut keyboard in &mut keyboards {
+        for button in gamepad.get_just_pressed() {
+            let pressed = match button.button_type {
+                GamepadButtonType::DPadUp => {
+                    keyboard.move_cursor(-1, 0);
+                    continue;
+                }
+                GamepadButtonType::DPadDown => {
+                    keyboard.move_cursor(1, 0);
+                    continue;
+                }
+                GamepadButtonType::DPadLeft => {
+                    keyboard.move_cursor(0, -1);
+                    continue;
+                }
+                GamepadButtonType::DPadRight => {
+                    keyboard.move_cursor(0, 1);
+                    continue;
+                }
+                GamepadButtonType::South => match keyboard.selected() {
+                    Some(key) => key,
+                    None => continue,
+                },
+                GamepadButtonType::East => {
+                    // The back button erases instead of closing a modal.
+                    back.consume();
+                    VirtualKey::Backspace
+                }
+                GamepadButtonType::West => VirtualKey::Space,
+                GamepadButtonType::North => VirtualKey::Shift,
+                GamepadButtonType::Start => VirtualKey::Enter,
+                GamepadButtonType::Select => VirtualKey::Close,
+                _ => continue,
+            };
+
+            if keyboard.press(pressed, &mut events) {
+                focus.entity = None;
+            }
+        }
+    }
+}
+
+/// Opens the virtual keyboard when a text input with a
+/// [`VirtualKeyboardTarget`] gains focus, and closes it when the input loses
+/// focus.
+fn attach_virtual_keyboard(
+    mut cmd: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<VirtualKeyboardSettings>,
+    gamepads: Res<Gamepads>,
+    focus: Res<UiFocus>,
+    targets: Query<&VirtualKeyboardTarget>,
+    keyboards: Query<(Entity, &VirtualKeyboard)>,
+) {
+    let wanted = focus
+        .entity
+        .filter(|_| !settings.require_gamepad || gamepads.iter().next().is_some())
+        .and_then(|entity| targets.get(entity).ok().map(|t| (entity, t.layout)));
+
+    let mut open = false;
+    for (keyboard_id, keyboard) in &keyboards {
+        if wanted == Some((keyboard.target, keyboard.layout)) && !open {
+            open = true;
+            continue;
+        }
+
+        cmd.entity(keyboard_id).despawn_recursive();
+    }
+
+    if let (Some((target, layout)), false) = (wanted, open) {
+        build_virtual_keyboard(&mut cmd, &asset_server, &settings, target, layout);
+    }
+}
+
+/// Spawns a virtual keyboard with the given layout in the overlay, attached
+/// to the given text input.
+fn build_virtual_keyboard(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    settings: &VirtualKeyboardSettings,
+    target: Entity,
+    layout: KeyboardLayout,
+) {
+    let spacing = Val::Px(settings.spacing);
+    let direction = settings.anchor.direction();
+
+    let mut container = NodeBundleBuilder::default();
+    container.insert(UiLayer::Keyboard);
+
+    let style = container.get_style_mut();
+    style.position_type = PositionType::Absolute;
+    style.width = Val::Percent(100.0);
+    style.height = Val::Percent(100.0);
+    style.padding = UiRect::all(Val::Px(settings.margin));
+    style.justify_content = match direction.x {
+        d if d < 0 => JustifyContent::FlexStart,
+        0 => JustifyContent::Center,
+        _ => JustifyContent::FlexEnd,
+    };
+    style.align_items = match direction.y {
+        d if d < 0 => AlignItems::FlexStart,
+        0 => AlignItems::Center,
+        _ => AlignItems::FlexEnd,
+    };
+    let keyboard_id = container.build(cmd, asset_server);
+
+    let mut panel = NodeBundleBuilder::default();
+    panel.set_parent(Some(keyboard_id));
+    settings
+        .background
+        .clone()
+        .apply_to_node(&mut panel, asset_server);
+    panel.insert((KeepsFocus, Interaction::default(), FocusPolicy::Block));
+
+    let style = panel.get_style_mut();
+    style.flex_direction = FlexDirection::Column;
+    style.align_items = AlignItems::Center;
+    style.row_gap = spacing;
+    style.padding = UiRect::all(spacing);
+    let panel_id = panel.build(cmd, asset_server);
+
+    let mut text = settings.text.clone();
+    text.sections.truncate(1);
+
+    let mut rows = Vec::new();
+    for (row, keys) in layout.rows().into_iter().enumerate() {
+        let mut row_node = NodeBundleBuilder::default();
+        row_node.set_parent(Some(panel_id));
+        row_node.get_style_mut().column_gap = spacing;
+        let row_id = row_node.build(cmd, asset_server);
+
+        let mut parts = Vec::new();
+        for (column, key) in keys.into_iter().enumerate() {
+            let units = key.width();
+
+            let mut button = NodeBundleBuilder::default();
+            button.set_parent(Some(row_id));
+            button.bundle_type(NodeBundleType::Button);
+            settings
+                .key_background
+                .clone()
+                .apply_to_node(&mut button, asset_server);
+            button.insert((
+                KeyboardKey {
+                    keyboard: keyboard_id,
+                    row,
+                    column,
+                },
+                KeepsFocus,
+            ));
+
+            let style = button.get_style_mut();
+            style.width = Val::Px(settings.key_size * units + settings.spacing * (units - 1.0));
+            style.height = Val::Px(settings.key_size);
+            style.border = UiRect::all(Val::Px(settings.selection_width));
+            style.justify_content = JustifyContent::Center;
+            style.align_items = AlignItems::Center;
+            let button_id = button.build(cmd, asset_server);
+
+            let mut label_text = text.clone();
+            if let Some(section) = label_text.sections.first_mut() {
+                section.text = key.label(false, false);
+            }
+
+            let mut label = NodeBundleBuilder::default();
+            label.set_parent(Some(button_id));
+            label_text.apply_to_node(&mut label, asset_server);
+            let label_id = label.build(cmd, asset_server);
+
+            parts.push(KeyParts {
+                key,
+                button: button_id,
+                label: label_id,
+            });
+        }
+        rows.push(parts);
+    }
+
+    cmd.entity(keyboard_id).insert(VirtualKeyboard {
+        target,
+        layout,
+        cursor: (0, 0),
+        shift: false,
+        caps: false,
+        rows,
+    });
+}
+
+/// Updates the labels, shift state, and selection of each virtual keyboard
+/// that has changed.
+fn update_virtual_keys(
+    settings: Res<VirtualKeyboardSettings>,
+    keyboards: Query<&VirtualKeyboard, Changed<VirtualKeyboard>>,
+    mut borders: Query<&mut BorderColor>,
+    mut texts: Query<&mut Text>,
+) {
+    let text_color = settings
+        .text
+        .sections
+        .first()
+        .map_or(Color::WHITE, |section| section.color);
+
+    for keyboard in &keyboards {
+        for (row, keys) in keyboard.rows.iter().enumerate() {
+            for (column, parts) in keys.iter().enumerate() {
+                if let Ok(mut border) = borders.get_mut(parts.button) {
+                    let color = match keyboard.cursor == (row, column) {
+                        true => settings.selection_color,
+                        false => Color::NONE,
+                    };
+                    if border.0 != color {
+                        border.0 = color;
+                    }
+                }
+
+                let Ok(mut text) = texts.get_mut(parts.label) else {
+                    continue;
+                };
+
+                let active = match parts.key {
+                    VirtualKey::Shift => keyboard.shift,
+                    VirtualKey::CapsLock => keyboard.caps,
+                    _ => false,
+                };
+
+                let value = parts.key.label(keyboard.shift, keyboard.caps);
+                let color = match active {
+                    true => settings.active_color,
+                    false => text_color,
+                };
+
+                if let Some(section) = text.sections.first_mut() {
+                    if section.value != value {
+                        section.value = value;
+                    }
+                    if section.style.color != color {
+                        section.style.color = color;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::input::ButtonState;
+    use bevy::input::gamepad::{
+        GamepadButtonChangedEvent,
+        GamepadConnection,
+        GamepadConnectionEvent,
+        GamepadEvent,
+        GamepadInfo,
+    };
+    use bevy::input::mouse::MouseButtonInput;
+
+    use super::*;
+    use crate::prelude::{ModalNodeBuilder, ModalStack, Spinner, SpinnerNodeBuilder, UiNode};
+    use crate::widgets::test_app;
+
+    /// Creates an unspawned keyboard with the given layout.
+    fn keyboard(layout: KeyboardLayout) -> VirtualKeyboard {
+        VirtualKeyboard {
+            target: Entity::PLACEHOLDER,
+            layout,
+            cursor: (0, 0),
+            shift: false,
+            caps: false,
+            rows: layout
+                .rows()
+                .into_iter()
+                .map(|keys| {
+                    keys.into_iter()
+                        .map(|key| KeyParts {
+                            key,
+                            button: Entity::PLACEHOLDER,
+                            label: Entity::PLACEHOLDER,
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Spawns the given node, returning its root entity.
+    fn spawn(app: &mut App, node: impl Into<UiNode>) -> Entity {
+        let node = node.into();
+        app.world
+            .run_system_once(move |mut cmd: Commands, asset_server: Res<AssetServer>| {
+                node.clone().build(&mut cmd, &asset_server)
+            })
+    }
+
+    /// Creates an app with a connected gamepad and a focused spinner, which
+    /// opens a numeric keyboard.
+    fn spinner_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.world.send_event(GamepadConnectionEvent {
+            gamepad: Gamepad::new(0),
+            connection: GamepadConnection::Connected(GamepadInfo {
+                name: "Test Gamepad".to_string(),
+            }),
+        });
+
+        let spinner = spawn(&mut app, SpinnerNodeBuilder::default());
+        app.world.resource_mut::<UiFocus>().entity = Some(spinner);
+        app.update();
+        (app, spinner)
+    }
+
+    /// Presses and releases the given button of the connected gamepad.
+    fn press(app: &mut App, button: GamepadButtonType) {
+        for value in [1.0, 0.0] {
+            app.world
+                .send_event(GamepadEvent::Button(GamepadButtonChangedEvent::new(
+                    Gamepad::new(0),
+                    button,
+                    value,
+                )));
+            app.update();
+        }
+    }
+
+    /// Gets the state of the open keyboard, if any.
+    fn open_keyboard(app: &mut App) -> Option<VirtualKeyboard> {
+        app.world
+            .query::<&VirtualKeyboard>()
+            .iter(&app.world)
+            .next()
+            .cloned()
+    }
+
+    /// Gets the typed entry of the given spinner.
+    fn entry(app: &App, spinner: Entity) -> Option<String> {
+        app.world.get::<Spinner>(spinner).unwrap().entry.clone()
+    }
+
+    #[test]
+    fn move_cursor_wraps_around_edges() {
+        let mut keyboard = keyboard(KeyboardLayout::Qwerty);
+
+        keyboard.move_cursor(0, -1);
+        assert_eq!(keyboard.cursor, (0, 10));
+        assert_eq!(keyboard.selected(), Some(VirtualKey::Backspace));
+
+        keyboard.move_cursor(0, 1);
+        assert_eq!(keyboard.cursor, (0, 0));
+
+        keyboard.move_cursor(-1, 0);
+        assert_eq!(keyboard.cursor, (4, 0));
+        assert_eq!(keyboard.selected(), Some(VirtualKey::Space));
+
+        keyboard.move_cursor(1, 0);
+        assert_eq!(keyboard.cursor, (0, 2));
+    }
+
+    #[test]
+    fn move_cursor_keeps_horizontal_position_between_rows() {
+        let mut keyboard = keyboard(KeyboardLayout::Qwerty);
+        keyboard.cursor = (0, 10);
+
+        keyboard.move_cursor(1, 0);
+        assert_eq!(keyboard.cursor, (1, 9));
+
+        keyboard.move_cursor(3, 0);
+        assert_eq!(keyboard.cursor, (4, 1));
+        assert_eq!(keyboard.selected(), Some(VirtualKey::Close));
+
+        keyboard.move_cursor(1, 0);
+        assert_eq!(keyboard.cursor, (0, 8));
+    }
+
+    #[test]
+    fn caps_lock_only_affects_letters() {
+        let letter = VirtualKey::Char {
+            normal: 'a',
+            shifted: 'A',
+        };
+        assert_eq!(letter.character(false, false), 'a');
+        assert_eq!(letter.character(true, false), 'A');
+        assert_eq!(letter.character(false, true), 'A');
+        assert_eq!(letter.character(true, true), 'a');
+
+        let digit = VirtualKey::Char {
+            normal: '1',
+            shifted: '!',
+        };
+        assert_eq!(digit.character(false, false), '1');
+        assert_eq!(digit.character(true, false), '!');
+        assert_eq!(digit.character(false, true), '1');
+        assert_eq!(digit.character(true, true), '!');
+    }
+
+    #[test]
+    fn keyboard_requires_connected_gamepad() {
+        let mut app = test_app();
+        let spinner = spawn(&mut app, SpinnerNodeBuilder::default());
+        app.world.resource_mut::<UiFocus>().entity = Some(spinner);
+        app.update();
+
+        assert!(open_keyboard(&mut app).is_none());
+    }
+
+    #[test]
+    fn gamepad_types_into_focused_spinner() {
+        let (mut app, spinner) = spinner_app();
+
+        let keyboard = open_keyboard(&mut app).expect("keyboard should open");
+        assert_eq!(keyboard.target(), spinner);
+        assert_eq!(keyboard.layout(), KeyboardLayout::Numeric);
+        assert_eq!(keyboard.cursor, (0, 0));
+
+        press(&mut app, GamepadButtonType::DPadRight);
+        assert_eq!(open_keyboard(&mut app).unwrap().cursor, (0, 1));
+
+        press(&mut app, GamepadButtonType::South);
+        assert_eq!(entry(&app, spinner).as_deref(), Some("8"));
+
+        press(&mut app, GamepadButtonType::DPadDown);
+        assert_eq!(open_keyboard(&mut app).unwrap().cursor, (1, 1));
+
+        press(&mut app, GamepadButtonType::South);
+        assert_eq!(entry(&app, spinner).as_deref(), Some("85"));
+
+        press(&mut app, GamepadButtonType::East);
+        assert_eq!(entry(&app, spinner).as_deref(), Some("8"));
+    }
+
+    #[test]
+    fn key_events_are_sent_to_target() {
+        let (mut app, spinner) = spinner_app();
+        app.world
+            .resource_mut::<Events<VirtualKeyPressed>>()
+            .clear();
+
+        press(&mut app, GamepadButtonType::South);
+
+        let events = app.world.resource::<Events<VirtualKeyPressed>>();
+        let keys = events
+            .get_reader()
+            .read(events)
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            [VirtualKeyPressed {
+                target: spinner,
+                key: Key::Character("7".into()),
+            }]
+        );
+    }
+
+    #[test]
+    fn clicking_keys_keeps_entry_focused() {
+        let (mut app, spinner) = spinner_app();
+        press(&mut app, GamepadButtonType::South);
+        assert_eq!(entry(&app, spinner).as_deref(), Some("7"));
+
+        // Interactions are not updated headless, so the key is pressed
+        // directly along with the mouse button.
+        let key = open_keyboard(&mut app).unwrap().rows[0][1].button;
+        app.world.entity_mut(key).insert(Interaction::Pressed);
+        app.world.send_event(MouseButtonInput {
+            button: MouseButton::Left,
+            state: ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+        assert_eq!(app.world.resource::<UiFocus>().entity, Some(spinner));
+
+        // The spinner is not ordered after the keyboard, so the pressed key
+        // may only be typed on the next frame.
+        app.update();
+        assert_eq!(app.world.resource::<UiFocus>().entity, Some(spinner));
+        assert_eq!(entry(&app, spinner).as_deref(), Some("78"));
+    }
+
+    #[test]
+    fn select_closes_keyboard() {
+        let (mut app, _) = spinner_app();
+        assert!(open_keyboard(&mut app).is_some());
+
+        press(&mut app, GamepadButtonType::Select);
+        assert_eq!(app.world.resource::<UiFocus>().entity, None);
+        assert!(open_keyboard(&mut app).is_none());
+    }
+
+    #[test]
+    fn erasing_does_not_dismiss_modal() {
+        let (mut app, _) = spinner_app();
+        spawn(&mut app, ModalNodeBuilder::default().dismissible(true));
+        app.update();
+        assert_eq!(app.world.resource::<ModalStack>().len(), 1);
+
+        press(&mut app, GamepadButtonType::East);
+        assert_eq!(app.world.resource::<ModalStack>().len(), 1);
+
+        press(&mut app, GamepadButtonType::Select);
+        press(&mut app, GamepadButtonType::East);
+        assert!(app.world.resource::<ModalStack>().is_empty());
+    }
+}
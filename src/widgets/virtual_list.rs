@@ -0,0 +1,297 @@
+//! Contains the components and systems that spawn and recycle the visible rows
+//! of a virtual list.
+
+use std::ops::Range;
+
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+use super::scroll::update_scroll_bounds;
+use crate::prelude::{NodeBundleBuilder, RowBuilder, ScrollContent, ScrollPosition, ScrollView};
+
+/// A component that stores the settings of a virtual list.
+///
+/// Game code may modify the item count or call [`VirtualList::refresh`] when
+/// the underlying data changes to rebuild the visible rows.
+#[derive(Debug, Clone, Component)]
+pub struct VirtualList {
+    /// The number of items in the list.
+    pub item_count: usize,
+
+    /// The height of each row, in logical pixels.
+    pub row_height: f32,
+
+    /// The number of additional rows to keep spawned above and below the
+    /// visible area.
+    pub buffer: usize,
+
+    /// The function used to create the node for each row.
+    pub row_builder: RowBuilder,
+
+    /// Whether all visible rows should be rebuilt on the next update.
+    pub(crate) dirty: bool,
+}
+
+impl VirtualList {
+    /// Marks all visible rows to be rebuilt on the next update.
+    pub fn refresh(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Gets the range of item indices that should currently be spawned for the
+    /// given scroll offset and viewport height.
+    pub fn visible_range(&self, offset: f32, viewport_height: f32) -> Range<usize> {
+        if self.item_count == 0 || self.row_height <= 0.0 {
+            return 0 .. 0;
+        }
+
+        let first = (offset / self.row_height).floor().max(0.0) as usize;
+        let last = ((offset + viewport_height) / self.row_height)
+            .ceil()
+            .max(0.0) as usize;
+
+        let start = first.saturating_sub(self.buffer).min(self.item_count);
+        let end = (last + self.buffer).min(self.item_count);
+        start .. end
+    }
+}
+
+/// A component for a recycled row container within a virtual list.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct VirtualRow {
+    /// The index of the item currently displayed by this row, if any.
+    index: Option<usize>,
+}
+
+/// This plugin handles spawning and recycling the rows of virtual lists.
+pub(crate) struct VirtualListPlugin;
+impl Plugin for VirtualListPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_virtual_lists.after(update_scroll_bounds));
+    }
+}
+
+/// Updates the height of the list contents and assigns the visible items to
+/// row containers, reusing containers that have scrolled out of view.
+fn update_virtual_lists(
+    mut cmd: Commands,
+    asset_server: Res<AssetServer>,
+    mut lists: Query<(&mut VirtualList, &ScrollView, &ScrollPosition, &Children)>,
+    mut contents: Query<(&mut Style, Option<&Children>), With<ScrollContent>>,
+    mut rows: Query<(&mut VirtualRow, &mut Style), Without<ScrollContent>>,
+) {
+    for (mut list, view, position, children) in &mut lists {
+        let Some(content) = children.iter().copied().find(|c| contents.contains(*c)) else {
+            continue;
+        };
+
+        let Ok((mut content_style, content_children)) = contents.get_mut(content) else {
+            continue;
+        };
+
+        let height = Val::Px(list.item_count as f32 * list.row_height);
+        if content_style.height != height {
+            content_style.height = height;
+        }
+
+        let visible = list.visible_range(position.offset.y, view.viewport_size.y);
+        let slots = content_children
+            .map(|c| c.iter().copied().filter(|c| rows.contains(*c)).collect())
+            .unwrap_or_else(Vec::new);
+
+        // Rows that still display a visible item keep their contents, and only
+        // the rows whose item changed are rebuilt.
+        let mut shown = HashSet::new();
+        let mut free = Vec::new();
+        for slot in slots {
+            let Ok((row, _)) = rows.get(slot) else {
+                continue;
+            };
+
+            match row.index {
+                Some(index) if visible.contains(&index) && !list.dirty => {
+                    shown.insert(index);
+                }
+                _ => free.push(slot),
+            }
+        }
+
+        for index in visible.filter(|i| !shown.contains(i)) {
+            let top = Val::Px(index as f32 * list.row_height);
+
+            let slot = match free.pop() {
+                Some(slot) => {
+                    let Ok((mut row, mut style)) = rows.get_mut(slot) else {
+                        continue;
+                    };
+
+                    row.index = Some(index);
+                    style.top = top;
+                    style.height = Val::Px(list.row_height);
+                    style.display = Display::Flex;
+                    cmd.entity(slot).despawn_descendants();
+                    slot
+                }
+
+                None => {
+                    let mut slot_node = NodeBundleBuilder::default();
+                    slot_node.set_parent(Some(content));
+                    slot_node.insert(VirtualRow { index: Some(index) });
+
+                    let style = slot_node.get_style_mut();
+                    style.position_type = PositionType::Absolute;
+                    style.left = Val::Px(0.0);
+                    style.top = top;
+                    style.width = Val::Percent(100.0);
+                    style.height = Val::Px(list.row_height);
+
+                    slot_node.build(&mut cmd, &asset_server)
+                }
+            };
+
+            list.row_builder
+                .build(index)
+                .build_node(&mut cmd, &asset_server, Some(slot));
+        }
+
+        for slot in free {
+            let Ok((mut row, mut style)) = rows.get_mut(slot) else {
+                continue;
+            };
+
+            if row.index.is_some() {
+                row.index = None;
+                style.display = Display::None;
+                cmd.entity(slot).despawn_descendants();
+            }
+        }
+
+        if list.dirty {
+            list.dirty = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::prelude::{UiNode, VirtualListNodeBuilder};
+    use crate::widgets::test_app;
+
+    /// Creates a virtual list with the given item count, a row height of 20,
+    /// and a buffer of 2 rows.
+    fn list(item_count: usize) -> VirtualList {
+        VirtualList {
+            item_count,
+            row_height: 20.0,
+            buffer: 2,
+            row_builder: RowBuilder::default(),
+            dirty: false,
+        }
+    }
+
+    /// Gets the displayed index and the row node of each visible row slot.
+    fn shown_rows(app: &mut App) -> Vec<(usize, Entity)> {
+        let mut rows = app
+            .world
+            .query::<(&VirtualRow, &Children)>()
+            .iter(&app.world)
+            .filter_map(|(row, children)| Some((row.index?, children[0])))
+            .collect::<Vec<_>>();
+        rows.sort();
+        rows
+    }
+
+    #[test]
+    fn visible_range_without_items_is_empty() {
+        assert_eq!(list(0).visible_range(0.0, 100.0), 0 .. 0);
+        assert_eq!(list(0).visible_range(50.0, 100.0), 0 .. 0);
+    }
+
+    #[test]
+    fn visible_range_without_row_height_is_empty() {
+        let mut list = list(10);
+        list.row_height = 0.0;
+        assert_eq!(list.visible_range(0.0, 100.0), 0 .. 0);
+    }
+
+    #[test]
+    fn visible_range_includes_buffer() {
+        assert_eq!(list(100).visible_range(0.0, 100.0), 0 .. 7);
+        assert_eq!(list(100).visible_range(200.0, 100.0), 8 .. 17);
+        assert_eq!(list(100).visible_range(210.0, 100.0), 8 .. 18);
+    }
+
+    #[test]
+    fn visible_range_is_clamped_to_items() {
+        assert_eq!(list(5).visible_range(0.0, 1000.0), 0 .. 5);
+        assert_eq!(list(5).visible_range(60.0, 1000.0), 1 .. 5);
+        assert_eq!(list(5).visible_range(1000.0, 100.0), 5 .. 5);
+    }
+
+    /// Spawns a virtual list with 100 items, a row height of 20, and a buffer
+    /// of 2 rows.
+    fn spawn_list(app: &mut App) -> Entity {
+        app.world
+            .run_system_once(|mut cmd: Commands, asset_server: Res<AssetServer>| {
+                let builder =
+                    VirtualListNodeBuilder::new(100, 20.0, RowBuilder::default()).buffer(2);
+                UiNode::from(builder).build(&mut cmd, &asset_server)
+            })
+    }
+
+    /// Scrolls the given list to the given vertical offset.
+    fn scroll_to(app: &mut App, list: Entity, offset: f32) {
+        let mut position = app.world.get_mut::<ScrollPosition>(list).unwrap();
+        position.offset.y = offset;
+    }
+
+    #[test]
+    fn scrolling_keeps_rows_with_unchanged_index() {
+        let mut app = test_app();
+        let list_id = spawn_list(&mut app);
+        app.update();
+
+        // Headless apps are not laid out, so the viewport has no height and
+        // only the buffer rows are shown.
+        let before = shown_rows(&mut app);
+        assert_eq!(before.iter().map(|r| r.0).collect::<Vec<_>>(), [0, 1]);
+
+        scroll_to(&mut app, list_id, 40.0);
+        app.update();
+
+        let after = shown_rows(&mut app);
+        assert_eq!(after.iter().map(|r| r.0).collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert_eq!(after[.. 2], before[..]);
+
+        scroll_to(&mut app, list_id, 100.0);
+        app.update();
+
+        let moved = shown_rows(&mut app);
+        assert_eq!(moved.iter().map(|r| r.0).collect::<Vec<_>>(), [3, 4, 5, 6]);
+        assert_eq!(moved[0], after[3]);
+        for row in &moved[1 ..] {
+            assert!(after.iter().all(|old| old.1 != row.1));
+        }
+    }
+
+    #[test]
+    fn refreshing_rebuilds_all_rows() {
+        let mut app = test_app();
+        let list_id = spawn_list(&mut app);
+        app.update();
+        let before = shown_rows(&mut app);
+
+        app.world.get_mut::<VirtualList>(list_id).unwrap().refresh();
+        app.update();
+
+        let after = shown_rows(&mut app);
+        assert_eq!(after.len(), before.len());
+        for (before, after) in before.iter().zip(&after) {
+            assert_eq!(before.0, after.0);
+            assert_ne!(before.1, after.1);
+        }
+    }
+}
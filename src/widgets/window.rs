@@ -0,0 +1,599 @@
+//! Contains the components, events, and systems that move, resize, minimize,
+//! and close floating windows.
+
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::utils::HashMap;
+use bevy::window::PrimaryWindow;
+
+use super::cursor_position;
+use crate::prelude::{
+    AnchorPoint,
+    DataBlock,
+    NodeBundleBuilder,
+    NodeBundleType,
+    NodeChildren,
+    NodePosition,
+    NodeWindow,
+    UiNode,
+};
+
+/// The distance, in logical pixels, a window must remain within its parent
+/// while being dragged, so it can always be dragged back.
+const MIN_VISIBLE: f32 = 24.0;
+
+/// A component that stores the state and settings of a floating window.
+///
+/// Game code may write to [`FloatingWindow::open`] and
+/// [`FloatingWindow::minimized`] to show, hide, or minimize the window.
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct FloatingWindow {
+    /// Whether the window is displayed.
+    pub open: bool,
+
+    /// Whether only the title bar of the window is displayed.
+    pub minimized: bool,
+
+    /// The smallest size the window may be resized to, in logical pixels.
+    pub min_size: Vec2,
+
+    /// The largest size the window may be resized to, in logical pixels.
+    pub max_size: Vec2,
+
+    /// The key the layout of the window is stored under, if any.
+    pub(crate) persist_key: Option<String>,
+
+    /// The node that contains the children of the window.
+    content: Entity,
+
+    /// The height of the window before it was minimized.
+    restore_height: Val,
+}
+
+/// An event that is sent when a floating window is closed using its close
+/// button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct FloatingWindowClosed {
+    /// The window entity.
+    pub window: Entity,
+}
+
+/// A resource that stores the last known position and size of each floating
+/// window with a persist key.
+///
+/// Game code may save and load this resource to keep window layouts between
+/// sessions.
+#[derive(Debug, Default, Clone, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct WindowLayouts {
+    /// The position and size of each window, by persist key, in logical
+    /// pixels relative to the parent of the window.
+    pub layouts: HashMap<String, Rect>,
+}
+
+/// A resource that tracks the z-index given to the most recently raised
+/// window.
+#[derive(Debug, Default, Resource)]
+struct WindowStack {
+    /// The z-index of the top-most window.
+    top: i32,
+}
+
+/// The action performed by a title bar button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WindowAction {
+    /// Toggles whether the window is minimized.
+    Minimize,
+
+    /// Closes the window.
+    Close,
+}
+
+/// A component for the buttons within the title bar of a window.
+#[derive(Debug, Clone, Copy, Component)]
+struct WindowButton {
+    /// The window this button belongs to.
+    window: Entity,
+
+    /// The action performed when pressed.
+    action: WindowAction,
+}
+
+/// The state of a title bar or resize handle while it is being dragged.
+#[derive(Debug, Clone, Copy)]
+struct WindowDrag {
+    /// The position of the cursor when the drag started.
+    cursor: Vec2,
+
+    /// The position and size of the window when the drag started, relative to
+    /// its parent.
+    rect: Rect,
+}
+
+/// A component for the title bar of a window, which moves the window when
+/// dragged.
+#[derive(Debug, Clone, Copy, Component)]
+struct WindowTitleBar {
+    /// The window this title bar belongs to.
+    window: Entity,
+
+    /// The current drag, if any.
+    drag: Option<WindowDrag>,
+}
+
+/// A component for a handle along the edge or corner of a window, which
+/// resizes the window when dragged.
+#[derive(Debug, Clone, Copy, Component)]
+struct WindowResizeHandle {
+    /// The window this handle belongs to.
+    window: Entity,
+
+    /// The edges moved by this handle. The x component is -1 for the left
+    /// edge and 1 for the right edge, and the y component is -1 for the top
+    /// edge and 1 for the bottom edge.
+    edges: IVec2,
+
+    /// The current drag, if any.
+    drag: Option<WindowDrag>,
+}
+
+/// This plugin handles moving, resizing, raising, minimizing, and closing
+/// floating windows.
+pub(crate) struct FloatingWindowPlugin;
+impl Plugin for FloatingWindowPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<FloatingWindow>()
+            .register_type::<WindowLayouts>()
+            .init_resource::<WindowLayouts>()
+            .init_resource::<WindowStack>()
+            .add_event::<FloatingWindowClosed>()
+            .add_systems(
+                Update,
+                (
+                    restore_window_layouts,
+                    raise_pressed_windows,
+                    press_window_buttons,
+                    drag_window_title_bars,
+                    drag_window_resize_handles,
+                    show_windows,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Consumes the given window node builder and creates a floating window, along
+/// with its title bar, content, and resize handles.
+///
+/// Returns the entity of the window.
+pub(crate) fn build_window(
+    cmd: &mut Commands,
+    asset_server: &AssetServer,
+    mut window_node: NodeBundleBuilder,
+    window: NodeWindow,
+    children: NodeChildren,
+) -> Entity {
+    let style = window_node.get_style_mut();
+    style.position_type = PositionType::Absolute;
+    style.flex_direction = FlexDirection::Column;
+    let restore_height = style.height;
+
+    let window_id = window_node.build(cmd, asset_server);
+
+    let mut title_bar = NodeBundleBuilder::default();
+    title_bar.set_parent(Some(window_id));
+    window
+        .title_bar_background
+        .apply_to_node(&mut title_bar, asset_server);
+    title_bar.insert((
+        WindowTitleBar {
+            window: window_id,
+            drag: None,
+        },
+        Interaction::default(),
+    ));
+
+    let style = title_bar.get_style_mut();
+    style.flex_shrink = 0.0;
+    style.height = Val::Px(window.title_bar_height);
+    style.align_items = AlignItems::Center;
+    style.padding.left = Val::Px(window.title_bar_height / 4.0);
+    let title_bar_id = title_bar.build(cmd, asset_server);
+
+    let mut button_text = window.title.clone();
+    button_text.sections.truncate(1);
+    button_text.anchor_point = AnchorPoint::Center;
+
+    let mut title_node = NodeBundleBuilder::default();
+    title_node.set_parent(Some(title_bar_id));
+    title_node.get_style_mut().flex_grow = 1.0;
+    window.title.apply_to_node(&mut title_node, asset_server);
+    title_node.build(cmd, asset_server);
+
+    let buttons = [
+        (window.minimizable, WindowAction::Minimize, "_"),
+        (window.closable, WindowAction::Close, "x"),
+    ];
+
+    for (_, action, label) in buttons.into_iter().filter(|(shown, _, _)| *shown) {
+        let mut button = NodeBundleBuilder::default();
+        button.set_parent(Some(title_bar_id));
+        button.bundle_type(NodeBundleType::Button);
+        button.insert((
+            WindowButton {
+                window: window_id,
+                action,
+            },
+            BackgroundColor(Color::NONE),
+        ));
+
+        let style = button.get_style_mut();
+        style.flex_shrink = 0.0;
+        style.width = Val::Px(window.title_bar_height);
+        style.height = Val::Px(window.title_bar_height);
+
+        let mut text = button_text.clone();
+        if let Some(section) = text.sections.first_mut() {
+            section.text = label.to_string();
+        }
+
+        button.set_children(vec![UiNode::Text {
+            background: default(),
+            position: NodePosition::Relative {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+            },
+            text,
+        }]);
+        button.build(cmd, asset_server);
+    }
+
+    let mut content = NodeBundleBuilder::default();
+    content.set_parent(Some(window_id));
+
+    let style = content.get_style_mut();
+    style.flex_grow = 1.0;
+    style.flex_direction = FlexDirection::Column;
+    style.overflow = Overflow::clip();
+    children.apply_to_node(&mut content, asset_server);
+    let content_id = content.build(cmd, asset_server);
+
+    if window.resizable {
+        for x in -1 ..= 1 {
+            for y in -1 ..= 1 {
+                if x != 0 || y != 0 {
+                    build_resize_handle(cmd, window_id, IVec2::new(x, y), window.handle_size);
+                }
+            }
+        }
+    }
+
+    cmd.entity(window_id).insert((
+        FloatingWindow {
+            open: true,
+            minimized: window.minimized,
+            min_size: window.min_size,
+            max_size: window.max_size,
+            persist_key: window.persist_key,
+            content: content_id,
+            restore_height,
+        },
+        Interaction::default(),
+    ));
+
+    window_id
+}
+
+/// Creates an invisible handle along the given edges of a window.
+fn build_resize_handle(cmd: &mut Commands, window: Entity, edges: IVec2, size: f32) {
+    let edge = |direction: i32| match direction {
+        d if d < 0 => (Val::Px(-size / 2.0), Val::Auto, Val::Px(size)),
+        0 => (Val::Px(size / 2.0), Val::Px(size / 2.0), Val::Auto),
+        _ => (Val::Auto, Val::Px(-size / 2.0), Val::Px(size)),
+    };
+
+    let (left, right, width) = edge(edges.x);
+    let (top, bottom, height) = edge(edges.y);
+
+    cmd.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left,
+                right,
+                top,
+                bottom,
+                width,
+                height,
+                ..default()
+            },
+            ..default()
+        },
+        WindowResizeHandle {
+            window,
+            edges,
+            drag: None,
+        },
+        Interaction::default(),
+        // The handles overlap the title bar, which must not also start
+        // dragging the window when a handle is pressed.
+        FocusPolicy::Block,
+    ))
+    .set_parent(window);
+}
+
+/// Gets the position and size of a node relative to the given parent node, in
+/// logical pixels.
+fn relative_rect(
+    node: (&Node, &GlobalTransform),
+    parent: Option<(&Node, &GlobalTransform)>,
+) -> Rect {
+    let rect = node.0.logical_rect(node.1);
+    let origin = parent.map_or(Vec2::ZERO, |(node, transform)| {
+        node.logical_rect(transform).min
+    });
+
+    Rect::from_corners(rect.min - origin, rect.max - origin)
+}
+
+/// Applies the stored layout of each newly spawned window with a persist key.
+fn restore_window_layouts(
+    layouts: Res<WindowLayouts>,
+    mut windows: Query<(&FloatingWindow, &mut Style), Added<FloatingWindow>>,
+) {
+    for (window, mut style) in &mut windows {
+        let Some(rect) = window
+            .persist_key
+            .as_ref()
+            .and_then(|key| layouts.layouts.get(key))
+        else {
+            continue;
+        };
+
+        style.left = Val::Px(rect.min.x);
+        style.top = Val::Px(rect.min.y);
+        style.width = Val::Px(rect.width());
+        style.height = Val::Px(rect.height());
+    }
+}
+
+/// Brings a window to the front of its siblings when it is pressed.
+fn raise_pressed_windows(
+    mut stack: ResMut<WindowStack>,
+    mut windows: Query<(Ref<Interaction>, &mut ZIndex), With<FloatingWindow>>,
+) {
+    for (interaction, mut z_index) in &mut windows {
+        if !interaction.is_changed() || *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let is_top = stack.top > 0 && matches!(*z_index, ZIndex::Local(z) if z == stack.top);
+        if !is_top {
+            stack.top += 1;
+            *z_index = ZIndex::Local(stack.top);
+        }
+    }
+}
+
+/// Minimizes or closes a window when one of its title bar buttons is pressed.
+fn press_window_buttons(
+    mut closed: EventWriter<FloatingWindowClosed>,
+    buttons: Query<(&Interaction, &WindowButton), Changed<Interaction>>,
+    mut windows: Query<&mut FloatingWindow>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut window) = windows.get_mut(button.window) else {
+            continue;
+        };
+
+        match button.action {
+            WindowAction::Minimize => window.minimized = !window.minimized,
+            WindowAction::Close => {
+                window.open = false;
+                closed.send(FloatingWindowClosed {
+                    window: button.window,
+                });
+            }
+        }
+    }
+}
+
+/// Moves a window while its title bar is dragged.
+fn drag_window_title_bars(
+    mouse: Res<ButtonInput<MouseButton>>,
+    ui_scale: Res<UiScale>,
+    screens: Query<&Window, With<PrimaryWindow>>,
+    mut layouts: ResMut<WindowLayouts>,
+    mut title_bars: Query<(&Interaction, &mut WindowTitleBar)>,
+    mut windows: Query<(
+        &FloatingWindow,
+        &Node,
+        &GlobalTransform,
+        &mut Style,
+        Option<&Parent>,
+    )>,
+    parents: Query<(&Node, &GlobalTransform), Without<FloatingWindow>>,
+) {
+    let cursor = cursor_position(&screens, &ui_scale);
+
+    for (interaction, mut title_bar) in &mut title_bars {
+        let Ok((window, node, transform, mut style, parent)) = windows.get_mut(title_bar.window)
+        else {
+            continue;
+        };
+
+        let parent = parent.and_then(|parent| parents.get(parent.get()).ok());
+        let rect = relative_rect((node, transform), parent);
+
+        if mouse.just_pressed(MouseButton::Left) && *interaction == Interaction::Pressed {
+            title_bar.drag = cursor.map(|cursor| WindowDrag { cursor, rect });
+            continue;
+        }
+
+        let Some(drag) = title_bar.drag else {
+            continue;
+        };
+
+        if !mouse.pressed(MouseButton::Left) {
+            title_bar.drag = None;
+            if let Some(key) = &window.persist_key {
+                layouts.layouts.insert(key.clone(), rect);
+            }
+            continue;
+        }
+
+        let Some(cursor) = cursor else {
+            continue;
+        };
+
+        let mut pos = drag.rect.min + cursor - drag.cursor;
+        if let Some((parent_node, _)) = parent {
+            let bounds = parent_node.size() - MIN_VISIBLE;
+            pos = pos.min(bounds);
+        }
+        pos.x = pos.x.max(MIN_VISIBLE - rect.width());
+        pos.y = pos.y.max(0.0);
+
+        let (left, top) = (Val::Px(pos.x), Val::Px(pos.y));
+        if style.left != left || style.top != top {
+            style.left = left;
+            style.top = top;
+        }
+    }
+}
+
+/// Resizes a window while one of its resize handles is dragged.
+fn drag_window_resize_handles(
+    mouse: Res<ButtonInput<MouseButton>>,
+    ui_scale: Res<UiScale>,
+    screens: Query<&Window, With<PrimaryWindow>>,
+    mut layouts: ResMut<WindowLayouts>,
+    mut handles: Query<(&Interaction, &mut WindowResizeHandle)>,
+    mut windows: Query<(
+        &FloatingWindow,
+        &Node,
+        &GlobalTransform,
+        &mut Style,
+        Option<&Parent>,
+    )>,
+    parents: Query<(&Node, &GlobalTransform), Without<FloatingWindow>>,
+) {
+    let cursor = cursor_position(&screens, &ui_scale);
+
+    for (interaction, mut handle) in &mut handles {
+        let Ok((window, node, transform, mut style, parent)) = windows.get_mut(handle.window)
+        else {
+            continue;
+        };
+
+        let parent = parent.and_then(|parent| parents.get(parent.get()).ok());
+        let rect = relative_rect((node, transform), parent);
+
+        if mouse.just_pressed(MouseButton::Left) && *interaction == Interaction::Pressed {
+            handle.drag = cursor.map(|cursor| WindowDrag { cursor, rect });
+            continue;
+        }
+
+        let Some(drag) = handle.drag else {
+            continue;
+        };
+
+        if !mouse.pressed(MouseButton::Left) {
+            handle.drag = None;
+            if let Some(key) = &window.persist_key {
+                layouts.layouts.insert(key.clone(), rect);
+            }
+            continue;
+        }
+
+        let Some(cursor) = cursor else {
+            continue;
+        };
+
+        let delta = cursor - drag.cursor;
+        let edges = handle.edges.as_vec2();
+        let size = (drag.rect.size() + delta * edges).clamp(window.min_size, window.max_size);
+
+        // Moving the left or top edge keeps the opposite edge in place.
+        let min = Vec2::select(edges.cmplt(Vec2::ZERO), drag.rect.max - size, drag.rect.min);
+
+        let left = Val::Px(min.x);
+        let top = Val::Px(min.y);
+        let width = if edges.x != 0.0 {
+            Val::Px(size.x)
+        } else {
+            style.width
+        };
+        let height = if edges.y != 0.0 && !window.minimized {
+            Val::Px(size.y)
+        } else {
+            style.height
+        };
+
+        if style.left != left || style.top != top || style.width != width || style.height != height
+        {
+            style.left = left;
+            style.top = top;
+            style.width = width;
+            style.height = height;
+        }
+    }
+}
+
+/// Shows, hides, and minimizes each window that has changed.
+fn show_windows(
+    mut windows: Query<(Entity, &mut FloatingWindow, &mut Style), Changed<FloatingWindow>>,
+    mut contents: Query<&mut Style, Without<FloatingWindow>>,
+    mut handles: Query<(&WindowResizeHandle, &mut Visibility)>,
+) {
+    for (window_id, mut window, mut style) in &mut windows {
+        let display = match window.open {
+            true => Display::Flex,
+            false => Display::None,
+        };
+
+        if style.display != display {
+            style.display = display;
+        }
+
+        if let Ok(mut content) = contents.get_mut(window.content) {
+            let display = match window.minimized {
+                true => Display::None,
+                false => Display::Flex,
+            };
+
+            if content.display != display {
+                content.display = display;
+            }
+        }
+
+        if window.minimized && style.height != Val::Auto {
+            window.bypass_change_detection().restore_height = style.height;
+            style.height = Val::Auto;
+        } else if !window.minimized && style.height == Val::Auto {
+            style.height = window.restore_height;
+        }
+
+        let visibility = match window.minimized {
+            true => Visibility::Hidden,
+            false => Visibility::Inherited,
+        };
+
+        for (handle, mut handle_visibility) in &mut handles {
+            // Handles along the left and right edges remain usable while the
+            // window is minimized.
+            if handle.window != window_id || handle.edges.y == 0 {
+                continue;
+            }
+
+            if *handle_visibility != visibility {
+                *handle_visibility = visibility;
+            }
+        }
+    }
+}